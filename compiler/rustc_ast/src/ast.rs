@@ -363,6 +363,8 @@ pub enum GenericParamKind {
         ty: P<Ty>,
         /// Span of the `const` keyword.
         kw_span: Span,
+        /// Optional default value for the const generic param
+        default: Option<AnonConst>,
     },
 }
 
@@ -970,6 +972,9 @@ pub struct Local {
     pub ty: Option<P<Ty>>,
     /// Initializer expression to set the value, if any.
     pub init: Option<P<Expr>>,
+    /// The diverging `else` block of a `let PAT = EXPR else { .. };` statement, if any.
+    /// Only present if there is an initializer.
+    pub els: Option<P<Block>>,
     pub span: Span,
     pub attrs: AttrVec,
 }
@@ -1282,8 +1287,9 @@ pub enum ExprKind {
     /// An await expression (`my_future.await`).
     Await(P<Expr>),
 
-    /// A try block (`try { ... }`).
-    TryBlock(P<Block>),
+    /// A try block (`try { ... }`), optionally annotated with its output type
+    /// (`try -> Result<_, E> { ... }`).
+    TryBlock(P<Block>, Option<P<Ty>>),
 
     /// An assignment (`a = foo()`).
     /// The `Span` argument is the span of the `=` token.
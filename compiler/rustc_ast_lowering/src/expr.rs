@@ -100,7 +100,9 @@ impl<'hir> LoweringContext<'_, 'hir> {
                         hir::LoopSource::Loop,
                     )
                 }),
-                ExprKind::TryBlock(ref body) => self.lower_expr_try_block(body),
+                ExprKind::TryBlock(ref body, ref ty) => {
+                    self.lower_expr_try_block(body, ty.as_deref())
+                }
                 ExprKind::Match(ref expr, ref arms) => hir::ExprKind::Match(
                     self.lower_expr(expr),
                     self.arena.alloc_from_iter(arms.iter().map(|x| self.lower_arm(x))),
@@ -321,8 +323,6 @@ impl<'hir> LoweringContext<'_, 'hir> {
         then: &Block,
         else_opt: Option<&Expr>,
     ) -> hir::ExprKind<'hir> {
-        // FIXME(#53667): handle lowering of && and parens.
-
         // `_ => else_block` where `else_block` is `{}` if there's `None`:
         let else_pat = self.pat_wild(span);
         let (else_expr, contains_else_clause) = match else_opt {
@@ -340,6 +340,12 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 let pat = self.lower_pat(pat);
                 (pat, scrutinee, hir::MatchSource::IfLetDesugar { contains_else_clause })
             }
+            // `true => <then>`, where the scrutinee is a `let` chain:
+            _ if is_let_chain(cond) => {
+                let cond = self.lower_let_chain(cond);
+                let pat = self.pat_bool(span, true);
+                (pat, cond, hir::MatchSource::IfDesugar { contains_else_clause })
+            }
             // `true => <then>`:
             _ => {
                 // Lower condition:
@@ -359,6 +365,39 @@ impl<'hir> LoweringContext<'_, 'hir> {
         hir::ExprKind::Match(scrutinee, arena_vec![self; then_arm, else_arm], desugar)
     }
 
+    /// Lowers the condition of an `if` or `while`, or the guard of a `match`
+    /// arm, that is a `let` chain, e.g. `let Some(x) = a && x > 0 && let Ok(y) = b(x)`.
+    ///
+    /// The `let`s and the `&&`s joining them are kept as they are, so that the
+    /// bindings are in scope for the rest of the chain and the body. Every other
+    /// operand is wrapped in `DropTemps`, like the condition of a plain `if`, so
+    /// that its temporaries are dropped before the next operand is evaluated.
+    /// Temporaries in the scrutinee of a `let` live as long as its bindings.
+    fn lower_let_chain(&mut self, e: &Expr) -> &'hir hir::Expr<'hir> {
+        let kind = match e.kind {
+            ExprKind::Binary(op, ref lhs, ref rhs)
+                if op.node == BinOpKind::And && is_let_chain(e) =>
+            {
+                let lhs = self.lower_let_chain(lhs);
+                let rhs = self.lower_let_chain(rhs);
+                hir::ExprKind::Binary(self.lower_binop(op), lhs, rhs)
+            }
+            ExprKind::Let(ref pat, ref scrutinee) => {
+                let scrutinee = self.lower_expr(scrutinee);
+                hir::ExprKind::Let(self.lower_pat(pat), scrutinee)
+            }
+            _ => {
+                let cond = self.lower_expr(e);
+                let span_block =
+                    self.mark_span_with_reason(DesugaringKind::CondTemporary, cond.span, None);
+                return self.expr_drop_temps(span_block, cond, ThinVec::new());
+            }
+        };
+        let hir_id = self.lower_node_id(e.id);
+        let attrs = e.attrs.iter().map(|a| self.lower_attr(a)).collect::<Vec<_>>().into();
+        self.arena.alloc(hir::Expr { hir_id, kind, span: e.span, attrs })
+    }
+
     fn lower_expr_while_in_loop_scope(
         &mut self,
         span: Span,
@@ -366,8 +405,6 @@ impl<'hir> LoweringContext<'_, 'hir> {
         body: &Block,
         opt_label: Option<Label>,
     ) -> hir::ExprKind<'hir> {
-        // FIXME(#53667): handle lowering of && and parens.
-
         // Note that the block AND the condition are evaluated in the loop scope.
         // This is done to allow `break` from inside the condition of the loop.
 
@@ -394,6 +431,13 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 let pat = self.lower_pat(pat);
                 (pat, scrutinee, hir::MatchSource::WhileLetDesugar, hir::LoopSource::WhileLet)
             }
+            _ if is_let_chain(cond) => {
+                // Like `while $cond $body` below, except that the `let`s in the
+                // chain are kept, see `lower_let_chain`.
+                let cond = self.with_loop_condition_scope(|this| this.lower_let_chain(cond));
+                let pat = self.pat_bool(span, true);
+                (pat, cond, hir::MatchSource::WhileDesugar, hir::LoopSource::While)
+            }
             _ => {
                 // We desugar: `'label: while $cond $body` into:
                 //
@@ -432,8 +476,11 @@ impl<'hir> LoweringContext<'_, 'hir> {
     /// Desugar `try { <stmts>; <expr> }` into `{ <stmts>; ::std::ops::Try::from_ok(<expr>) }`,
     /// `try { <stmts>; }` into `{ <stmts>; ::std::ops::Try::from_ok(()) }`
     /// and save the block id to use it as a break target for desugaring of the `?` operator.
-    fn lower_expr_try_block(&mut self, body: &Block) -> hir::ExprKind<'hir> {
-        self.with_catch_scope(body.id, |this| {
+    ///
+    /// An annotated `try -> <ty> { .. }` block is additionally wrapped in a type ascription,
+    /// i.e. `{ <stmts>; ::std::ops::Try::from_ok(<expr>) }: <ty>`.
+    fn lower_expr_try_block(&mut self, body: &Block, ty: Option<&Ty>) -> hir::ExprKind<'hir> {
+        let block = self.with_catch_scope(body.id, |this| {
             let mut block = this.lower_block_noalloc(body, true);
 
             // Final expression of the block (if present) or `()` with span at the end of block
@@ -468,7 +515,15 @@ impl<'hir> LoweringContext<'_, 'hir> {
             ));
 
             hir::ExprKind::Block(this.arena.alloc(block), None)
-        })
+        });
+
+        match ty {
+            Some(ty) => {
+                let block = self.arena.alloc(self.expr(body.span, block, ThinVec::new()));
+                hir::ExprKind::Type(block, self.lower_ty(ty, ImplTraitContext::disallowed()))
+            }
+            None => block,
+        }
     }
 
     fn wrap_in_try_constructor(
@@ -489,6 +544,7 @@ impl<'hir> LoweringContext<'_, 'hir> {
             attrs: self.lower_attrs(&arm.attrs),
             pat: self.lower_pat(&arm.pat),
             guard: match arm.guard {
+                Some(ref x) if is_let_chain(x) => Some(hir::Guard::If(self.lower_let_chain(x))),
                 Some(ref x) => Some(hir::Guard::If(self.lower_expr(x))),
                 _ => None,
             },
@@ -1795,3 +1851,14 @@ impl<'hir> LoweringContext<'_, 'hir> {
         }
     }
 }
+
+/// Whether `e` is a `let` expression, or a chain of `&&`s containing one (RFC 2497).
+fn is_let_chain(e: &Expr) -> bool {
+    match e.kind {
+        ExprKind::Let(..) => true,
+        ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And => {
+            is_let_chain(lhs) || is_let_chain(rhs)
+        }
+        _ => false,
+    }
+}
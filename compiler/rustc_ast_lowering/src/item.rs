@@ -1,5 +1,5 @@
 use super::{AnonymousLifetimeMode, LoweringContext, ParamMode};
-use super::{ImplTraitContext, ImplTraitPosition};
+use super::{ImplTraitContext, ImplTraitInTrait, ImplTraitPosition};
 use crate::Arena;
 
 use rustc_ast::node_id::NodeMap;
@@ -11,6 +11,8 @@ use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::LocalDefId;
+use rustc_hir::definitions::DefPathData;
+use rustc_span::hygiene::ExpnId;
 use rustc_span::source_map::{respan, DesugaringKind};
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::Span;
@@ -402,10 +404,13 @@ impl<'hir> LoweringContext<'_, 'hir> {
                     },
                 );
 
+                let is_trait_impl = trait_ref.is_some();
                 let new_impl_items =
                     self.with_in_scope_lifetime_defs(&ast_generics.params, |this| {
                         this.arena.alloc_from_iter(
-                            impl_items.iter().map(|item| this.lower_impl_item_ref(item)),
+                            impl_items
+                                .iter()
+                                .flat_map(|item| this.lower_impl_item_ref(item, is_trait_impl)),
                         )
                     });
 
@@ -429,7 +434,7 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 let bounds = self.lower_param_bounds(bounds, ImplTraitContext::disallowed());
                 let items = self
                     .arena
-                    .alloc_from_iter(items.iter().map(|item| self.lower_trait_item_ref(item)));
+                    .alloc_from_iter(items.iter().flat_map(|item| self.lower_trait_item_ref(item)));
                 hir::ItemKind::Trait(
                     is_auto,
                     self.lower_unsafety(unsafety),
@@ -780,14 +785,41 @@ impl<'hir> LoweringContext<'_, 'hir> {
             }
             AssocItemKind::Fn(_, ref sig, ref generics, None) => {
                 let names = self.lower_fn_params_to_names(&sig.decl);
-                let (generics, sig) =
-                    self.lower_method_sig(generics, sig, trait_item_def_id, false, None);
+                let (generics, sig) = match self.impl_trait_in_trait_items.get(&i.id) {
+                    Some(&assoc_ty_id) => self.lower_impl_trait_in_trait_fn(
+                        i,
+                        generics,
+                        sig,
+                        trait_item_def_id,
+                        assoc_ty_id,
+                    ),
+                    None => self.lower_method_sig(generics, sig, trait_item_def_id, false, None),
+                };
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Required(names)))
             }
             AssocItemKind::Fn(_, ref sig, ref generics, Some(ref body)) => {
-                let body_id = self.lower_fn_body_block(i.span, &sig.decl, Some(body));
-                let (generics, sig) =
-                    self.lower_method_sig(generics, sig, trait_item_def_id, false, None);
+                let (body_id, (generics, sig)) = match self.impl_trait_in_trait_items.get(&i.id) {
+                    Some(&assoc_ty_id) => {
+                        self.current_item = Some(i.span);
+                        let asyncness = sig.header.asyncness;
+                        let body_id =
+                            self.lower_maybe_async_body(i.span, &sig.decl, asyncness, Some(body));
+                        let sig = self.lower_impl_trait_in_trait_provided_fn(
+                            i,
+                            generics,
+                            sig,
+                            trait_item_def_id,
+                            assoc_ty_id,
+                        );
+                        (body_id, sig)
+                    }
+                    None => {
+                        let body_id = self.lower_fn_body_block(i.span, &sig.decl, Some(body));
+                        let sig =
+                            self.lower_method_sig(generics, sig, trait_item_def_id, false, None);
+                        (body_id, sig)
+                    }
+                };
                 (generics, hir::TraitItemKind::Fn(sig, hir::TraitFn::Provided(body_id)))
             }
             AssocItemKind::TyAlias(_, ref generics, ref bounds, ref default) => {
@@ -813,7 +845,7 @@ impl<'hir> LoweringContext<'_, 'hir> {
         }
     }
 
-    fn lower_trait_item_ref(&mut self, i: &AssocItem) -> hir::TraitItemRef {
+    fn lower_trait_item_ref(&mut self, i: &AssocItem) -> SmallVec<[hir::TraitItemRef; 2]> {
         let (kind, has_default) = match &i.kind {
             AssocItemKind::Const(_, _, default) => (hir::AssocItemKind::Const, default.is_some()),
             AssocItemKind::TyAlias(_, _, _, default) => {
@@ -826,7 +858,92 @@ impl<'hir> LoweringContext<'_, 'hir> {
         };
         let id = hir::TraitItemId { hir_id: self.lower_node_id(i.id) };
         let defaultness = hir::Defaultness::Default { has_value: has_default };
-        hir::TraitItemRef { id, ident: i.ident, span: i.span, defaultness, kind }
+        let item_ref = hir::TraitItemRef {
+            id,
+            ident: i.ident,
+            span: i.span,
+            defaultness,
+            kind,
+            impl_trait_fn: None,
+        };
+        let mut refs = smallvec![item_ref];
+        if let Some((hir_id, ident)) = self.lower_impl_trait_in_trait_item_id(i) {
+            refs.push(hir::TraitItemRef {
+                id: hir::TraitItemId { hir_id },
+                ident,
+                span: i.span,
+                // Provided methods define the default of the associated type.
+                defaultness,
+                kind: hir::AssocItemKind::Type,
+                impl_trait_fn: Some(id),
+            });
+        }
+        refs
+    }
+
+    /// Creates the anonymous associated type that the `impl Trait` or `async fn` return type of
+    /// the trait method or trait impl method `i` desugars to, if any:
+    ///
+    /// ```ignore (pseudo-Rust)
+    /// trait Foo {
+    ///     fn foo<'a, T>(&'a self, t: T) -> impl Debug + 'a;
+    ///     // is lowered to
+    ///     type foo::{opaque}<'a>: Debug + 'a;
+    ///     fn foo<'a, T>(&'a self, t: T) -> Self::foo::{opaque}<'a>;
+    /// }
+    ///
+    /// impl Foo for Bar {
+    ///     fn foo<'a, T>(&'a self, t: T) -> impl Debug + 'a { .. }
+    ///     // additionally defines
+    ///     type foo::{opaque}<'a> = <return type of `foo`><'a>;
+    /// }
+    /// ```
+    ///
+    /// The associated type also has the generic parameters of the method, see `generics_of`.
+    /// Provided methods define its default, just like impl methods define its value.
+    ///
+    /// The associated type is lowered together with the method, see
+    /// `lower_impl_trait_in_trait_fn`, `lower_impl_trait_in_trait_provided_fn` and
+    /// `lower_impl_trait_in_trait_impl_item`. This only creates its definition and returns its
+    /// `HirId` and name.
+    fn lower_impl_trait_in_trait_item_id(&mut self, i: &AssocItem) -> Option<(hir::HirId, Ident)> {
+        let sig = match &i.kind {
+            AssocItemKind::Fn(_, sig, ..) => sig,
+            _ => return None,
+        };
+        self.impl_trait_in_trait_opaque_ty_id(sig)?;
+
+        let parent = self.current_hir_id_owner.last().unwrap().0;
+        let ident = Ident::new(hir::impl_trait_in_trait_name(i.ident.name), i.ident.span);
+        let node_id = self.resolver.next_node_id();
+        self.resolver.create_def(
+            parent,
+            node_id,
+            DefPathData::TypeNs(ident.name),
+            ExpnId::root(),
+            sig.decl.output.span(),
+        );
+        let hir_id = self.allocate_hir_id_counter(node_id);
+        self.impl_trait_in_trait_items.insert(i.id, node_id);
+        Some((hir_id, ident))
+    }
+
+    /// Returns the `NodeId` of the `impl Trait` return type of `sig`, or of the `impl Future`
+    /// return type of an `async fn`, if it desugars to an anonymous associated type in traits.
+    fn impl_trait_in_trait_opaque_ty_id(&self, sig: &FnSig) -> Option<NodeId> {
+        let features = self.sess.features_untracked();
+        match (sig.header.asyncness, &sig.decl.output) {
+            (Async::Yes { return_impl_trait_id, .. }, _) if features.async_fn_in_trait => {
+                Some(return_impl_trait_id)
+            }
+            (Async::No, FnRetTy::Ty(ty)) if features.return_position_impl_trait_in_trait => {
+                match ty.kind {
+                    TyKind::ImplTrait(id, _) => Some(id),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
     }
 
     /// Construct `ExprKind::Err` for the given `span`.
@@ -850,7 +967,8 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 let asyncness = sig.header.asyncness;
                 let body_id =
                     self.lower_maybe_async_body(i.span, &sig.decl, asyncness, body.as_deref());
-                let impl_trait_return_allow = !self.is_in_trait_impl;
+                let assoc_ty_id = self.impl_trait_in_trait_items.get(&i.id).copied();
+                let impl_trait_return_allow = !self.is_in_trait_impl || assoc_ty_id.is_some();
                 let (generics, sig) = self.lower_method_sig(
                     generics,
                     sig,
@@ -858,6 +976,25 @@ impl<'hir> LoweringContext<'_, 'hir> {
                     impl_trait_return_allow,
                     asyncness.opt_return_id(),
                 );
+                if let Some(assoc_ty_id) = assoc_ty_id {
+                    let span = sig.decl.output.span();
+                    let (generics, ty, _) =
+                        self.lower_impl_trait_in_trait_value(sig.decl, span, assoc_ty_id);
+                    let ident = hir::impl_trait_in_trait_name(i.ident.name);
+                    let impl_item = hir::ImplItem {
+                        hir_id: self.lower_node_id(assoc_ty_id),
+                        ident: Ident::new(ident, i.ident.span),
+                        attrs: &[],
+                        generics,
+                        vis: respan(span.shrink_to_lo(), hir::VisibilityKind::Inherited),
+                        defaultness: hir::Defaultness::Final,
+                        kind: hir::ImplItemKind::TyAlias(ty),
+                        span,
+                    };
+                    let id = hir::ImplItemId { hir_id: impl_item.hir_id };
+                    self.impl_items.insert(id, impl_item);
+                    self.modules.get_mut(&self.current_module).unwrap().impl_items.insert(id);
+                }
 
                 (generics, hir::ImplItemKind::Fn(sig, body_id))
             }
@@ -899,11 +1036,15 @@ impl<'hir> LoweringContext<'_, 'hir> {
         }
     }
 
-    fn lower_impl_item_ref(&mut self, i: &AssocItem) -> hir::ImplItemRef<'hir> {
+    fn lower_impl_item_ref(
+        &mut self,
+        i: &AssocItem,
+        is_trait_impl: bool,
+    ) -> SmallVec<[hir::ImplItemRef<'hir>; 2]> {
         // Since `default impl` is not yet implemented, this is always true in impls.
         let has_value = true;
         let (defaultness, _) = self.lower_defaultness(i.kind.defaultness(), has_value);
-        hir::ImplItemRef {
+        let item_ref = hir::ImplItemRef {
             id: hir::ImplItemId { hir_id: self.lower_node_id(i.id) },
             ident: i.ident,
             span: i.span,
@@ -917,7 +1058,24 @@ impl<'hir> LoweringContext<'_, 'hir> {
                 }
                 AssocItemKind::MacCall(..) => unimplemented!(),
             },
+            impl_trait_fn: None,
+        };
+        let impl_trait_fn = item_ref.id;
+        let mut refs = smallvec![item_ref];
+        if is_trait_impl {
+            if let Some((hir_id, ident)) = self.lower_impl_trait_in_trait_item_id(i) {
+                refs.push(hir::ImplItemRef {
+                    id: hir::ImplItemId { hir_id },
+                    ident,
+                    span: i.span,
+                    vis: respan(i.span.shrink_to_lo(), hir::VisibilityKind::Inherited),
+                    defaultness: hir::Defaultness::Final,
+                    kind: hir::AssocItemKind::Type,
+                    impl_trait_fn: Some(impl_trait_fn),
+                });
+            }
         }
+        refs
     }
 
     /// If an `explicit_owner` is given, this method allocates the `HirId` in
@@ -1258,6 +1416,214 @@ impl<'hir> LoweringContext<'_, 'hir> {
         (generics, hir::FnSig { header, decl, span: sig.span })
     }
 
+    /// Lowers the signature of the required trait method `i`, replacing its `impl Trait` return
+    /// type with a projection to the anonymous associated type `assoc_ty_id`, and lowers that
+    /// associated type. See `lower_impl_trait_in_trait_item_id`.
+    fn lower_impl_trait_in_trait_fn(
+        &mut self,
+        i: &AssocItem,
+        generics: &Generics,
+        sig: &FnSig,
+        fn_def_id: LocalDefId,
+        assoc_ty_id: NodeId,
+    ) -> (hir::Generics<'hir>, hir::FnSig<'hir>) {
+        // Lower the return type as if this was an inherent method, but generate the opaque type
+        // under `assoc_ty_id`, leaving out the lifetimes of the trait.
+        let opaque_ty_id = self.impl_trait_in_trait_opaque_ty_id(sig).unwrap();
+        let old = self.impl_trait_in_trait.replace(ImplTraitInTrait {
+            opaque_ty_id,
+            assoc_ty_id,
+            trait_lifetimes: self.in_scope_lifetimes.len(),
+        });
+        let (generics, sig) = self.lower_method_sig(
+            generics,
+            sig,
+            fn_def_id,
+            true,
+            sig.header.asyncness.opt_return_id(),
+        );
+        self.impl_trait_in_trait = old;
+
+        // Turn the opaque type into the associated type...
+        let hir_id = self.lower_node_id(assoc_ty_id);
+        let item = self.items.remove(&hir_id).unwrap();
+        self.modules.get_mut(&self.current_module).unwrap().items.remove(&hir_id);
+        let opaque = match item.kind {
+            hir::ItemKind::OpaqueTy(opaque) => opaque,
+            _ => unreachable!(),
+        };
+        let ident = Ident::new(hir::impl_trait_in_trait_name(i.ident.name), i.ident.span);
+        let trait_item = hir::TraitItem {
+            hir_id,
+            ident,
+            attrs: &[],
+            generics: opaque.generics,
+            kind: hir::TraitItemKind::Type(opaque.bounds, None),
+            span: item.span,
+        };
+        let id = hir::TraitItemId { hir_id };
+        self.trait_items.insert(id, trait_item);
+        self.modules.get_mut(&self.current_module).unwrap().trait_items.insert(id);
+
+        // ...and the return type into `Self::foo::{opaque}<'a, ..>`.
+        let args = match sig.decl.output {
+            hir::FnRetTy::Return(&hir::Ty { kind: hir::TyKind::OpaqueDef(_, args), .. }) => args,
+            _ => unreachable!(),
+        };
+        let decl = self.impl_trait_in_trait_projection(sig.decl, fn_def_id, ident, args);
+        (generics, hir::FnSig { decl, ..sig })
+    }
+
+    /// Lowers the signature of the provided trait method `i`, replacing its `impl Trait` return
+    /// type with a projection to the anonymous associated type `assoc_ty_id`, and lowers that
+    /// associated type with the opaque return type as its default.
+    fn lower_impl_trait_in_trait_provided_fn(
+        &mut self,
+        i: &AssocItem,
+        generics: &Generics,
+        sig: &FnSig,
+        fn_def_id: LocalDefId,
+        assoc_ty_id: NodeId,
+    ) -> (hir::Generics<'hir>, hir::FnSig<'hir>) {
+        let (generics, sig) = self.lower_method_sig(
+            generics,
+            sig,
+            fn_def_id,
+            true,
+            sig.header.asyncness.opt_return_id(),
+        );
+        let span = sig.decl.output.span();
+        let (assoc_generics, default, args) =
+            self.lower_impl_trait_in_trait_value(sig.decl, span, assoc_ty_id);
+
+        // The bounds of the associated type are those of the opaque type, see `item_bounds`.
+        let ident = Ident::new(hir::impl_trait_in_trait_name(i.ident.name), i.ident.span);
+        let trait_item = hir::TraitItem {
+            hir_id: self.lower_node_id(assoc_ty_id),
+            ident,
+            attrs: &[],
+            generics: assoc_generics,
+            kind: hir::TraitItemKind::Type(&[], Some(default)),
+            span,
+        };
+        let id = hir::TraitItemId { hir_id: trait_item.hir_id };
+        self.trait_items.insert(id, trait_item);
+        self.modules.get_mut(&self.current_module).unwrap().trait_items.insert(id);
+
+        let decl = self.impl_trait_in_trait_projection(sig.decl, fn_def_id, ident, args);
+        (generics, hir::FnSig { decl, ..sig })
+    }
+
+    /// Replaces the return type of `decl`, the signature of the trait method `fn_def_id`, with
+    /// the projection `Self::foo::{opaque}<args>` to its anonymous associated type `ident`.
+    fn impl_trait_in_trait_projection(
+        &mut self,
+        decl: &'hir hir::FnDecl<'hir>,
+        fn_def_id: LocalDefId,
+        ident: Ident,
+        args: &'hir [hir::GenericArg<'hir>],
+    ) -> &'hir hir::FnDecl<'hir> {
+        let ret_ty = match decl.output {
+            hir::FnRetTy::Return(ty) => ty,
+            hir::FnRetTy::DefaultReturn(_) => unreachable!(),
+        };
+        let trait_def_id = self.resolver.def_key(fn_def_id.to_def_id()).parent.unwrap();
+        let trait_def_id = LocalDefId { local_def_index: trait_def_id }.to_def_id();
+        let self_path = self.arena.alloc(hir::Path {
+            span: ret_ty.span,
+            res: Res::SelfTy(Some(trait_def_id), None),
+            segments: arena_vec![self; hir::PathSegment::from_ident(Ident::new(
+                kw::SelfUpper,
+                ret_ty.span,
+            ))],
+        });
+        let self_ty = hir::TyKind::Path(hir::QPath::Resolved(None, self_path));
+        let self_ty = self.arena.alloc(self.ty(ret_ty.span, self_ty));
+        let segment = self.arena.alloc(hir::PathSegment {
+            ident,
+            hir_id: Some(self.next_id()),
+            res: None,
+            args: Some(self.arena.alloc(hir::GenericArgs {
+                args,
+                bindings: &[],
+                parenthesized: false,
+            })),
+            infer_args: false,
+        });
+        let ret_ty = self.arena.alloc(hir::Ty {
+            hir_id: ret_ty.hir_id,
+            kind: hir::TyKind::Path(hir::QPath::TypeRelative(self_ty, segment)),
+            span: ret_ty.span,
+        });
+        self.arena.alloc(hir::FnDecl { output: hir::FnRetTy::Return(ret_ty), ..*decl })
+    }
+
+    /// Lowers the generic parameters and the value of the anonymous associated type
+    /// `assoc_ty_id` of a provided trait method or a trait impl method with the signature `decl`.
+    /// The value is the opaque return type of the method, and the associated type is generic
+    /// over the lifetimes captured by it, except for those of the trait or impl. Also returns the
+    /// arguments of the opaque return type for these lifetimes, in terms of the method.
+    fn lower_impl_trait_in_trait_value(
+        &mut self,
+        decl: &'hir hir::FnDecl<'hir>,
+        span: Span,
+        assoc_ty_id: NodeId,
+    ) -> (hir::Generics<'hir>, &'hir hir::Ty<'hir>, &'hir [hir::GenericArg<'hir>]) {
+        let (item_id, args) = match decl.output {
+            hir::FnRetTy::Return(&hir::Ty { kind: hir::TyKind::OpaqueDef(item_id, args), .. }) => {
+                (item_id, args)
+            }
+            _ => {
+                let ty =
+                    self.with_hir_id_owner(assoc_ty_id, |this| this.ty(span, hir::TyKind::Err));
+                return (hir::Generics::empty(), self.arena.alloc(ty), &[]);
+            }
+        };
+        let params: Vec<_> = match self.items[&item_id.id].kind {
+            hir::ItemKind::OpaqueTy(ref opaque) => {
+                opaque.generics.params.iter().map(|param| (param.span, param.name)).collect()
+            }
+            _ => unreachable!(),
+        };
+        // The lifetimes of the trait or impl are in scope of the associated type already.
+        let is_own: Vec<_> = params
+            .iter()
+            .map(|(_, name)| !self.in_scope_lifetimes.contains(&name.normalize_to_macros_2_0()))
+            .collect();
+        let own_args = self.arena.alloc_from_iter(args.iter().zip(&is_own).filter_map(
+            |(arg, &is_own)| match *arg {
+                hir::GenericArg::Lifetime(lifetime) if is_own => {
+                    Some(hir::GenericArg::Lifetime(lifetime))
+                }
+                hir::GenericArg::Lifetime(_) => None,
+                _ => unreachable!(),
+            },
+        ));
+
+        let def_id = self.resolver.local_def_id(assoc_ty_id);
+        let (generics, ty) = self.with_hir_id_owner(assoc_ty_id, |this| {
+            let generic_params = this.arena.alloc_from_iter(
+                params.iter().zip(&is_own).filter(|(_, &is_own)| is_own).map(
+                    |(&(span, name), _)| this.lifetime_to_generic_param(span, name, def_id),
+                ),
+            );
+            let args = this.arena.alloc_from_iter(params.iter().map(|&(span, name)| {
+                hir::GenericArg::Lifetime(hir::Lifetime {
+                    hir_id: this.next_id(),
+                    span,
+                    name: hir::LifetimeName::Param(name),
+                })
+            }));
+            let generics = hir::Generics {
+                params: generic_params,
+                where_clause: hir::WhereClause { predicates: &[], span },
+                span,
+            };
+            (generics, this.ty(span, hir::TyKind::OpaqueDef(item_id, args)))
+        });
+        (generics, self.arena.alloc(ty), own_args)
+    }
+
     fn lower_fn_header(&mut self, h: FnHeader) -> hir::FnHeader {
         hir::FnHeader {
             unsafety: self.lower_unsafety(h.unsafety),
@@ -166,6 +166,28 @@ struct LoweringContext<'a, 'hir: 'a> {
 
     allow_try_trait: Option<Lrc<[Symbol]>>,
     allow_gen_future: Option<Lrc<[Symbol]>>,
+
+    /// Maps the `NodeId`s of trait methods and trait impl methods whose `impl Trait` (or
+    /// `async fn`) return type desugars to an anonymous associated type to the `NodeId` of
+    /// that associated type. See `lower_impl_trait_in_trait_item_id`.
+    impl_trait_in_trait_items: NodeMap<NodeId>,
+
+    /// Set while lowering the signature of a required trait method whose return type desugars
+    /// to an anonymous associated type.
+    impl_trait_in_trait: Option<ImplTraitInTrait>,
+}
+
+/// The anonymous associated type that the `impl Trait` return type of a required trait method
+/// desugars to.
+#[derive(Copy, Clone, Debug)]
+struct ImplTraitInTrait {
+    /// The `NodeId` of the `impl Trait` return type, or of the `impl Future` of an `async fn`.
+    opaque_ty_id: NodeId,
+    /// The `NodeId` of the associated type, which is lowered in place of the opaque type.
+    assoc_ty_id: NodeId,
+    /// The number of lifetimes in `in_scope_lifetimes` that belong to the trait. They are in
+    /// scope of the associated type already, so it does not capture them.
+    trait_lifetimes: usize,
 }
 
 pub trait ResolverAstLowering {
@@ -323,6 +345,8 @@ pub fn lower_crate<'a, 'hir>(
         in_scope_lifetimes: Vec::new(),
         allow_try_trait: Some([sym::try_trait][..].into()),
         allow_gen_future: Some([sym::gen_future][..].into()),
+        impl_trait_in_trait_items: Default::default(),
+        impl_trait_in_trait: None,
     }
     .lower_crate(krate)
 }
@@ -1316,15 +1340,18 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             TyKind::ImplTrait(def_node_id, ref bounds) => {
                 let span = t.span;
                 match itctx {
-                    ImplTraitContext::ReturnPositionOpaqueTy { fn_def_id, origin } => self
-                        .lower_opaque_impl_trait(
+                    ImplTraitContext::ReturnPositionOpaqueTy { fn_def_id, origin } => {
+                        let opaque_ty_node_id =
+                            self.impl_trait_in_trait_assoc_ty(def_node_id).unwrap_or(def_node_id);
+                        self.lower_opaque_impl_trait(
                             span,
                             Some(fn_def_id),
                             origin,
-                            def_node_id,
+                            opaque_ty_node_id,
                             None,
                             |this| this.lower_param_bounds(bounds, itctx),
-                        ),
+                        )
+                    }
                     ImplTraitContext::OtherOpaqueTy { ref capturable_lifetimes, origin } => {
                         // Reset capturable lifetimes, any nested impl trait
                         // types will inherit lifetimes from this opaque type,
@@ -1500,6 +1527,25 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
         opaque_ty_id
     }
 
+    /// Returns the `NodeId` of the anonymous associated type that the `impl Trait` return type
+    /// `opaque_ty_id` desugars to, if it is the return type of the trait method being lowered.
+    fn impl_trait_in_trait_assoc_ty(&self, opaque_ty_id: NodeId) -> Option<NodeId> {
+        self.impl_trait_in_trait
+            .filter(|itit| itit.opaque_ty_id == opaque_ty_id)
+            .map(|itit| itit.assoc_ty_id)
+    }
+
+    /// Returns the lifetimes of the trait if `opaque_ty_id` is the anonymous associated type of
+    /// the trait method being lowered, and an empty slice otherwise.
+    fn impl_trait_in_trait_lifetimes(&self, opaque_ty_id: NodeId) -> &[ParamName] {
+        match self.impl_trait_in_trait {
+            Some(itit) if itit.assoc_ty_id == opaque_ty_id => {
+                &self.in_scope_lifetimes[..itit.trait_lifetimes]
+            }
+            _ => &[],
+        }
+    }
+
     fn lifetimes_from_impl_trait_bounds(
         &mut self,
         opaque_ty_id: NodeId,
@@ -1657,13 +1703,21 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             }
         }
 
+        // The anonymous associated type of an `impl Trait` in a trait does not capture the
+        // lifetimes of the trait.
+        let already_defined_lifetimes = self
+            .impl_trait_in_trait_lifetimes(opaque_ty_id)
+            .iter()
+            .map(|&name| hir::LifetimeName::Param(name))
+            .collect();
+
         let mut lifetime_collector = ImplTraitLifetimeCollector {
             context: self,
             parent: parent_def_id,
             opaque_ty_id,
             collect_elided_lifetimes: true,
             currently_bound_lifetimes: Vec::new(),
-            already_defined_lifetimes: FxHashSet::default(),
+            already_defined_lifetimes,
             output_lifetimes: Vec::new(),
             output_lifetime_params: Vec::new(),
             lifetimes_to_include,
@@ -1706,12 +1760,14 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             )
         });
         let init = l.init.as_ref().map(|e| self.lower_expr(e));
+        let els = l.els.as_ref().map(|b| self.lower_block(b, false));
         (
             hir::Local {
                 hir_id: self.lower_node_id(l.id),
                 ty,
                 pat: self.lower_pat(&l.pat),
                 init,
+                els,
                 span: l.span,
                 attrs: l.attrs.clone(),
                 source: hir::LocalSource::Normal,
@@ -1871,6 +1927,12 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             output, fn_def_id, opaque_ty_node_id,
         );
 
+        let opaque_ty_node_id =
+            self.impl_trait_in_trait_assoc_ty(opaque_ty_node_id).unwrap_or(opaque_ty_node_id);
+        // The anonymous associated type of an `async fn` in a trait does not capture the
+        // lifetimes of the trait.
+        let skipped_lifetimes = self.impl_trait_in_trait_lifetimes(opaque_ty_node_id).len();
+
         let span = output.span();
 
         let opaque_ty_span = self.mark_span_with_reason(DesugaringKind::Async, span, None);
@@ -1925,7 +1987,8 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
         // content of `lifetimes_to_define`). Next, we will process
         // the return type, which will cause `lifetimes_to_define` to
         // grow.
-        let input_lifetimes_count = self.in_scope_lifetimes.len() + self.lifetimes_to_define.len();
+        let input_lifetimes_count =
+            self.in_scope_lifetimes.len() - skipped_lifetimes + self.lifetimes_to_define.len();
 
         let (opaque_ty_id, lifetime_params) = self.with_hir_id_owner(opaque_ty_node_id, |this| {
             // We have to be careful to get elision right here. The
@@ -1949,8 +2012,8 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
             //
             // Note: this must be done after lowering the output type,
             // as the output type may introduce new in-band lifetimes.
-            let lifetime_params: Vec<(Span, ParamName)> = this
-                .in_scope_lifetimes
+            let lifetime_params: Vec<(Span, ParamName)> = this.in_scope_lifetimes
+                [skipped_lifetimes..]
                 .iter()
                 .cloned()
                 .map(|name| (name.ident().span, name))
@@ -2206,13 +2269,14 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
 
                 (hir::ParamName::Plain(param.ident), kind)
             }
-            GenericParamKind::Const { ref ty, kw_span: _ } => {
+            GenericParamKind::Const { ref ty, kw_span: _, ref default } => {
                 let ty = self
                     .with_anonymous_lifetime_mode(AnonymousLifetimeMode::ReportError, |this| {
                         this.lower_ty(&ty, ImplTraitContext::disallowed())
                     });
+                let default = default.as_ref().map(|def| self.lower_anon_const(def));
 
-                (hir::ParamName::Plain(param.ident), hir::GenericParamKind::Const { ty })
+                (hir::ParamName::Plain(param.ident), hir::GenericParamKind::Const { ty, default })
             }
         };
 
@@ -2431,7 +2495,16 @@ impl<'a, 'hir> LoweringContext<'a, 'hir> {
         pat: &'hir hir::Pat<'hir>,
         source: hir::LocalSource,
     ) -> hir::Stmt<'hir> {
-        let local = hir::Local { attrs, hir_id: self.next_id(), init, pat, source, span, ty: None };
+        let local = hir::Local {
+            attrs,
+            hir_id: self.next_id(),
+            init,
+            els: None,
+            pat,
+            source,
+            span,
+            ty: None,
+        };
         self.stmt(span, hir::StmtKind::Local(self.arena.alloc(local)))
     }
 
@@ -226,6 +226,9 @@ impl<'a> AstValidator<'a> {
     }
 
     fn check_trait_fn_not_async(&self, fn_span: Span, asyncness: Async) {
+        if self.session.features_untracked().async_fn_in_trait {
+            return;
+        }
         if let Async::Yes { span, .. } = asyncness {
             struct_span_err!(
                 self.session,
@@ -1123,24 +1126,39 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
     }
 
     fn visit_generics(&mut self, generics: &'a Generics) {
-        let mut prev_ty_default = None;
+        // The span of the last parameter with a default, and whether it was a type parameter.
+        let mut prev_default = None;
         for param in &generics.params {
             match param.kind {
                 GenericParamKind::Lifetime => (),
                 GenericParamKind::Type { default: Some(_), .. } => {
-                    prev_ty_default = Some(param.ident.span);
+                    prev_default = Some((param.ident.span, true));
+                }
+                GenericParamKind::Const { default: Some(_), .. } => {
+                    prev_default = Some((param.ident.span, false));
                 }
                 GenericParamKind::Type { .. } | GenericParamKind::Const { .. } => {
-                    if let Some(span) = prev_ty_default {
+                    if let Some((span, is_ty)) = prev_default {
                         let mut err = self.err_handler().struct_span_err(
                             span,
-                            "type parameters with a default must be trailing",
+                            if is_ty {
+                                "type parameters with a default must be trailing"
+                            } else {
+                                "generic parameters with a default must be trailing"
+                            },
                         );
-                        if matches!(param.kind, GenericParamKind::Const { .. }) {
-                            err.note(
-                                "using type defaults and const parameters \
-                                 in the same parameter list is currently not permitted",
-                            );
+                        if is_ty && matches!(param.kind, GenericParamKind::Const { .. }) {
+                            if self.session.features_untracked().const_generics_defaults {
+                                err.note(
+                                    "const parameters following a type parameter with a default \
+                                     must have a default as well",
+                                );
+                            } else {
+                                err.note(
+                                    "using type defaults and const parameters \
+                                     in the same parameter list is currently not permitted",
+                                );
+                            }
                         }
                         err.emit();
                         break;
@@ -1157,12 +1175,15 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
                 let (kind, ident) = match &param.kind {
                     GenericParamKind::Lifetime => (ParamKindOrd::Lifetime, ident),
                     GenericParamKind::Type { default: _ } => (ParamKindOrd::Type, ident),
-                    GenericParamKind::Const { ref ty, kw_span: _ } => {
+                    GenericParamKind::Const { ref ty, kw_span: _, ref default } => {
                         let ty = pprust::ty_to_string(ty);
                         let unordered = self.session.features_untracked().const_generics;
+                        let default = default.as_ref().map_or(String::new(), |default| {
+                            format!(" = {}", pprust::expr_to_string(&default.value))
+                        });
                         (
                             ParamKindOrd::Const { unordered },
-                            Some(format!("const {}: {}", param.ident, ty)),
+                            Some(format!("const {}: {}{}", param.ident, ty, default)),
                         )
                     }
                 };
@@ -1525,6 +1525,12 @@ impl<'a> State<'a> {
                     self.word_space("=");
                     self.print_expr(init);
                 }
+                if let Some(ref els) = loc.els {
+                    self.cbox(INDENT_UNIT - 1);
+                    self.ibox(0);
+                    self.s.word(" else ");
+                    self.print_block(els);
+                }
                 self.s.word(";");
                 self.end();
             }
@@ -2299,9 +2305,14 @@ impl<'a> State<'a> {
                 self.print_expr_maybe_paren(e, parser::PREC_POSTFIX);
                 self.s.word("?")
             }
-            ast::ExprKind::TryBlock(ref blk) => {
+            ast::ExprKind::TryBlock(ref blk, ref ty) => {
                 self.head("try");
                 self.s.space();
+                if let Some(ty) = ty {
+                    self.word_space("->");
+                    self.print_type(ty);
+                    self.s.space();
+                }
                 self.print_block_with_attrs(blk, attrs)
             }
             ast::ExprKind::Err => {
@@ -2673,13 +2684,18 @@ impl<'a> State<'a> {
                         s.print_type(default)
                     }
                 }
-                ast::GenericParamKind::Const { ref ty, kw_span: _ } => {
+                ast::GenericParamKind::Const { ref ty, kw_span: _, ref default } => {
                     s.word_space("const");
                     s.print_ident(param.ident);
                     s.s.space();
                     s.word_space(":");
                     s.print_type(ty);
-                    s.print_type_bounds(":", &param.bounds)
+                    s.print_type_bounds(":", &param.bounds);
+                    if let Some(ref default) = default {
+                        s.s.space();
+                        s.word_space("=");
+                        s.print_expr(&default.value);
+                    }
                 }
             }
         });
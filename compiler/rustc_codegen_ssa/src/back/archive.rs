@@ -1,9 +1,15 @@
+use object::read::archive::ArchiveFile;
+use object::Object;
 use rustc_session::Session;
 use rustc_span::symbol::Symbol;
 
+use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
+use super::link::archive_search_paths;
+use crate::{looks_like_rust_object_file, METADATA_FILENAME};
+
 pub fn find_library(name: Symbol, search_paths: &[PathBuf], sess: &Session) -> PathBuf {
     // On Windows, static libraries sometimes show up as libfoo.a and other
     // times show up as foo.lib
@@ -52,3 +58,335 @@ pub trait ArchiveBuilder<'a> {
 
     fn build(self);
 }
+
+/// An [`ArchiveBuilder`] which doesn't depend on a particular backend: the archives and the
+/// object files are read with the `object` crate, and the archive is written here.
+#[must_use = "must call build() to finish building the archive"]
+pub struct ObjectArchiveBuilder<'a> {
+    sess: &'a Session,
+    dst: PathBuf,
+    lib_search_paths: Vec<PathBuf>,
+    src_files: Vec<String>,
+    members: Vec<ArchiveMember>,
+    added_archives: Vec<PathBuf>,
+    should_update_symbols: bool,
+}
+
+struct ArchiveMember {
+    name: String,
+    data: MemberData,
+}
+
+enum MemberData {
+    File(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+impl<'a> ArchiveBuilder<'a> for ObjectArchiveBuilder<'a> {
+    fn new(sess: &'a Session, output: &Path, input: Option<&Path>) -> Self {
+        let mut builder = ObjectArchiveBuilder {
+            sess,
+            dst: output.to_path_buf(),
+            lib_search_paths: archive_search_paths(sess),
+            src_files: Vec::new(),
+            members: Vec::new(),
+            added_archives: Vec::new(),
+            should_update_symbols: false,
+        };
+        if let Some(input) = input.filter(|input| input.exists()) {
+            builder.add_archive(input, |_| false).unwrap_or_else(|e| {
+                sess.fatal(&format!("failed to read archive `{}`: {}", input.display(), e))
+            });
+            builder.src_files = builder.members.iter().map(|m| m.name.clone()).collect();
+        }
+        builder
+    }
+
+    fn add_file(&mut self, path: &Path) {
+        let name = path.file_name().unwrap().to_str().unwrap().to_owned();
+        self.members.push(ArchiveMember { name, data: MemberData::File(path.to_path_buf()) });
+    }
+
+    fn remove_file(&mut self, name: &str) {
+        self.src_files.retain(|file| file != name);
+        self.members.retain(|member| member.name != name);
+    }
+
+    fn src_files(&mut self) -> Vec<String> {
+        self.src_files.clone()
+    }
+
+    fn add_rlib(
+        &mut self,
+        rlib: &Path,
+        name: &str,
+        lto: bool,
+        skip_objects: bool,
+    ) -> io::Result<()> {
+        let obj_start = name.to_owned();
+        self.add_archive(rlib, |fname| {
+            // Ignore metadata files, and Rust objects when they are going to be linked with LTO.
+            fname == METADATA_FILENAME
+                || (lto && looks_like_rust_object_file(fname))
+                || (skip_objects && (!fname.starts_with(&obj_start) || !fname.ends_with(".o")))
+        })
+    }
+
+    fn add_native_library(&mut self, name: Symbol) {
+        let location = find_library(name, &self.lib_search_paths, self.sess);
+        self.add_archive(&location, |_| false).unwrap_or_else(|e| {
+            self.sess.fatal(&format!(
+                "failed to add native library {}: {}",
+                location.to_string_lossy(),
+                e
+            ));
+        });
+    }
+
+    fn update_symbols(&mut self) {
+        self.should_update_symbols = true;
+    }
+
+    fn build(self) {
+        let sess = self.sess;
+        let dst = self.dst.clone();
+        if let Err(e) = self.write_archive() {
+            sess.fatal(&format!("failed to build archive `{}`: {}", dst.display(), e));
+        }
+    }
+}
+
+impl<'a> ObjectArchiveBuilder<'a> {
+    fn add_archive(&mut self, path: &Path, mut skip: impl FnMut(&str) -> bool) -> io::Result<()> {
+        if self.added_archives.iter().any(|added| added == path) {
+            return Ok(());
+        }
+        let data = fs::read(path)?;
+        let archive = ArchiveFile::parse(&*data).map_err(invalid_data)?;
+        for member in archive.members() {
+            let member = member.map_err(invalid_data)?;
+            let name = String::from_utf8_lossy(member.name()).into_owned();
+            // Only keep the file name, like the LLVM archive writer.
+            let name = match Path::new(&name).file_name().and_then(|name| name.to_str()) {
+                Some(name) if !name.contains("SYMDEF") => name.to_owned(),
+                _ => continue,
+            };
+            if skip(&name) {
+                continue;
+            }
+            let data = MemberData::Bytes(member.data().to_vec());
+            self.members.push(ArchiveMember { name, data });
+        }
+        self.added_archives.push(path.to_path_buf());
+        Ok(())
+    }
+
+    fn write_archive(self) -> io::Result<()> {
+        let kind = match &self.sess.target.options.archive_format[..] {
+            "gnu" | "coff" => ArchiveKind::Gnu,
+            "bsd" | "darwin" => ArchiveKind::Bsd,
+            kind => {
+                let msg = format!("unsupported archive format `{}`", kind);
+                return Err(io::Error::new(io::ErrorKind::Other, msg));
+            }
+        };
+
+        let mut members = Vec::with_capacity(self.members.len());
+        for member in self.members {
+            let data = match member.data {
+                MemberData::File(path) => fs::read(path)?,
+                MemberData::Bytes(data) => data,
+            };
+            members.push((member.name, data));
+        }
+
+        let symbols = self.should_update_symbols.then(|| archive_symbols(&members));
+        let big_endian = self.sess.target.target_endian == "big";
+        let archive = match kind {
+            ArchiveKind::Gnu => write_gnu_archive(&members, symbols.as_deref()),
+            ArchiveKind::Bsd => write_bsd_archive(&members, symbols.as_deref(), big_endian),
+        };
+        fs::write(&self.dst, archive)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum ArchiveKind {
+    Gnu,
+    Bsd,
+}
+
+const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+const HEADER_SIZE: usize = 60;
+
+/// Returns the global symbols defined by the object files among `members`, along with the
+/// index of the member defining them.
+fn archive_symbols(members: &[(String, Vec<u8>)]) -> Vec<(usize, Vec<u8>)> {
+    let mut symbols = Vec::new();
+    for (i, (_, data)) in members.iter().enumerate() {
+        // Members which aren't object files, like the metadata, don't have symbols.
+        let file = match object::File::parse(data) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        for (_, symbol) in file.symbols() {
+            if symbol.is_global() && !symbol.is_undefined() {
+                if let Some(name) = symbol.name().filter(|name| !name.is_empty()) {
+                    symbols.push((i, name.as_bytes().to_vec()));
+                }
+            }
+        }
+    }
+    symbols
+}
+
+fn invalid_data(e: object::read::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+fn write_header(out: &mut Vec<u8>, name: &str, size: usize) {
+    // The timestamps, owners and modes are zeroed so that archives are reproducible.
+    let header = format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", name, 0, 0, 0, 644, size);
+    assert_eq!(header.len(), HEADER_SIZE, "archive member `{}` is too large", name);
+    out.extend_from_slice(header.as_bytes());
+}
+
+/// Writes an archive in the GNU format, which is also the format of COFF archives. Names
+/// which don't fit in the header are stored in the `//` member.
+fn write_gnu_archive(
+    members: &[(String, Vec<u8>)],
+    symbols: Option<&[(usize, Vec<u8>)]>,
+) -> Vec<u8> {
+    let padded = |size: usize| size + size % 2;
+
+    let mut long_names = Vec::new();
+    let header_names: Vec<String> = members
+        .iter()
+        .map(|(name, _)| {
+            if name.len() < 16 && !name.contains('/') {
+                format!("{}/", name)
+            } else {
+                let offset = long_names.len();
+                long_names.extend_from_slice(name.as_bytes());
+                long_names.extend_from_slice(b"/\n");
+                format!("/{}", offset)
+            }
+        })
+        .collect();
+
+    let symbol_table_size = symbols.map(|symbols| {
+        4 + symbols.iter().map(|(_, name)| 4 + name.len() + 1).sum::<usize>()
+    });
+
+    let mut offset = ARCHIVE_MAGIC.len();
+    if let Some(size) = symbol_table_size {
+        offset += HEADER_SIZE + padded(size);
+    }
+    if !long_names.is_empty() {
+        offset += HEADER_SIZE + padded(long_names.len());
+    }
+    let mut member_offsets = Vec::with_capacity(members.len());
+    for (_, data) in members {
+        member_offsets.push(offset);
+        offset += HEADER_SIZE + padded(data.len());
+    }
+
+    let mut out = Vec::with_capacity(offset);
+    out.extend_from_slice(ARCHIVE_MAGIC);
+    let write_member = |out: &mut Vec<u8>, name: &str, data: &[u8]| {
+        write_header(out, name, data.len());
+        out.extend_from_slice(data);
+        if data.len() % 2 != 0 {
+            out.push(b'\n');
+        }
+    };
+    if let (Some(symbols), Some(size)) = (symbols, symbol_table_size) {
+        let mut table = Vec::with_capacity(size);
+        table.extend_from_slice(&(symbols.len() as u32).to_be_bytes());
+        for &(member, _) in symbols {
+            table.extend_from_slice(&(member_offsets[member] as u32).to_be_bytes());
+        }
+        for (_, name) in symbols {
+            table.extend_from_slice(name);
+            table.push(0);
+        }
+        write_member(&mut out, "/", &table);
+    }
+    if !long_names.is_empty() {
+        write_member(&mut out, "//", &long_names);
+    }
+    for ((_, data), name) in members.iter().zip(header_names) {
+        write_member(&mut out, &name, data);
+    }
+    out
+}
+
+/// Writes an archive in the BSD format used by Apple's tools. All the names are stored at
+/// the start of the member data, padded so that the data of every member is aligned to 8
+/// bytes, as the linker expects for object files.
+fn write_bsd_archive(
+    members: &[(String, Vec<u8>)],
+    symbols: Option<&[(usize, Vec<u8>)]>,
+    big_endian: bool,
+) -> Vec<u8> {
+    // Members start at 8-byte aligned offsets, so their data is aligned if the size of the
+    // stored name is 4 modulo 8, to account for the 60 bytes of the header.
+    let name_size = |name: &str| {
+        let size = name.len() + 1;
+        size + (12 - size % 8) % 8
+    };
+    let member_size = |name: &str, data_len: usize| {
+        let size = name_size(name) + data_len;
+        size + (8 - (HEADER_SIZE + size) % 8) % 8
+    };
+    let u32_bytes =
+        |n: usize| if big_endian { (n as u32).to_be_bytes() } else { (n as u32).to_le_bytes() };
+
+    const SYMDEF_NAME: &str = "__.SYMDEF";
+    let string_table_size = symbols
+        .map(|symbols| symbols.iter().map(|(_, name)| name.len() + 1).sum::<usize>());
+    let symbol_table_size =
+        symbols.map(|symbols| 4 + 8 * symbols.len() + 4 + string_table_size.unwrap());
+
+    let mut offset = ARCHIVE_MAGIC.len();
+    if let Some(size) = symbol_table_size {
+        offset += HEADER_SIZE + member_size(SYMDEF_NAME, size);
+    }
+    let mut member_offsets = Vec::with_capacity(members.len());
+    for (name, data) in members {
+        member_offsets.push(offset);
+        offset += HEADER_SIZE + member_size(name, data.len());
+    }
+
+    let mut out = Vec::with_capacity(offset);
+    out.extend_from_slice(ARCHIVE_MAGIC);
+    let write_member = |out: &mut Vec<u8>, name: &str, data: &[u8]| {
+        let name_size = name_size(name);
+        let size = member_size(name, data.len());
+        write_header(out, &format!("#1/{}", name_size), size);
+        out.extend_from_slice(name.as_bytes());
+        out.resize(out.len() + name_size - name.len(), 0);
+        out.extend_from_slice(data);
+        out.resize(out.len() + size - name_size - data.len(), b'\n');
+    };
+    if let (Some(symbols), Some(size)) = (symbols, symbol_table_size) {
+        let mut table = Vec::with_capacity(size);
+        table.extend_from_slice(&u32_bytes(8 * symbols.len()));
+        let mut string_offset = 0;
+        for (member, name) in symbols {
+            table.extend_from_slice(&u32_bytes(string_offset));
+            table.extend_from_slice(&u32_bytes(member_offsets[*member]));
+            string_offset += name.len() + 1;
+        }
+        table.extend_from_slice(&u32_bytes(string_table_size.unwrap()));
+        for (_, name) in symbols {
+            table.extend_from_slice(name);
+            table.push(0);
+        }
+        write_member(&mut out, SYMDEF_NAME, &table);
+    }
+    for (name, data) in members {
+        write_member(&mut out, name, data);
+    }
+    out
+}
@@ -0,0 +1,165 @@
+//! Backend-independent reading and writing of the crate metadata.
+
+use object::elf;
+use object::read::archive::ArchiveFile;
+use object::write::{self, StandardSegment, Symbol, SymbolSection};
+use object::{
+    Architecture, BinaryFormat, Endianness, FileFlags, Object, ObjectSection, SectionKind,
+    SymbolFlags, SymbolKind, SymbolScope,
+};
+
+use rustc_data_structures::owning_ref::OwningRef;
+use rustc_data_structures::rustc_erase_owner;
+use rustc_data_structures::sync::MetadataRef;
+use rustc_middle::middle::cstore::{EncodedMetadata, MetadataLoader};
+use rustc_middle::middle::exported_symbols;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::Session;
+use rustc_target::spec::{RelocModel, Target};
+
+use snap::write::FrameEncoder;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::METADATA_FILENAME;
+
+/// A [`MetadataLoader`] which reads the metadata of rlibs and dylibs with the `object` crate,
+/// for backends which don't have an object file reader of their own.
+pub struct DefaultMetadataLoader;
+
+impl MetadataLoader for DefaultMetadataLoader {
+    fn get_rlib_metadata(&self, _target: &Target, path: &Path) -> Result<MetadataRef, String> {
+        load_metadata_with(path, |data| {
+            let archive = ArchiveFile::parse(data).map_err(|e| {
+                format!("failed to parse rlib `{}`: {}", path.display(), e)
+            })?;
+            for member in archive.members() {
+                let member = member.map_err(|e| {
+                    format!("failed to parse rlib `{}`: {}", path.display(), e)
+                })?;
+                if member.name() == METADATA_FILENAME.as_bytes() {
+                    return Ok(member.data());
+                }
+            }
+            Err(format!("metadata not found in rlib `{}`", path.display()))
+        })
+    }
+
+    fn get_dylib_metadata(&self, _target: &Target, path: &Path) -> Result<MetadataRef, String> {
+        load_metadata_with(path, |data| {
+            let file = object::File::parse(data).map_err(|e| {
+                format!("failed to parse dylib `{}`: {}", path.display(), e)
+            })?;
+            file.section_by_name(".rustc")
+                .and_then(|section| section.data().ok())
+                .ok_or_else(|| format!("metadata not found in dylib `{}`", path.display()))
+        })
+    }
+}
+
+fn load_metadata_with(
+    path: &Path,
+    f: impl for<'a> FnOnce(&'a [u8]) -> Result<&'a [u8], String>,
+) -> Result<MetadataRef, String> {
+    let data = fs::read(path)
+        .map_err(|e| format!("failed to read metadata from `{}`: {}", path.display(), e))?;
+    let metadata = OwningRef::new(Box::new(data)).try_map(|data| f(data))?;
+    Ok(rustc_erase_owner!(metadata))
+}
+
+/// Returns an empty object file for the target of `sess`, or `None` if the `object` crate
+/// doesn't know how to write object files for this target. In that case the backend has to
+/// take care of the metadata object itself.
+fn create_object_file(sess: &Session) -> Option<write::Object> {
+    let endianness = match &sess.target.target_endian[..] {
+        "little" => Endianness::Little,
+        "big" => Endianness::Big,
+        _ => return None,
+    };
+    let architecture = match &sess.target.arch[..] {
+        "arm" => Architecture::Arm,
+        "aarch64" => Architecture::Aarch64,
+        "x86" => Architecture::I386,
+        "s390x" => Architecture::S390x,
+        "mips" => Architecture::Mips,
+        "x86_64" => Architecture::X86_64,
+        _ => return None,
+    };
+    let binary_format = if sess.target.options.is_like_osx {
+        BinaryFormat::MachO
+    } else if sess.target.options.is_like_windows {
+        BinaryFormat::Coff
+    } else {
+        BinaryFormat::Elf
+    };
+
+    let mut file = write::Object::new(binary_format, architecture, endianness);
+    if architecture == Architecture::Mips && binary_format == BinaryFormat::Elf {
+        // The linker refuses to link objects with different MIPS ABIs and architectures, so
+        // the metadata object has to use the flags of the objects emitted by the backend.
+        let cpu = &sess.target.options.cpu[..];
+        let arch = match cpu {
+            "mips1" => elf::EF_MIPS_ARCH_1,
+            "mips2" => elf::EF_MIPS_ARCH_2,
+            "mips3" => elf::EF_MIPS_ARCH_3,
+            "mips4" => elf::EF_MIPS_ARCH_4,
+            "mips5" => elf::EF_MIPS_ARCH_5,
+            cpu if cpu.contains("r6") => elf::EF_MIPS_ARCH_32R6,
+            _ => elf::EF_MIPS_ARCH_32R2,
+        };
+        // The only ABI LLVM supports for 32-bit MIPS CPUs is o32.
+        let mut e_flags = elf::EF_MIPS_CPIC | elf::EF_MIPS_ABI_O32 | arch;
+        if sess.target.options.relocation_model != RelocModel::Static {
+            e_flags |= elf::EF_MIPS_PIC;
+        }
+        if cpu.contains("r6") {
+            e_flags |= elf::EF_MIPS_NAN2008;
+        }
+        file.flags = FileFlags::Elf { e_flags };
+    }
+    Some(file)
+}
+
+/// Creates an object file containing the compressed metadata of the local crate in its
+/// `.rustc` section, exported under the metadata symbol name.
+///
+/// Returns `None` if the target's object file format is not supported.
+pub fn create_compressed_metadata_file(
+    tcx: TyCtxt<'_>,
+    metadata: &EncodedMetadata,
+) -> Option<Vec<u8>> {
+    let mut file = create_object_file(tcx.sess)?;
+
+    let mut compressed = tcx.metadata_encoding_version();
+    FrameEncoder::new(&mut compressed).write_all(&metadata.raw_data).unwrap();
+
+    // On ELF, the section is emitted without any flags so that the metadata doesn't get
+    // loaded into memory, just like the `.section` directive the LLVM backend used to emit.
+    let kind = match file.format() {
+        BinaryFormat::Elf => SectionKind::Other,
+        _ => SectionKind::ReadOnlyData,
+    };
+    let segment = file.segment_name(StandardSegment::Data).to_vec();
+    let section = file.add_section(segment, b".rustc".to_vec(), kind);
+    let offset = file.append_section_data(section, &compressed, 1);
+
+    // For Mach-O and probably PE this is necessary to prevent the linker from throwing away
+    // the `.rustc` section. For ELF this isn't necessary, but it also doesn't harm.
+    let symbol_name = exported_symbols::metadata_symbol_name(tcx);
+    file.add_symbol(Symbol {
+        name: symbol_name.into_bytes(),
+        value: offset,
+        size: compressed.len() as u64,
+        kind: SymbolKind::Data,
+        scope: SymbolScope::Dynamic,
+        weak: false,
+        section: SymbolSection::Section(section),
+        flags: SymbolFlags::None,
+    });
+
+    match file.write() {
+        Ok(data) => Some(data),
+        Err(err) => tcx.sess.fatal(&format!("failed to write metadata object file: {}", err)),
+    }
+}
@@ -407,6 +407,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
     backend: B,
     tcx: TyCtxt<'_>,
     metadata: EncodedMetadata,
+    metadata_module: Option<CompiledModule>,
     total_cgus: usize,
 ) -> OngoingCodegen<B> {
     let (coordinator_send, coordinator_receive) = channel();
@@ -450,6 +451,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
         shared_emitter,
         codegen_worker_send,
         coordinator_receive,
+        metadata_module,
         total_cgus,
         sess.jobserver.clone(),
         Arc::new(regular_config),
@@ -931,6 +933,7 @@ fn start_executing_work<B: ExtraBackendMethods>(
     shared_emitter: SharedEmitter,
     codegen_worker_send: Sender<Message<B>>,
     coordinator_receive: Receiver<Box<dyn Any + Send>>,
+    metadata_module: Option<CompiledModule>,
     total_cgus: usize,
     jobserver: Client,
     regular_config: Arc<ModuleConfig>,
@@ -1179,7 +1182,9 @@ fn start_executing_work<B: ExtraBackendMethods>(
         // This is where we collect codegen units that have gone all the way
         // through codegen and LLVM.
         let mut compiled_modules = vec![];
-        let mut compiled_metadata_module = None;
+        // The metadata module may already have been written by `codegen_crate`, in which
+        // case it never goes through the backend.
+        let mut compiled_metadata_module = metadata_module;
         let mut compiled_allocator_module = None;
         let mut needs_link = Vec::new();
         let mut needs_fat_lto = Vec::new();
@@ -23,7 +23,8 @@ use crate::mir;
 use crate::mir::operand::OperandValue;
 use crate::mir::place::PlaceRef;
 use crate::traits::*;
-use crate::{CachedModuleCodegen, CrateInfo, MemFlags, ModuleCodegen, ModuleKind};
+use crate::back::metadata::create_compressed_metadata_file;
+use crate::{CachedModuleCodegen, CompiledModule, CrateInfo, MemFlags, ModuleCodegen, ModuleKind};
 
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashMap;
@@ -43,7 +44,7 @@ use rustc_middle::ty::layout::{FAT_PTR_ADDR, FAT_PTR_EXTRA};
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
 use rustc_session::cgu_reuse_tracker::CguReuse;
-use rustc_session::config::{self, EntryFnType};
+use rustc_session::config::{self, EntryFnType, OutputType};
 use rustc_session::utils::NativeLibKind;
 use rustc_session::Session;
 use rustc_span::Span;
@@ -51,6 +52,7 @@ use rustc_symbol_mangling::test as symbol_names_test;
 use rustc_target::abi::{Align, LayoutOf, VariantIdx};
 
 use std::cmp;
+use std::fs;
 use std::ops::{Deref, DerefMut};
 use std::time::{Duration, Instant};
 
@@ -459,6 +461,22 @@ pub fn maybe_create_entry_wrapper<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     }
 }
 
+/// Writes the compressed metadata of the local crate into an object file using
+/// `back::metadata`, returning `None` if the target isn't supported there.
+fn write_metadata_object(
+    tcx: TyCtxt<'_>,
+    metadata: &EncodedMetadata,
+    name: String,
+) -> Option<CompiledModule> {
+    let data = create_compressed_metadata_file(tcx, metadata)?;
+    let path = tcx.output_filenames(LOCAL_CRATE).temp_path(OutputType::Object, Some(&name));
+    if let Err(err) = fs::write(&path, data) {
+        let msg = format!("error writing metadata object file `{}`: {}", path.display(), err);
+        tcx.sess.fatal(&msg);
+    }
+    Some(CompiledModule { name, kind: ModuleKind::Metadata, object: Some(path), bytecode: None })
+}
+
 /// Obtain the `argc` and `argv` values to pass to the rust start function.
 fn get_argc_argv<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     cx: &'a Bx::CodegenCx,
@@ -487,7 +505,7 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
 ) -> OngoingCodegen<B> {
     // Skip crate items and just output metadata in -Z no-codegen mode.
     if tcx.sess.opts.debugging_opts.no_codegen || !tcx.sess.opts.output_types.should_codegen() {
-        let ongoing_codegen = start_async_codegen(backend, tcx, metadata, 1);
+        let ongoing_codegen = start_async_codegen(backend, tcx, metadata, None, 1);
 
         ongoing_codegen.codegen_finished(tcx);
 
@@ -515,7 +533,26 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
         }
     }
 
-    let ongoing_codegen = start_async_codegen(backend.clone(), tcx, metadata, codegen_units.len());
+    // Write the metadata object ourselves if the `object` crate supports the target, so
+    // that backends don't have to. Otherwise we fall back to the backend's implementation.
+    let metadata_module = if need_metadata_module {
+        let metadata_cgu_name =
+            cgu_name_builder.build_cgu_name(LOCAL_CRATE, &["crate"], Some("metadata")).to_string();
+        tcx.sess.time("write_compressed_metadata", || {
+            write_metadata_object(tcx, &metadata, metadata_cgu_name)
+        })
+    } else {
+        None
+    };
+    let need_backend_metadata_module = need_metadata_module && metadata_module.is_none();
+
+    let ongoing_codegen = start_async_codegen(
+        backend.clone(),
+        tcx,
+        metadata,
+        metadata_module,
+        codegen_units.len(),
+    );
     let ongoing_codegen = AbortCodegenOnDrop::<B>(Some(ongoing_codegen));
 
     // Codegen an allocator shim, if necessary.
@@ -549,7 +586,7 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
         ongoing_codegen.submit_pre_codegened_module_to_llvm(tcx, allocator_module);
     }
 
-    if need_metadata_module {
+    if need_backend_metadata_module {
         // Codegen the encoded metadata.
         let metadata_cgu_name =
             cgu_name_builder.build_cgu_name(LOCAL_CRATE, &["crate"], Some("metadata")).to_string();
@@ -1,5 +1,6 @@
 use crate::base;
 use crate::traits::*;
+use rustc_data_structures::profiling::TimingGuard;
 use rustc_hir as hir;
 use rustc_middle::mir::mono::{Linkage, Visibility};
 use rustc_middle::ty::layout::HasTyCtxt;
@@ -39,6 +40,13 @@ impl<'a, 'tcx: 'a> MonoItemExt<'a, 'tcx> for MonoItem<'tcx> {
                 }
             }
             MonoItem::Fn(instance) => {
+                // Only pay for the name of the instance when it is going to be recorded.
+                let _timer = if cx.tcx().prof.enabled() {
+                    let instance_name = instance.to_string();
+                    cx.tcx().prof.generic_activity_with_arg("codegen_instance", instance_name)
+                } else {
+                    TimingGuard::none()
+                };
                 base::codegen_instance::<Bx>(&cx, instance);
             }
         }
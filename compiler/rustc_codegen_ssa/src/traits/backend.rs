@@ -98,6 +98,8 @@ pub trait CodegenBackend {
 
 pub trait ExtraBackendMethods: CodegenBackend + WriteBackendMethods + Sized + Send + Sync {
     fn new_metadata(&self, sess: TyCtxt<'_>, mod_name: &str) -> Self::Module;
+    /// Only used for targets whose object file format isn't supported by
+    /// `back::metadata::create_compressed_metadata_file`.
     fn write_compressed_metadata<'tcx>(
         &self,
         tcx: TyCtxt<'tcx>,
@@ -79,6 +79,16 @@
 //! invocation) and allocate the corresponding strings together with a mapping
 //! for `DepNodeIndex as StringId`.
 //!
+//!
+//! ## Summaries
+//!
+//! Analyzing the raw event data requires the tools from the `measureme`
+//! project. For quick investigations `-Z self-profile-summary` additionally
+//! aggregates query provider and generic activity events in memory and prints
+//! the most expensive queries, activities and items, as well as the sizes of
+//! the codegen units, when the `SelfProfiler` is dropped at the end of the
+//! compilation session. See `Summary` for the details.
+//!
 //! [mm]: https://github.com/rust-lang/measureme/
 
 use crate::cold_path;
@@ -86,6 +96,7 @@ use crate::fx::FxHashMap;
 
 use std::borrow::Borrow;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::convert::Into;
 use std::error::Error;
 use std::fs;
@@ -95,7 +106,9 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use measureme::{EventId, EventIdBuilder, SerializableString, StringId};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use rustc_serialize::json::{Json, ToJson};
+use tempfile::TempDir;
 
 cfg_if! {
     if #[cfg(any(windows, target_os = "wasi"))] {
@@ -162,6 +175,13 @@ const EVENT_FILTERS_BY_NAME: &[(&str, EventFilter)] = &[
 /// Something that uniquely identifies a query invocation.
 pub struct QueryInvocationId(pub u32);
 
+/// The output format of `-Z self-profile-summary`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Text,
+    Json,
+}
+
 /// A reference to the SelfProfiler. It can be cloned and sent across thread
 /// boundaries at will.
 #[derive(Clone)]
@@ -267,9 +287,9 @@ impl SelfProfilerRef {
     #[inline(always)]
     pub fn generic_activity(&self, event_label: &'static str) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let event_label = profiler.get_or_alloc_cached_string(event_label);
-            let event_id = EventId::from_label(event_label);
+            let event_id = EventId::from_label(profiler.get_or_alloc_cached_string(event_label));
             TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+                .with_summary(profiler, SummaryEvent::Activity(event_label, None))
         })
     }
 
@@ -286,14 +306,23 @@ impl SelfProfilerRef {
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
             let builder = EventIdBuilder::new(&profiler.profiler);
+            let label = event_label;
             let event_label = profiler.get_or_alloc_cached_string(event_label);
+            let mut summary_arg = None;
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
+                if profiler.summary.is_some() {
+                    summary_arg = Some(event_arg.borrow().to_owned());
+                }
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
                 builder.from_label_and_arg(event_label, event_arg)
             } else {
+                if profiler.summary.is_some() {
+                    summary_arg = Some(event_arg.into());
+                }
                 builder.from_label(event_label)
             };
             TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+                .with_summary(profiler, SummaryEvent::Activity(label, summary_arg))
         })
     }
 
@@ -303,6 +332,7 @@ impl SelfProfilerRef {
     pub fn query_provider(&self) -> TimingGuard<'_> {
         self.exec(EventFilter::QUERY_PROVIDERS, |profiler| {
             TimingGuard::start(profiler, profiler.query_event_kind, EventId::INVALID)
+                .with_summary(profiler, SummaryEvent::Query)
         })
     }
 
@@ -393,14 +423,31 @@ pub struct SelfProfiler {
     incremental_load_result_event_kind: StringId,
     query_blocked_event_kind: StringId,
     query_cache_hit_event_kind: StringId,
+
+    /// In-memory aggregation of events for `-Z self-profile-summary`.
+    summary: Option<Summary>,
+
+    /// The directory the raw event data is written to when the profiler only exists for the
+    /// summary. Declared after `profiler` so that the files are closed before it is removed.
+    _summary_only_dir: Option<TempDir>,
 }
 
 impl SelfProfiler {
+    /// Creates a profiler writing its event data to `output_directory`. Without an output
+    /// directory, the profiler only exists for the summary and the event data is written to a
+    /// temporary directory that is removed along with the profiler.
     pub fn new(
-        output_directory: &Path,
+        output_directory: Option<&Path>,
         crate_name: Option<&str>,
         event_filters: &Option<Vec<String>>,
+        summary_format: Option<SummaryFormat>,
     ) -> Result<SelfProfiler, Box<dyn Error>> {
+        let summary_only_dir = match output_directory {
+            Some(_) => None,
+            None => Some(tempfile::Builder::new().prefix("rustc-self-profile").tempdir()?),
+        };
+        let output_directory =
+            output_directory.unwrap_or_else(|| summary_only_dir.as_ref().unwrap().path());
         fs::create_dir_all(output_directory)?;
 
         let crate_name = crate_name.unwrap_or("unknown-crate");
@@ -447,6 +494,11 @@ impl SelfProfiler {
             event_filter_mask = EventFilter::DEFAULT;
         }
 
+        // The summary is built from these events, so always record them.
+        if summary_format.is_some() {
+            event_filter_mask |= EventFilter::GENERIC_ACTIVITIES | EventFilter::QUERY_PROVIDERS;
+        }
+
         Ok(SelfProfiler {
             profiler,
             event_filter_mask,
@@ -456,6 +508,8 @@ impl SelfProfiler {
             incremental_load_result_event_kind,
             query_blocked_event_kind,
             query_cache_hit_event_kind,
+            summary: summary_format.map(|format| Summary::new(crate_name.to_owned(), format)),
+            _summary_only_dir: summary_only_dir,
         })
     }
 
@@ -514,10 +568,304 @@ impl SelfProfiler {
     pub fn event_id_builder(&self) -> EventIdBuilder<'_, SerializationSink> {
         EventIdBuilder::new(&self.profiler)
     }
+
+    pub fn summary_enabled(&self) -> bool {
+        self.summary.is_some()
+    }
+
+    /// Tells the summary which query (and, for item-level queries, which item)
+    /// a query invocation belongs to. Called when the query strings are allocated.
+    pub fn name_query_invocation_for_summary(
+        &self,
+        query_invocation_id: QueryInvocationId,
+        query_name: &'static str,
+        item: Option<String>,
+    ) {
+        if let Some(summary) = &self.summary {
+            summary.query_names.lock().insert(query_invocation_id.0, (query_name, item));
+        }
+    }
+
+    /// Records the size of a codegen unit for the summary.
+    pub fn record_cgu_size_for_summary(&self, cgu_name: &str, items: usize, size_estimate: usize) {
+        if let Some(summary) = &self.summary {
+            summary.cgu_sizes.lock().push((cgu_name.to_owned(), items, size_estimate));
+        }
+    }
+}
+
+impl Drop for SelfProfiler {
+    fn drop(&mut self) {
+        if let Some(summary) = &self.summary {
+            summary.print();
+        }
+    }
+}
+
+/// The events recorded in the summary, see `SelfProfiler::summary`.
+enum SummaryEvent {
+    /// A query provider; which query it was is only known once the query
+    /// strings are allocated, see `SelfProfiler::name_query_invocation_for_summary`.
+    Query,
+    /// A generic activity, with its argument if it has one.
+    Activity(&'static str, Option<String>),
+}
+
+/// An event that is currently running on some thread.
+struct OpenSummaryEvent {
+    /// The time spent in events nested within this one, which is not
+    /// counted towards its self time.
+    children: Duration,
+}
+
+#[derive(Default, Clone, Copy)]
+struct SummaryTimes {
+    self_time: Duration,
+    total_time: Duration,
+    count: usize,
+}
+
+impl SummaryTimes {
+    fn add(&mut self, other: SummaryTimes) {
+        self.self_time += other.self_time;
+        self.total_time += other.total_time;
+        self.count += other.count;
+    }
+}
+
+/// The generic activities whose argument names an item, reported along with the item-level
+/// queries in the "most expensive items" table of the summary.
+const SUMMARY_ITEM_ACTIVITIES: &[&str] = &["codegen_instance"];
+
+/// How many entries are shown in each table of the text summary.
+const SUMMARY_TABLE_LEN: usize = 20;
+
+/// In-memory aggregation of the query provider and generic activity events
+/// recorded during the session, printed at the end for `-Z self-profile-summary`.
+///
+/// The self time of an event is its total time minus the total time of the
+/// events that ran nested within it on the same thread.
+struct Summary {
+    crate_name: String,
+    format: SummaryFormat,
+    stacks: Mutex<FxHashMap<u64, Vec<OpenSummaryEvent>>>,
+    queries: Mutex<FxHashMap<u32, SummaryTimes>>,
+    query_names: Mutex<FxHashMap<u32, (&'static str, Option<String>)>>,
+    activities: Mutex<FxHashMap<(&'static str, Option<String>), SummaryTimes>>,
+    cgu_sizes: Mutex<Vec<(String, usize, usize)>>,
+}
+
+impl Summary {
+    fn new(crate_name: String, format: SummaryFormat) -> Summary {
+        Summary {
+            crate_name,
+            format,
+            stacks: Default::default(),
+            queries: Default::default(),
+            query_names: Default::default(),
+            activities: Default::default(),
+            cgu_sizes: Default::default(),
+        }
+    }
+
+    fn start(&self) {
+        let thread_id = std::thread::current().id().as_u64().get();
+        let mut stacks = self.stacks.lock();
+        stacks
+            .entry(thread_id)
+            .or_default()
+            .push(OpenSummaryEvent { children: Duration::default() });
+    }
+
+    fn finish(&self, event: SummaryEvent, query_invocation_id: Option<u32>, elapsed: Duration) {
+        let thread_id = std::thread::current().id().as_u64().get();
+        let children = {
+            let mut stacks = self.stacks.lock();
+            let stack = stacks.get_mut(&thread_id).expect("summary event finished twice");
+            let open = stack.pop().expect("summary event finished twice");
+            if let Some(parent) = stack.last_mut() {
+                parent.children += elapsed;
+            }
+            open.children
+        };
+
+        let times = SummaryTimes {
+            self_time: elapsed.checked_sub(children).unwrap_or(Duration::default()),
+            total_time: elapsed,
+            count: 1,
+        };
+
+        match (event, query_invocation_id) {
+            (SummaryEvent::Query, Some(id)) => {
+                self.queries.lock().entry(id).or_default().add(times);
+            }
+            // A query that was never assigned an invocation ID, e.g. because it panicked.
+            (SummaryEvent::Query, None) => {}
+            (SummaryEvent::Activity(label, arg), _) => {
+                self.activities.lock().entry((label, arg)).or_default().add(times);
+            }
+        }
+    }
+
+    fn print(&self) {
+        let queries = self.queries.lock();
+        let query_names = self.query_names.lock();
+
+        let mut by_query: FxHashMap<&'static str, SummaryTimes> = FxHashMap::default();
+        let mut by_item: Vec<(&'static str, &str, SummaryTimes)> = Vec::new();
+        for (id, times) in queries.iter() {
+            let (query_name, item) = match query_names.get(id) {
+                Some((query_name, item)) => (*query_name, item.as_deref()),
+                None => ("<unknown>", None),
+            };
+            by_query.entry(query_name).or_default().add(*times);
+            if let Some(item) = item {
+                by_item.push((query_name, item, *times));
+            }
+        }
+        let mut by_query: Vec<_> = by_query.into_iter().collect();
+        by_query.sort_by(|a, b| b.1.self_time.cmp(&a.1.self_time).then(a.0.cmp(b.0)));
+
+        let activities = self.activities.lock();
+        let mut by_activity: FxHashMap<&'static str, SummaryTimes> = FxHashMap::default();
+        for ((label, arg), times) in activities.iter() {
+            by_activity.entry(*label).or_default().add(*times);
+            if SUMMARY_ITEM_ACTIVITIES.contains(label) {
+                if let Some(item) = arg {
+                    by_item.push((*label, item.as_str(), *times));
+                }
+            }
+        }
+        by_item.sort_by(|a, b| b.2.self_time.cmp(&a.2.self_time).then(a.1.cmp(b.1)));
+        let mut by_activity: Vec<_> = by_activity.into_iter().collect();
+        by_activity.sort_by(|a, b| b.1.self_time.cmp(&a.1.self_time).then(a.0.cmp(b.0)));
+
+        let mut cgus: Vec<_> = self
+            .cgu_sizes
+            .lock()
+            .iter()
+            .map(|(name, items, size)| {
+                let codegen_time = activities
+                    .get(&("codegen_module", Some(name.clone())))
+                    .map(|times| times.total_time);
+                (name.clone(), *items, *size, codegen_time)
+            })
+            .collect();
+        cgus.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+
+        match self.format {
+            SummaryFormat::Text => {
+                println!("self-profile summary for `{}`", self.crate_name);
+                println!();
+                println!("{:>10}  {:>10}  {:>9}  query", "self time", "total time", "count");
+                for (query_name, times) in by_query.iter().take(SUMMARY_TABLE_LEN) {
+                    print_summary_times(times, query_name);
+                }
+                println!();
+                println!("{:>10}  {:>10}  {:>9}  activity", "self time", "total time", "count");
+                for (label, times) in by_activity.iter().take(SUMMARY_TABLE_LEN) {
+                    print_summary_times(times, label);
+                }
+                println!();
+                println!("{:>10}  {:>10}  {:>9}  item", "self time", "total time", "count");
+                for (kind, item, times) in by_item.iter().take(SUMMARY_TABLE_LEN) {
+                    print_summary_times(times, &format!("{}: {}", kind, item));
+                }
+                if !cgus.is_empty() {
+                    println!();
+                    println!(
+                        "{:>9}  {:>13}  {:>12}  codegen unit",
+                        "items", "size estimate", "codegen"
+                    );
+                    for (name, items, size, codegen_time) in &cgus {
+                        let codegen_time =
+                            codegen_time.map(duration_to_secs_str).unwrap_or_else(|| "-".into());
+                        println!("{:>9}  {:>13}  {:>12}  {}", items, size, codegen_time, name);
+                    }
+                }
+            }
+            SummaryFormat::Json => {
+                let times_to_json = |name: &str, times: &SummaryTimes| {
+                    let mut obj = BTreeMap::new();
+                    obj.insert("name".to_owned(), name.to_json());
+                    obj.insert("self_time".to_owned(), times.self_time.as_secs_f64().to_json());
+                    obj.insert("total_time".to_owned(), times.total_time.as_secs_f64().to_json());
+                    obj.insert("count".to_owned(), times.count.to_json());
+                    obj
+                };
+                let queries: Vec<_> = by_query
+                    .iter()
+                    .map(|(query_name, times)| Json::Object(times_to_json(query_name, times)))
+                    .collect();
+                let activities: Vec<_> = by_activity
+                    .iter()
+                    .map(|(label, times)| Json::Object(times_to_json(label, times)))
+                    .collect();
+                let items: Vec<_> = by_item
+                    .iter()
+                    .map(|(kind, item, times)| {
+                        let mut obj = times_to_json(item, times);
+                        obj.insert("kind".to_owned(), kind.to_json());
+                        Json::Object(obj)
+                    })
+                    .collect();
+                let cgus: Vec<_> = cgus
+                    .iter()
+                    .map(|(name, items, size, codegen_time)| {
+                        let mut obj = BTreeMap::new();
+                        obj.insert("name".to_owned(), name.to_json());
+                        obj.insert("items".to_owned(), items.to_json());
+                        obj.insert("size_estimate".to_owned(), size.to_json());
+                        obj.insert(
+                            "codegen_time".to_owned(),
+                            codegen_time.map(|t| t.as_secs_f64()).to_json(),
+                        );
+                        Json::Object(obj)
+                    })
+                    .collect();
+
+                let mut summary = BTreeMap::new();
+                summary.insert("crate".to_owned(), self.crate_name.to_json());
+                summary.insert("queries".to_owned(), Json::Array(queries));
+                summary.insert("activities".to_owned(), Json::Array(activities));
+                summary.insert("items".to_owned(), Json::Array(items));
+                summary.insert("codegen_units".to_owned(), Json::Array(cgus));
+                println!("{}", Json::Object(summary));
+            }
+        }
+    }
+}
+
+fn print_summary_times(times: &SummaryTimes, what: &str) {
+    println!(
+        "{:>10}  {:>10}  {:>9}  {}",
+        duration_to_secs_str(times.self_time),
+        duration_to_secs_str(times.total_time),
+        times.count,
+        what
+    );
+}
+
+/// Records an event in the summary when dropped, see `TimingGuard::with_summary`.
+struct SummaryGuard<'a> {
+    summary: &'a Summary,
+    event: Option<SummaryEvent>,
+    query_invocation_id: Option<u32>,
+    start: Instant,
+}
+
+impl Drop for SummaryGuard<'_> {
+    fn drop(&mut self) {
+        let event = self.event.take().unwrap();
+        self.summary.finish(event, self.query_invocation_id, self.start.elapsed());
+    }
 }
 
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a, SerializationSink>>);
+pub struct TimingGuard<'a> {
+    guard: Option<measureme::TimingGuard<'a, SerializationSink>>,
+    summary: Option<SummaryGuard<'a>>,
+}
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -530,23 +878,42 @@ impl<'a> TimingGuard<'a> {
         let raw_profiler = &profiler.profiler;
         let timing_guard =
             raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
-        TimingGuard(Some(timing_guard))
+        TimingGuard { guard: Some(timing_guard), summary: None }
+    }
+
+    /// Additionally records this event in the summary, if `-Z self-profile-summary` is enabled.
+    #[inline]
+    fn with_summary(mut self, profiler: &'a SelfProfiler, event: SummaryEvent) -> TimingGuard<'a> {
+        if let Some(summary) = &profiler.summary {
+            summary.start();
+            self.summary = Some(SummaryGuard {
+                summary,
+                event: Some(event),
+                query_invocation_id: None,
+                start: Instant::now(),
+            });
+        }
+        self
     }
 
     #[inline]
     pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocationId) {
-        if let Some(guard) = self.0 {
+        let TimingGuard { guard, summary } = self;
+        if let Some(guard) = guard {
             cold_path(|| {
                 let event_id = StringId::new_virtual(query_invocation_id.0);
                 let event_id = EventId::from_virtual(event_id);
                 guard.finish_with_override_event_id(event_id);
             });
         }
+        if let Some(mut summary) = summary {
+            summary.query_invocation_id = Some(query_invocation_id.0);
+        }
     }
 
     #[inline]
     pub fn none() -> TimingGuard<'a> {
-        TimingGuard(None)
+        TimingGuard { guard: None, summary: None }
     }
 
     #[inline(always)]
@@ -823,7 +823,7 @@ pub fn version(binary: &str, matches: &getopts::Matches) {
         println!("commit-date: {}", unw(commit_date_str()));
         println!("host: {}", config::host_triple());
         println!("release: {}", unw(release_str()));
-        get_builtin_codegen_backend("llvm")().print_version();
+        get_builtin_codegen_backend(&None, "llvm")().print_version();
     }
 }
 
@@ -1109,7 +1109,7 @@ pub fn handle_options(args: &[String]) -> Option<getopts::Matches> {
     }
 
     if cg_flags.iter().any(|x| *x == "passes=list") {
-        get_builtin_codegen_backend("llvm")().print_passes();
+        get_builtin_codegen_backend(&None, "llvm")().print_passes();
         return None;
     }
 
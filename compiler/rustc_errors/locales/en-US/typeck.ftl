@@ -0,0 +1,87 @@
+# Messages of the errors emitted by `rustc_typeck`, see `rustc_typeck::errors`.
+
+typeck-field-multiply-specified-in-initializer =
+    field `{$ident}` specified more than once
+    .label = used more than once
+    .previous-use-label = first use of `{$ident}`
+
+typeck-unrecognized-atomic-operation =
+    unrecognized atomic operation function: `{$op}`
+    .label = unrecognized atomic operation
+
+typeck-wrong-number-of-type-arguments-to-intrinsic =
+    intrinsic has wrong number of type parameters: found {$found}, expected {$expected}
+    .label = expected {$expected} type parameter
+
+typeck-unrecognized-intrinsic-function =
+    unrecognized intrinsic function: `{$name}`
+    .label = unrecognized intrinsic
+
+typeck-lifetimes-or-bounds-mismatch-on-trait =
+    lifetime parameters or bounds on {$item_kind} `{$ident}` do not match the trait declaration
+    .label = lifetimes do not match {$item_kind} in trait
+    .generics-label = lifetimes in impl do not match this {$item_kind} in trait
+
+typeck-drop-impl-on-wrong-item =
+    the `Drop` trait may only be implemented for structs, enums, and unions
+    .label = must be a struct, enum, or union
+
+typeck-field-already-declared =
+    field `{$field_name}` is already declared
+    .label = field already declared
+    .previous-decl-label = `{$field_name}` first declared here
+
+typeck-copy-impl-on-type-with-dtor =
+    the trait `Copy` may not be implemented for this type; the type has a destructor
+    .label = Copy not allowed on types with destructors
+
+typeck-assoc-type-on-inherent-impl =
+    associated types are not yet supported in inherent impls (see #8995)
+
+typeck-multiple-relaxed-default-bounds =
+    type parameter has more than one relaxed default bound, only one is supported
+
+typeck-copy-impl-on-non-adt =
+    the trait `Copy` may not be implemented for this type
+    .label = type is not a structure or enumeration
+
+typeck-trait-object-declared-with-no-traits =
+    at least one trait is required for an object type
+
+typeck-ambiguous-lifetime-bound =
+    ambiguous lifetime bound, explicit lifetime bound required
+
+typeck-assoc-type-binding-not-allowed =
+    associated type bindings are not allowed here
+    .label = associated type not allowed here
+
+typeck-simd-shuffle-missing-length =
+    invalid `simd_shuffle`, needs length: `{$name}`
+
+typeck-functional-record-update-on-non-struct =
+    functional record update syntax requires a struct
+
+typeck-typeof-reserved-keyword-used =
+    `typeof` is a reserved keyword but unimplemented
+    .label = reserved keyword
+
+typeck-return-stmt-outside-of-fn-body =
+    return statement outside of function body
+
+typeck-yield-expr-outside-of-generator =
+    yield expression outside of generator literal
+
+typeck-struct-expr-non-exhaustive =
+    cannot create non-exhaustive {$what} using struct expression
+
+typeck-method-call-on-unknown-type =
+    the type of this value must be known to call a method on a raw pointer on it
+
+typeck-value-of-associated-struct-already-specified =
+    the value of the associated type `{$item_name}` (from trait `{$def_path}`) is already specified
+    .label = re-bound here
+    .previous-bound-label = `{$item_name}` bound here first
+
+typeck-address-of-temporary-taken =
+    cannot take address of a temporary
+    .label = temporary value
@@ -15,7 +15,8 @@ use rustc_span::{MultiSpan, SourceFile, Span};
 use crate::snippet::{Annotation, AnnotationType, Line, MultilineAnnotation, Style, StyledString};
 use crate::styled_buffer::StyledBuffer;
 use crate::{
-    pluralize, CodeSuggestion, Diagnostic, DiagnosticId, Level, SubDiagnostic, SuggestionStyle,
+    pluralize, CodeSuggestion, Diagnostic, DiagnosticId, ExplanationNode, Level, SubDiagnostic,
+    SuggestionStyle,
 };
 
 use rustc_data_structures::fx::FxHashMap;
@@ -192,6 +193,18 @@ pub trait Emitter {
     /// other formats can, and will, simply ignore it.
     fn emit_artifact_notification(&mut self, _path: &Path, _artifact_type: &str) {}
 
+    /// Emit a report about the future-incompatible lints that fired, even if they were
+    /// allowed. This is currently only supported for the JSON format.
+    fn emit_future_breakage_report(&mut self, _diags: Vec<Diagnostic>) {}
+
+    /// Emit the list of the `--extern` crates which were not used by the crate.
+    /// This is currently only supported for the JSON format.
+    fn emit_unused_externs(&mut self, _unused_externs: &[&str]) {}
+
+    /// Emit an explanation tree of the given kind, which is also rendered in a note
+    /// of the diagnostic it explains. This is currently only supported for the JSON format.
+    fn emit_explanation(&mut self, _kind: &str, _tree: &ExplanationNode) {}
+
     /// Checks if should show explanations about "rustc --explain"
     fn should_show_explain(&self) -> bool {
         true
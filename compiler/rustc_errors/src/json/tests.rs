@@ -4,7 +4,7 @@ use crate::json::JsonEmitter;
 use rustc_span::source_map::{FilePathMapping, SourceMap};
 
 use crate::emitter::{ColorConfig, HumanReadableErrorType};
-use crate::Handler;
+use crate::{Handler, HandlerFlags};
 use rustc_serialize::json::decode;
 use rustc_span::{BytePos, Span};
 
@@ -202,3 +202,156 @@ fn span_after_crlf() {
         },
     )
 }
+
+#[derive(Decodable, Debug, PartialEq, Eq)]
+struct FutureIncompatTestData {
+    future_incompat_report: Vec<FutureBreakageTestData>,
+}
+
+#[derive(Decodable, Debug, PartialEq, Eq)]
+struct FutureBreakageTestData {
+    diagnostic: DiagnosticTestData,
+}
+
+#[derive(Decodable, Debug, PartialEq, Eq)]
+struct DiagnosticTestData {
+    message: String,
+    level: String,
+}
+
+#[test]
+fn future_breakage_report() {
+    with_default_session_globals(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let je = JsonEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            false,
+            HumanReadableErrorType::Short(ColorConfig::Never),
+            None,
+            false,
+        );
+        let handler = Handler::with_emitter(true, None, Box::new(je));
+
+        let lint = |name: &str| DiagnosticId::Lint {
+            name: name.to_string(),
+            has_future_breakage: name == "future_lint",
+        };
+        handler.struct_allow("allowed").code(lint("future_lint")).emit();
+        handler.struct_allow("other").code(lint("other_lint")).emit();
+        // Nothing is emitted for allowed lints.
+        assert!(output.lock().unwrap().is_empty());
+
+        let diags = handler.take_future_breakage_diagnostics();
+        assert!(handler.take_future_breakage_diagnostics().is_empty());
+        handler.emit_future_breakage_report(diags);
+
+        let bytes = output.lock().unwrap();
+        let actual_output: FutureIncompatTestData =
+            decode(str::from_utf8(&bytes).unwrap()).unwrap();
+        let expected_output = FutureIncompatTestData {
+            future_incompat_report: vec![FutureBreakageTestData {
+                diagnostic: DiagnosticTestData {
+                    message: "allowed".to_string(),
+                    level: "warning".to_string(),
+                },
+            }],
+        };
+        assert_eq!(expected_output, actual_output)
+    })
+}
+
+#[derive(Decodable, Debug, PartialEq, Eq)]
+struct ExplanationTestData {
+    explanation: String,
+    tree: ExplanationNodeTestData,
+}
+
+#[derive(Decodable, Debug, PartialEq, Eq)]
+struct ExplanationNodeTestData {
+    message: String,
+    children: Vec<ExplanationNodeTestData>,
+}
+
+#[test]
+fn explanation_tree() {
+    with_default_session_globals(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let je = JsonEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            false,
+            HumanReadableErrorType::Short(ColorConfig::Never),
+            None,
+            false,
+        );
+        let handler = Handler::with_emitter(true, None, Box::new(je));
+
+        let mut tree = ExplanationNode::new("`A: Trait`".to_string());
+        tree.children.push(ExplanationNode::new("no candidates".to_string()));
+        assert_eq!(tree.render(), "- `A: Trait`\n  - no candidates");
+        handler.emit_explanation("obligations", &tree);
+
+        let bytes = output.lock().unwrap();
+        let actual_output: ExplanationTestData = decode(str::from_utf8(&bytes).unwrap()).unwrap();
+        let expected_output = ExplanationTestData {
+            explanation: "obligations".to_string(),
+            tree: ExplanationNodeTestData {
+                message: "`A: Trait`".to_string(),
+                children: vec![ExplanationNodeTestData {
+                    message: "no candidates".to_string(),
+                    children: vec![],
+                }],
+            },
+        };
+        assert_eq!(expected_output, actual_output)
+    })
+}
+
+#[test]
+fn deterministic_order_keeps_standalone_notes() {
+    with_default_session_globals(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("test.rs").to_owned().into(), "fn a() {}\nfn b() {}\n".into());
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let je = JsonEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            false,
+            HumanReadableErrorType::Short(ColorConfig::Never),
+            None,
+            false,
+        );
+        let flags = HandlerFlags {
+            can_emit_warnings: true,
+            deterministic_diagnostic_order: true,
+            ..Default::default()
+        };
+        let handler = Handler::with_emitter_and_flags(Box::new(je), flags);
+
+        let span = |lo, hi| Span::with_root_ctxt(BytePos(lo), BytePos(hi));
+        handler.span_err(span(13, 14), "in `b`");
+        handler.note_without_error("about the error in `b`");
+        handler.span_err(span(3, 4), "in `a`");
+        // Dropping the handler emits the buffered diagnostics.
+        drop(handler);
+
+        let bytes = output.lock().unwrap();
+        let actual_output: Vec<DiagnosticTestData> =
+            str::from_utf8(&bytes).unwrap().lines().map(|line| decode(line).unwrap()).collect();
+        let expected_output = vec![
+            DiagnosticTestData { message: "in `a`".to_string(), level: "error".to_string() },
+            DiagnosticTestData { message: "in `b`".to_string(), level: "error".to_string() },
+            DiagnosticTestData {
+                message: "about the error in `b`".to_string(),
+                level: "note".to_string(),
+            },
+        ];
+        assert_eq!(expected_output, actual_output)
+    })
+}
@@ -6,6 +6,7 @@
 #![feature(crate_visibility_modifier)]
 #![feature(backtrace)]
 #![feature(nll)]
+#![feature(once_cell)]
 
 #[macro_use]
 extern crate rustc_macros;
@@ -17,10 +18,13 @@ use Level::*;
 
 use emitter::{is_case_difference, Emitter, EmitterWriter};
 use registry::Registry;
+use rustc_ast::AttrId;
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
-use rustc_data_structures::stable_hasher::StableHasher;
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_data_structures::sync::{self, Lock, Lrc};
 use rustc_data_structures::AtomicRef;
+use rustc_hir::HirId;
+use rustc_span::hygiene::{ExpnKind, SyntaxContext};
 use rustc_span::source_map::SourceMap;
 use rustc_span::{Loc, MultiSpan, Span};
 
@@ -40,7 +44,9 @@ mod lock;
 pub mod registry;
 mod snippet;
 mod styled_buffer;
+pub mod translation;
 pub use snippet::Style;
+pub use translation::{DiagnosticArg, MessageCatalog};
 
 pub type PResult<'a, T> = Result<T, DiagnosticBuilder<'a>>;
 
@@ -276,6 +282,40 @@ impl fmt::Display for ExplicitBug {
 
 impl error::Error for ExplicitBug {}
 
+/// A tree explaining how the compiler reached a conclusion, like the obligations it tried
+/// to prove for an error. It is added to the diagnostic as a note, and emitted as is for
+/// tools with the JSON format.
+#[derive(Clone, Debug, PartialEq, Encodable)]
+pub struct ExplanationNode {
+    pub message: String,
+    pub children: Vec<ExplanationNode>,
+}
+
+impl ExplanationNode {
+    pub fn new(message: String) -> ExplanationNode {
+        ExplanationNode { message, children: vec![] }
+    }
+
+    /// Renders the tree as a nested list, with a line per node.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        self.render_into(&mut rendered, 0);
+        rendered
+    }
+
+    fn render_into(&self, rendered: &mut String, depth: usize) {
+        if depth > 0 {
+            rendered.push('\n');
+        }
+        rendered.push_str(&"  ".repeat(depth));
+        rendered.push_str("- ");
+        rendered.push_str(&self.message);
+        for child in &self.children {
+            child.render_into(rendered, depth + 1);
+        }
+    }
+}
+
 pub use diagnostic::{Diagnostic, DiagnosticId, DiagnosticStyledString, SubDiagnostic};
 pub use diagnostic_builder::DiagnosticBuilder;
 
@@ -285,6 +325,8 @@ pub use diagnostic_builder::DiagnosticBuilder;
 pub struct Handler {
     flags: HandlerFlags,
     inner: Lock<HandlerInner>,
+    /// The messages of the language selected with `-Z translate-lang`, if any.
+    locale_catalog: Option<MessageCatalog>,
 }
 
 /// This inner struct exists to keep it all behind a single lock;
@@ -324,6 +366,82 @@ struct HandlerInner {
 
     /// The warning count, used for a recap upon finishing
     deduplicated_warn_count: usize,
+
+    /// Errors and warnings waiting to be emitted in a deterministic order, each with the
+    /// standalone notes and help messages reported right after it, see
+    /// `HandlerFlags::deterministic_diagnostic_order`.
+    ordered_diagnostics: Vec<(Diagnostic, Vec<Diagnostic>)>,
+
+    /// Diagnostics reported within macro expansions, waiting to be emitted once for all the
+    /// invocations of the macro, see `HandlerFlags::group_macro_diagnostics`.
+    macro_diagnostic_groups: FxIndexMap<MacroDiagnosticKey, MacroDiagnosticGroup>,
+
+    /// The diagnostics of future-incompatible lints, including the allowed ones, for the
+    /// future incompatibility report.
+    future_breakage_diagnostics: Vec<Diagnostic>,
+
+    /// The lint expectations fulfilled by the diagnostics at the `Expect` level, including
+    /// the ones replayed from the incremental cache.
+    fulfilled_expectations: FxHashSet<LintExpectationId>,
+}
+
+/// What makes diagnostics reported within different expansions of a macro the same: their
+/// level, code and message, and their primary span in the macro definition.
+#[derive(PartialEq, Eq, Hash)]
+struct MacroDiagnosticKey {
+    level: Level,
+    code: Option<DiagnosticId>,
+    message: String,
+    span: Span,
+}
+
+impl MacroDiagnosticKey {
+    fn new(diagnostic: &Diagnostic) -> Option<MacroDiagnosticKey> {
+        if !matches!(diagnostic.level, Error | Warning) {
+            return None;
+        }
+        let span = diagnostic.span.primary_span()?;
+        match span.ctxt().outer_expn_data().kind {
+            ExpnKind::Macro(..) => {}
+            _ => return None,
+        }
+        Some(MacroDiagnosticKey {
+            level: diagnostic.level,
+            code: diagnostic.code.clone(),
+            message: diagnostic.message(),
+            span: span.with_ctxt(SyntaxContext::root()),
+        })
+    }
+}
+
+struct MacroDiagnosticGroup {
+    /// The diagnostic reported within the first expansion.
+    diagnostic: Diagnostic,
+    /// The call sites of the expansions the diagnostic was reported within.
+    call_sites: Vec<Span>,
+}
+
+impl MacroDiagnosticGroup {
+    /// The diagnostic of the group, with a note listing the invocations of the macro when
+    /// there is more than one.
+    fn into_diagnostic(self) -> Diagnostic {
+        let MacroDiagnosticGroup { mut diagnostic, call_sites } = self;
+        if call_sites.len() > 1 {
+            let span = diagnostic.span.primary_span().unwrap();
+            let name = match span.ctxt().outer_expn_data().kind {
+                ExpnKind::Macro(_, name) => name,
+                _ => unreachable!(),
+            };
+            let msg = format!(
+                "this {} was reported for {} invocations of `{}`",
+                if diagnostic.is_error() { "error" } else { "warning" },
+                call_sites.len(),
+                name,
+            );
+            diagnostic.span_note(call_sites, &msg);
+        }
+        diagnostic
+    }
 }
 
 /// A key denoting where from a diagnostic was stashed.
@@ -356,11 +474,23 @@ pub struct HandlerFlags {
     pub macro_backtrace: bool,
     /// If true, identical diagnostics are reported only once.
     pub deduplicate_diagnostics: bool,
+    /// If true, errors and warnings are buffered and emitted sorted by their span before
+    /// aborting or printing the error count, so that the output doesn't depend on thread
+    /// scheduling.
+    /// (rustc: see `-Z deterministic-diagnostics`, enabled by default with `-Z threads`)
+    pub deterministic_diagnostic_order: bool,
+    /// If true, errors and warnings with the same code and message that point into the same
+    /// macro are emitted once, when the other buffered diagnostics are, with a note listing
+    /// the invocations of the macro they were reported for.
+    /// (rustc: see `-Z group-macro-diagnostics`)
+    pub group_macro_diagnostics: bool,
 }
 
 impl Drop for HandlerInner {
     fn drop(&mut self) {
         self.emit_stashed_diagnostics();
+        self.flush_macro_diagnostic_groups();
+        self.flush_ordered_diagnostics();
 
         if !self.has_errors() {
             let bugs = std::mem::replace(&mut self.delayed_span_bugs, Vec::new());
@@ -437,10 +567,27 @@ impl Handler {
                 emitted_diagnostic_codes: Default::default(),
                 emitted_diagnostics: Default::default(),
                 stashed_diagnostics: Default::default(),
+                ordered_diagnostics: Vec::new(),
+                macro_diagnostic_groups: Default::default(),
+                future_breakage_diagnostics: Vec::new(),
+                fulfilled_expectations: Default::default(),
             }),
+            locale_catalog: None,
         }
     }
 
+    /// Translates the messages of translatable diagnostics with `catalog`, falling back to
+    /// English for the messages it doesn't contain.
+    pub fn set_locale_catalog(&mut self, catalog: MessageCatalog) {
+        self.locale_catalog = Some(catalog);
+    }
+
+    /// Formats the message `id`, or its attribute `attr`, with `args` (see
+    /// `rustc_errors::translation`).
+    pub fn translate(&self, id: &str, attr: Option<&str>, args: &[DiagnosticArg<'_>]) -> String {
+        translation::translate_message(self.locale_catalog.as_ref(), id, attr, args)
+    }
+
     // This is here to not allow mutation of flags;
     // as of this writing it's only used in tests in librustc_middle.
     pub fn can_emit_warnings(&self) -> bool {
@@ -499,6 +646,39 @@ impl Handler {
         DiagnosticBuilder::new(self, Level::Cancelled, "")
     }
 
+    /// Construct a builder at the `Allow` level at the given `span` and with the `msg`.
+    pub fn struct_span_allow(
+        &self,
+        span: impl Into<MultiSpan>,
+        msg: &str,
+    ) -> DiagnosticBuilder<'_> {
+        let mut result = self.struct_allow(msg);
+        result.set_span(span);
+        result
+    }
+
+    /// Construct a builder at the `Allow` level with the `msg`.
+    pub fn struct_allow(&self, msg: &str) -> DiagnosticBuilder<'_> {
+        DiagnosticBuilder::new(self, Level::Allow, msg)
+    }
+
+    /// Construct a builder at the `Expect` level at the given `span` and with the `msg`.
+    pub fn struct_span_expect(
+        &self,
+        span: impl Into<MultiSpan>,
+        msg: &str,
+        id: LintExpectationId,
+    ) -> DiagnosticBuilder<'_> {
+        let mut result = self.struct_expect(msg, id);
+        result.set_span(span);
+        result
+    }
+
+    /// Construct a builder at the `Expect` level with the `msg`.
+    pub fn struct_expect(&self, msg: &str, id: LintExpectationId) -> DiagnosticBuilder<'_> {
+        DiagnosticBuilder::new(self, Level::Expect(id), msg)
+    }
+
     /// Construct a builder at the `Warning` level at the given `span` and with the `msg`.
     pub fn struct_span_warn(&self, span: impl Into<MultiSpan>, msg: &str) -> DiagnosticBuilder<'_> {
         let mut result = self.struct_warn(msg);
@@ -726,6 +906,28 @@ impl Handler {
         self.inner.borrow_mut().emit_artifact_notification(path, artifact_type)
     }
 
+    /// Takes the diagnostics of the future-incompatible lints that fired so far.
+    pub fn take_future_breakage_diagnostics(&self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.inner.borrow_mut().future_breakage_diagnostics)
+    }
+
+    /// Takes the ids of the lint expectations fulfilled so far.
+    pub fn steal_fulfilled_expectation_ids(&self) -> FxHashSet<LintExpectationId> {
+        std::mem::take(&mut self.inner.borrow_mut().fulfilled_expectations)
+    }
+
+    pub fn emit_future_breakage_report(&self, diags: Vec<Diagnostic>) {
+        self.inner.borrow_mut().emitter.emit_future_breakage_report(diags)
+    }
+
+    pub fn emit_unused_externs(&self, unused_externs: &[&str]) {
+        self.inner.borrow_mut().emitter.emit_unused_externs(unused_externs)
+    }
+
+    pub fn emit_explanation(&self, kind: &str, tree: &ExplanationNode) {
+        self.inner.borrow_mut().emitter.emit_explanation(kind, tree)
+    }
+
     pub fn delay_as_bug(&self, diagnostic: Diagnostic) {
         self.inner.borrow_mut().delay_as_bug(diagnostic)
     }
@@ -740,6 +942,28 @@ impl HandlerInner {
         self.emitter.emit_diagnostic(&db);
     }
 
+    /// Emit the diagnostics grouped because of `HandlerFlags::group_macro_diagnostics`. This
+    /// only happens once no more invocations can be added to the groups, i.e. when aborting
+    /// because of errors, printing the error count or dropping the handler, so that other
+    /// diagnostics reported in between don't split a group.
+    fn flush_macro_diagnostic_groups(&mut self) {
+        let groups = std::mem::take(&mut self.macro_diagnostic_groups);
+        for (_, group) in groups {
+            self.emit_deduplicated_diagnostic(&group.into_diagnostic());
+        }
+    }
+
+    /// Emit all diagnostics buffered because of `HandlerFlags::deterministic_diagnostic_order`,
+    /// sorted by their span and message.
+    fn flush_ordered_diagnostics(&mut self) {
+        let mut diags = std::mem::take(&mut self.ordered_diagnostics);
+        diags.sort_by_cached_key(|(diag, _)| (diag.sort_span, diag.message()));
+        for (diag, notes) in &diags {
+            self.emitter.emit_diagnostic(diag);
+            notes.iter().for_each(|note| self.emitter.emit_diagnostic(note));
+        }
+    }
+
     /// Emit all stashed diagnostics.
     fn emit_stashed_diagnostics(&mut self) {
         let diags = self.stashed_diagnostics.drain(..).map(|x| x.1).collect::<Vec<_>>();
@@ -751,6 +975,22 @@ impl HandlerInner {
             return;
         }
 
+        // Future-incompatible lints are recorded even when they are allowed or capped, so
+        // that the report can tell about the code that will break.
+        if diagnostic.has_future_breakage() {
+            self.future_breakage_diagnostics.push(diagnostic.clone());
+        }
+        if diagnostic.level == Allow {
+            return;
+        }
+        if let Expect(id) = diagnostic.level {
+            // Not shown, but tracked like any other diagnostic, so that a query loaded from
+            // the incremental cache replays it and fulfils the expectation again.
+            (*TRACK_DIAGNOSTICS)(diagnostic);
+            self.fulfilled_expectations.insert(id);
+            return;
+        }
+
         if diagnostic.level == Warning && !self.flags.can_emit_warnings {
             return;
         }
@@ -761,6 +1001,32 @@ impl HandlerInner {
             self.emitted_diagnostic_codes.insert(code.clone());
         }
 
+        let macro_diagnostic_key =
+            if self.flags.group_macro_diagnostics && self.flags.treat_err_as_bug.is_none() {
+                MacroDiagnosticKey::new(diagnostic)
+            } else {
+                None
+            };
+        if let Some(key) = macro_diagnostic_key {
+            let call_site = diagnostic.span.primary_span().unwrap().source_callsite();
+            let group = self.macro_diagnostic_groups.entry(key).or_insert_with(|| {
+                MacroDiagnosticGroup { diagnostic: diagnostic.clone(), call_sites: Vec::new() }
+            });
+            group.call_sites.push(call_site);
+        } else {
+            self.emit_deduplicated_diagnostic(diagnostic);
+        }
+
+        if diagnostic.is_error() {
+            self.bump_err_count();
+        } else {
+            self.bump_warn_count();
+        }
+    }
+
+    /// Emit `diagnostic` unless an identical one was already emitted and we've been asked to
+    /// deduplicate diagnostics.
+    fn emit_deduplicated_diagnostic(&mut self, diagnostic: &Diagnostic) {
         let already_emitted = |this: &mut Self| {
             use std::hash::Hash;
             let mut hasher = StableHasher::new();
@@ -772,30 +1038,49 @@ impl HandlerInner {
         // Only emit the diagnostic if we've been asked to deduplicate and
         // haven't already emitted an equivalent diagnostic.
         if !(self.flags.deduplicate_diagnostics && already_emitted(self)) {
-            self.emitter.emit_diagnostic(diagnostic);
+            match diagnostic.level {
+                Error | Warning if self.buffers_in_order() => {
+                    self.ordered_diagnostics.push((diagnostic.clone(), Vec::new()));
+                }
+                // Standalone notes and help messages are about the diagnostic reported right
+                // before them, so they are kept with it rather than sorted on their own.
+                Note | Help if self.buffers_in_order() && !self.ordered_diagnostics.is_empty() => {
+                    let (_, notes) = self.ordered_diagnostics.last_mut().unwrap();
+                    notes.push(diagnostic.clone());
+                }
+                _ => {
+                    // Diagnostics that may end the compilation must come after those
+                    // that were already reported.
+                    self.flush_ordered_diagnostics();
+                    self.emitter.emit_diagnostic(diagnostic);
+                }
+            }
             if diagnostic.is_error() {
                 self.deduplicated_err_count += 1;
             } else if diagnostic.level == Warning {
                 self.deduplicated_warn_count += 1;
             }
         }
-        if diagnostic.is_error() {
-            self.bump_err_count();
-        } else {
-            self.bump_warn_count();
-        }
     }
 
     fn emit_artifact_notification(&mut self, path: &Path, artifact_type: &str) {
         self.emitter.emit_artifact_notification(path, artifact_type);
     }
 
+    /// Whether diagnostics should be buffered to be emitted in a deterministic order. This is
+    /// never the case with `-Z treat-err-as-bug`, as we wouldn't get to print them.
+    fn buffers_in_order(&self) -> bool {
+        self.flags.deterministic_diagnostic_order && self.flags.treat_err_as_bug.is_none()
+    }
+
     fn treat_err_as_bug(&self) -> bool {
         self.flags.treat_err_as_bug.map(|c| self.err_count() >= c).unwrap_or(false)
     }
 
     fn print_error_count(&mut self, registry: &Registry) {
         self.emit_stashed_diagnostics();
+        self.flush_macro_diagnostic_groups();
+        self.flush_ordered_diagnostics();
 
         let warnings = match self.deduplicated_warn_count {
             0 => String::new(),
@@ -880,6 +1165,8 @@ impl HandlerInner {
 
     fn abort_if_errors(&mut self) {
         self.emit_stashed_diagnostics();
+        self.flush_macro_diagnostic_groups();
+        self.flush_ordered_diagnostics();
 
         if self.has_errors() {
             FatalError.raise();
@@ -985,7 +1272,7 @@ impl HandlerInner {
     }
 }
 
-#[derive(Copy, PartialEq, Clone, Hash, Debug, Encodable, Decodable)]
+#[derive(Copy, PartialEq, Eq, Clone, Hash, Debug, Encodable, Decodable)]
 pub enum Level {
     Bug,
     Fatal,
@@ -995,6 +1282,12 @@ pub enum Level {
     Help,
     Cancelled,
     FailureNote,
+    /// The diagnostic of an allowed lint, which is only recorded for the future
+    /// incompatibility report.
+    Allow,
+    /// The diagnostic of a lint at the `#[expect]` level, which is never shown but fulfils
+    /// the expectation.
+    Expect(LintExpectationId),
 }
 
 impl fmt::Display for Level {
@@ -1020,7 +1313,7 @@ impl Level {
                 spec.set_fg(Some(Color::Cyan)).set_intense(true);
             }
             FailureNote => {}
-            Cancelled => unreachable!(),
+            Allow | Expect(_) | Cancelled => unreachable!(),
         }
         spec
     }
@@ -1034,6 +1327,8 @@ impl Level {
             Help => "help",
             FailureNote => "failure-note",
             Cancelled => panic!("Shouldn't call on cancelled error"),
+            Allow => panic!("Shouldn't call on allowed error"),
+            Expect(_) => panic!("Shouldn't call on expected error"),
         }
     }
 
@@ -1045,6 +1340,33 @@ impl Level {
     }
 }
 
+/// Identifies a lint named in an `#[expect(...)]` attribute (RFC 2383). Any lint that is
+/// emitted at its level, in any pass, fulfils it.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Encodable, Decodable)]
+pub enum LintExpectationId {
+    /// An expectation set for the early lint passes, which run on the AST before there are
+    /// `HirId`s. Attribute ids are only valid within a session, so these ids are converted
+    /// to `Stable` ones before being compared and are never hashed.
+    Unstable { attr_id: AttrId, lint_index: u16 },
+    /// An expectation of an HIR node, `lint_index` being the position of the lint among all
+    /// the lints named in the `#[expect]` attributes of the node.
+    Stable { hir_id: HirId, lint_index: u16 },
+}
+
+impl<HCX: rustc_hir::HashStableContext> HashStable<HCX> for LintExpectationId {
+    fn hash_stable(&self, hcx: &mut HCX, hasher: &mut StableHasher) {
+        match *self {
+            LintExpectationId::Stable { hir_id, lint_index } => {
+                hir_id.hash_stable(hcx, hasher);
+                lint_index.hash_stable(hcx, hasher);
+            }
+            LintExpectationId::Unstable { .. } => {
+                unreachable!("the expectation ids of the early lint passes are never hashed")
+            }
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! pluralize {
     ($x:expr) => {
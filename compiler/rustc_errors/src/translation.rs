@@ -0,0 +1,289 @@
+//! Translation of diagnostic messages.
+//!
+//! Translatable diagnostics refer to their messages by an identifier, which is
+//! looked up in catalogs of messages written in a subset of the
+//! [Fluent](https://projectfluent.org) syntax:
+//!
+//! ```text
+//! # A comment.
+//! typeck-field-already-declared =
+//!     field `{$field_name}` is already declared
+//!     .label = field already declared
+//! ```
+//!
+//! A message has a value and any number of attributes (such as `label` above),
+//! which are used for the different parts of a diagnostic. Both are patterns in
+//! which `{$name}` is replaced with the argument `name` of the diagnostic, and
+//! `{"text"}` with `text` (to write literal braces). Patterns spanning several
+//! lines are joined with newlines.
+//!
+//! The English messages are built into the compiler, and are used for the
+//! messages that are missing from the catalog of the language selected with
+//! `-Z translate-lang`, which is read from `$sysroot/share/locale/<lang>/*.ftl`.
+
+use rustc_data_structures::fx::FxHashMap;
+
+use std::fs;
+use std::lazy::SyncLazy;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests;
+
+/// The English message catalogs, which are used when a message is not translated.
+pub const DEFAULT_LOCALE_RESOURCES: &[&str] = &[include_str!("../locales/en-US/typeck.ftl")];
+
+static FALLBACK_CATALOG: SyncLazy<MessageCatalog> = SyncLazy::new(|| {
+    let mut catalog = MessageCatalog::default();
+    for resource in DEFAULT_LOCALE_RESOURCES {
+        if let Err(e) = catalog.add_resource(resource) {
+            panic!("invalid built-in message catalog: {}", e);
+        }
+    }
+    catalog
+});
+
+/// An argument of a diagnostic message: its name and formatted value.
+pub type DiagnosticArg<'a> = (&'a str, String);
+
+#[derive(Debug, PartialEq)]
+enum PatternElement {
+    Text(String),
+    Variable(String),
+}
+
+type Pattern = Vec<PatternElement>;
+
+#[derive(Debug, Default)]
+struct Message {
+    value: Option<Pattern>,
+    attributes: FxHashMap<String, Pattern>,
+}
+
+/// The messages of a language, indexed by their identifier.
+#[derive(Debug, Default)]
+pub struct MessageCatalog {
+    messages: FxHashMap<String, Message>,
+}
+
+impl MessageCatalog {
+    /// Loads the catalog of `locale` from the `.ftl` files in
+    /// `$sysroot/share/locale/<locale>`.
+    pub fn load_locale(sysroot: &Path, locale: &str) -> Result<MessageCatalog, String> {
+        if locale.is_empty()
+            || !locale.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!("invalid language identifier `{}`", locale));
+        }
+
+        let dir = sysroot.join("share").join("locale").join(locale);
+        let entries = fs::read_dir(&dir)
+            .map_err(|e| format!("failed to read `{}`: {}", dir.display(), e))?;
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map_or(false, |ext| ext == "ftl"))
+            .collect::<Vec<_>>();
+        // Sort the files so that errors about duplicate messages are reproducible.
+        paths.sort();
+
+        let mut catalog = MessageCatalog::default();
+        for path in paths {
+            let source = fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read `{}`: {}", path.display(), e))?;
+            catalog.add_resource(&source).map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+        Ok(catalog)
+    }
+
+    /// Adds the messages of the catalog file `source` to this catalog.
+    pub fn add_resource(&mut self, source: &str) -> Result<(), String> {
+        // The message being parsed, and the name of its attribute being parsed, if any.
+        let mut current: Option<(String, Option<String>)> = None;
+
+        for (idx, line) in source.lines().enumerate() {
+            let line_number = idx + 1;
+            let err = |msg: &str| format!("line {}: {}", line_number, msg);
+
+            if line.trim().is_empty() {
+                continue;
+            }
+            if line.starts_with('#') {
+                current = None;
+                continue;
+            }
+
+            if !line.starts_with(|c: char| c.is_whitespace()) {
+                let (id, value) = match split_definition(line) {
+                    Some(definition) => definition,
+                    None => return Err(err("expected a message definition")),
+                };
+                if self.messages.contains_key(id) {
+                    return Err(err(&format!("duplicate message `{}`", id)));
+                }
+                let mut message = Message::default();
+                if !value.is_empty() {
+                    message.value = Some(parse_pattern(value).map_err(|e| err(&e))?);
+                }
+                self.messages.insert(id.to_string(), message);
+                current = Some((id.to_string(), None));
+                continue;
+            }
+
+            let (id, attr) = match &mut current {
+                Some(current) => current,
+                None => return Err(err("indented line outside of a message")),
+            };
+            let message = self.messages.get_mut(id.as_str()).unwrap();
+            let line = line.trim();
+
+            if let Some(attribute) = line.strip_prefix('.') {
+                let (name, value) = match split_definition(attribute) {
+                    Some(definition) => definition,
+                    None => return Err(err("expected an attribute definition")),
+                };
+                if message.attributes.contains_key(name) {
+                    return Err(err(&format!("duplicate attribute `{}` of `{}`", name, id)));
+                }
+                let pattern = parse_pattern(value).map_err(|e| err(&e))?;
+                message.attributes.insert(name.to_string(), pattern);
+                *attr = Some(name.to_string());
+                continue;
+            }
+
+            // The continuation of the value or attribute above.
+            let pattern = match attr {
+                Some(attr) => message.attributes.get_mut(attr.as_str()).unwrap(),
+                None => message.value.get_or_insert_with(Vec::new),
+            };
+            if !pattern.is_empty() {
+                push_text(pattern, "\n");
+            }
+            pattern.extend(parse_pattern(line).map_err(|e| err(&e))?);
+        }
+
+        Ok(())
+    }
+
+    /// Formats the message `id`, or its attribute `attr`, with `args`. Returns `None` if
+    /// the catalog doesn't contain it.
+    pub fn format(
+        &self,
+        id: &str,
+        attr: Option<&str>,
+        args: &[DiagnosticArg<'_>],
+    ) -> Option<String> {
+        let message = self.messages.get(id)?;
+        let pattern = match attr {
+            Some(attr) => message.attributes.get(attr)?,
+            None => message.value.as_ref()?,
+        };
+
+        let mut formatted = String::new();
+        for element in pattern {
+            match element {
+                PatternElement::Text(text) => formatted.push_str(text),
+                PatternElement::Variable(name) => {
+                    match args.iter().find(|(arg, _)| arg == name) {
+                        Some((_, value)) => formatted.push_str(value),
+                        // Like Fluent, show the placeable of a missing argument.
+                        None => {
+                            formatted.push_str("{$");
+                            formatted.push_str(name);
+                            formatted.push('}');
+                        }
+                    }
+                }
+            }
+        }
+        Some(formatted)
+    }
+}
+
+/// Formats the message `id`, or its attribute `attr`, with `args`, using the catalog of
+/// the selected language if it contains the message, and the English one otherwise.
+///
+/// A message missing from the English catalog too is shown as its identifier, like
+/// Fluent does, rather than losing the whole diagnostic.
+pub fn translate_message(
+    locale_catalog: Option<&MessageCatalog>,
+    id: &str,
+    attr: Option<&str>,
+    args: &[DiagnosticArg<'_>],
+) -> String {
+    locale_catalog
+        .and_then(|catalog| catalog.format(id, attr, args))
+        .or_else(|| FALLBACK_CATALOG.format(id, attr, args))
+        .unwrap_or_else(|| match attr {
+            Some(attr) => format!("{}.{}", id, attr),
+            None => id.to_string(),
+        })
+}
+
+/// Splits `name = value` into its trimmed parts, checking that `name` is an identifier.
+fn split_definition(line: &str) -> Option<(&str, &str)> {
+    let eq = line.find('=')?;
+    let (name, value) = (line[..eq].trim(), line[eq + 1..].trim());
+    let mut chars = name.chars();
+    let valid_start = chars.next().map_or(false, |c| c.is_ascii_alphabetic());
+    if valid_start && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        Some((name, value))
+    } else {
+        None
+    }
+}
+
+fn parse_pattern(text: &str) -> Result<Pattern, String> {
+    let mut pattern = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c == '{' || c == '}') {
+        if rest[start..].starts_with('}') {
+            return Err("unmatched `}`, write `{\"}\"}` for a literal brace".to_string());
+        }
+        let end = match find_placeable_end(&rest[start + 1..]) {
+            Some(len) => start + 1 + len,
+            None => return Err("unterminated placeable".to_string()),
+        };
+        if start > 0 {
+            push_text(&mut pattern, &rest[..start]);
+        }
+
+        let placeable = rest[start + 1..end].trim();
+        if let Some(name) = placeable.strip_prefix('$') {
+            if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return Err(format!("invalid variable name `{}`", name));
+            }
+            pattern.push(PatternElement::Variable(name.to_string()));
+        } else if placeable.len() >= 2 && placeable.starts_with('"') && placeable.ends_with('"') {
+            push_text(&mut pattern, &placeable[1..placeable.len() - 1]);
+        } else {
+            return Err(format!("unsupported placeable `{{{}}}`", placeable));
+        }
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        push_text(&mut pattern, rest);
+    }
+    Ok(pattern)
+}
+
+/// Returns the position of the `}` closing a placeable whose content starts `text`,
+/// skipping over the braces within string literals.
+fn find_placeable_end(text: &str) -> Option<usize> {
+    let mut in_literal = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_literal = !in_literal,
+            '}' if !in_literal => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn push_text(pattern: &mut Pattern, text: &str) {
+    if let Some(PatternElement::Text(last)) = pattern.last_mut() {
+        last.push_str(text);
+    } else {
+        pattern.push(PatternElement::Text(text.to_string()));
+    }
+}
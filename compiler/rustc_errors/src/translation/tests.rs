@@ -0,0 +1,90 @@
+use super::*;
+
+fn catalog(source: &str) -> MessageCatalog {
+    let mut catalog = MessageCatalog::default();
+    catalog.add_resource(source).unwrap();
+    catalog
+}
+
+fn args<'a>(args: &[(&'a str, &str)]) -> Vec<DiagnosticArg<'a>> {
+    args.iter().map(|&(name, value)| (name, value.to_string())).collect()
+}
+
+#[test]
+fn format_value_and_attributes() {
+    let catalog = catalog(
+        "# A comment.\n\
+         field-declared =\n    field `{$name}` is already declared\n\
+         \x20   .label = field already declared\n\
+         \x20   .previous-label = `{ $name }` first declared here\n",
+    );
+    let args = args(&[("name", "x")]);
+    assert_eq!(
+        catalog.format("field-declared", None, &args).as_deref(),
+        Some("field `x` is already declared"),
+    );
+    assert_eq!(
+        catalog.format("field-declared", Some("label"), &args).as_deref(),
+        Some("field already declared"),
+    );
+    assert_eq!(
+        catalog.format("field-declared", Some("previous-label"), &args).as_deref(),
+        Some("`x` first declared here"),
+    );
+    assert_eq!(catalog.format("field-declared", Some("note"), &args), None);
+    assert_eq!(catalog.format("unknown", None, &args), None);
+}
+
+#[test]
+fn format_multiline_and_literals() {
+    let catalog = catalog("multi = first {\"{\"}line{\"}\"}\n    second line\n");
+    assert_eq!(catalog.format("multi", None, &[]).as_deref(), Some("first {line}\nsecond line"));
+}
+
+#[test]
+fn format_missing_argument() {
+    let catalog = catalog("missing = found {$found}, expected {$expected}\n");
+    let args = args(&[("found", "1")]);
+    assert_eq!(
+        catalog.format("missing", None, &args).as_deref(),
+        Some("found 1, expected {$expected}"),
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let error = |source: &str| MessageCatalog::default().add_resource(source).unwrap_err();
+    assert_eq!(error("    .label = orphan\n"), "line 1: indented line outside of a message");
+    assert_eq!(error("no value\n"), "line 1: expected a message definition");
+    assert_eq!(error("a = one\na = two\n"), "line 2: duplicate message `a`");
+    assert_eq!(error("a = {$x\n"), "line 1: unterminated placeable");
+    assert_eq!(error("a = {x}\n"), "line 1: unsupported placeable `{x}`");
+}
+
+#[test]
+fn translate_falls_back_to_english() {
+    let locale = catalog("typeck-address-of-temporary-taken = adresse d'un temporaire\n");
+    assert_eq!(
+        translate_message(Some(&locale), "typeck-address-of-temporary-taken", None, &[]),
+        "adresse d'un temporaire",
+    );
+    assert_eq!(
+        translate_message(Some(&locale), "typeck-address-of-temporary-taken", Some("label"), &[]),
+        "temporary value",
+    );
+    let args = args(&[("name", "f")]);
+    assert_eq!(
+        translate_message(None, "typeck-simd-shuffle-missing-length", None, &args),
+        "invalid `simd_shuffle`, needs length: `f`",
+    );
+}
+
+#[test]
+fn translate_missing_message() {
+    let locale = catalog("typeck-unknown = inconnu\n");
+    assert_eq!(
+        translate_message(Some(&locale), "typeck-unknown", Some("label"), &[]),
+        "typeck-unknown.label",
+    );
+    assert_eq!(translate_message(None, "typeck-unknown", None, &[]), "typeck-unknown");
+}
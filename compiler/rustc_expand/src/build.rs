@@ -159,6 +159,7 @@ impl<'a> ExtCtxt<'a> {
             pat,
             ty: None,
             init: Some(ex),
+            els: None,
             id: ast::DUMMY_NODE_ID,
             span: sp,
             attrs: AttrVec::new(),
@@ -177,6 +178,7 @@ impl<'a> ExtCtxt<'a> {
             pat: self.pat_wild(span),
             ty: Some(ty),
             init: None,
+            els: None,
             id: ast::DUMMY_NODE_ID,
             span,
             attrs: AttrVec::new(),
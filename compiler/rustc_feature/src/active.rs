@@ -601,6 +601,21 @@ declare_features! (
     /// Allow anonymous constants from an inline `const` block
     (active, inline_const, "1.49.0", Some(76001), None),
 
+    /// Allows `let PAT = EXPR else { DIVERGING_BLOCK };` statements.
+    (active, let_else, "1.49.0", Some(87335), None),
+
+    /// Allows `impl Trait` as the return type of trait methods and their implementations.
+    (active, return_position_impl_trait_in_trait, "1.49.0", None, None),
+
+    /// Allows `async fn` in traits and trait implementations.
+    (active, async_fn_in_trait, "1.49.0", None, None),
+
+    /// Allows default values for const generic parameters, e.g. `struct Buf<const N: usize = 64>`.
+    (active, const_generics_defaults, "1.49.0", Some(44580), None),
+
+    /// Allows `#[must_not_suspend]`, to warn when a value of a type is held across a suspend point.
+    (active, must_not_suspend, "1.49.0", Some(83310), None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -622,6 +637,8 @@ pub const INCOMPLETE_FEATURES: &[Symbol] = &[
     sym::lazy_normalization_consts,
     sym::specialization,
     sym::inline_const,
+    sym::return_position_impl_trait_in_trait,
+    sym::async_fn_in_trait,
 ];
 
 /// Some features are not allowed to be used together at the same time, if
@@ -292,6 +292,16 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         experimental!(link_ordinal)
     ),
 
+    // Lints:
+    gated!(
+        expect, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#),
+        lint_reasons, experimental!(expect)
+    ),
+    gated!(
+        must_not_suspend, Normal, template!(Word, NameValueStr: "reason"), must_not_suspend,
+        experimental!(must_not_suspend)
+    ),
+
     // Plugins:
     (
         sym::plugin_registrar, Normal, template!(Word),
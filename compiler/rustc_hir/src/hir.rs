@@ -413,6 +413,8 @@ pub enum GenericParamKind<'hir> {
     },
     Const {
         ty: &'hir Ty<'hir>,
+        /// Optional default value for the const generic param
+        default: Option<AnonConst>,
     },
 }
 
@@ -1117,6 +1119,8 @@ pub struct Local<'hir> {
     pub ty: Option<&'hir Ty<'hir>>,
     /// Initializer expression to set the value, if any.
     pub init: Option<&'hir Expr<'hir>>,
+    /// The diverging `else` block of a `let PAT = EXPR else { .. };` statement, if any.
+    pub els: Option<&'hir Block<'hir>>,
     pub hir_id: HirId,
     pub span: Span,
     pub attrs: AttrVec,
@@ -1371,6 +1375,7 @@ impl Expr<'_> {
             ExprKind::Lit(_) => ExprPrecedence::Lit,
             ExprKind::Type(..) | ExprKind::Cast(..) => ExprPrecedence::Cast,
             ExprKind::DropTemps(ref expr, ..) => expr.precedence(),
+            ExprKind::Let(..) => ExprPrecedence::Let,
             ExprKind::Loop(..) => ExprPrecedence::Loop,
             ExprKind::Match(..) => ExprPrecedence::Match,
             ExprKind::Closure(..) => ExprPrecedence::Closure,
@@ -1433,6 +1438,7 @@ impl Expr<'_> {
             | ExprKind::MethodCall(..)
             | ExprKind::Struct(..)
             | ExprKind::Tup(..)
+            | ExprKind::Let(..)
             | ExprKind::Match(..)
             | ExprKind::Closure(..)
             | ExprKind::Block(..)
@@ -1471,6 +1477,19 @@ impl Expr<'_> {
         }
         expr
     }
+
+    /// Whether this is a `let` expression, or a chain of `&&`s containing one, as found in
+    /// the condition of an `if` or `while` with `let` chains (RFC 2497) or in an `if let`
+    /// guard (RFC 2294).
+    pub fn is_let_chain(&self) -> bool {
+        match self.kind {
+            ExprKind::Let(..) => true,
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And => {
+                lhs.is_let_chain() || rhs.is_let_chain()
+            }
+            _ => false,
+        }
+    }
 }
 
 /// Checks if the specified expression is a built-in range literal.
@@ -1549,6 +1568,13 @@ pub enum ExprKind<'hir> {
     /// This construct only exists to tweak the drop order in HIR lowering.
     /// An example of that is the desugaring of `for` loops.
     DropTemps(&'hir Expr<'hir>),
+    /// A `let $pat = $expr` expression.
+    ///
+    /// These only appear within `&&` chains in the condition of an `if` or
+    /// `while`, or in the guard of a `match` arm (see `Expr::is_let_chain`).
+    /// A lone `if let` or `while let` is lowered using
+    /// `MatchSource::IfLetDesugar` or `WhileLetDesugar` instead.
+    Let(&'hir Pat<'hir>, &'hir Expr<'hir>),
     /// A conditionless loop (can be exited with `break`, `continue`, or `return`).
     ///
     /// I.e., `'label: loop { <block> }`.
@@ -1699,11 +1725,13 @@ pub enum LocalSource {
 pub enum MatchSource {
     /// A `match _ { .. }`.
     Normal,
-    /// An `if _ { .. }` (optionally with `else { .. }`).
+    /// An `if _ { .. }` (optionally with `else { .. }`), including an `if` whose
+    /// condition is a `let` chain.
     IfDesugar { contains_else_clause: bool },
     /// An `if let _ = _ { .. }` (optionally with `else { .. }`).
     IfLetDesugar { contains_else_clause: bool },
-    /// A `while _ { .. }` (which was desugared to a `loop { match _ { .. } }`).
+    /// A `while _ { .. }` (which was desugared to a `loop { match _ { .. } }`),
+    /// including a `while` whose condition is a `let` chain.
     WhileDesugar,
     /// A `while let _ = _ { .. }` (which was desugared to a
     /// `loop { match _ { .. } }`).
@@ -2583,6 +2611,14 @@ impl ItemKind<'_> {
     }
 }
 
+/// Returns the name of the anonymous associated type that the `impl Trait` return type (or the
+/// `impl Future` of an `async fn`) of the trait method `fn_name` desugars to. Users cannot write
+/// this name; it only pairs the associated type of a trait with those of its impls. Use the
+/// `impl_trait_fn` of `TraitItemRef`, `ImplItemRef` or `ty::AssocItem` to recognize such types.
+pub fn impl_trait_in_trait_name(fn_name: Symbol) -> Symbol {
+    Symbol::intern(&format!("{}::{{opaque}}", fn_name))
+}
+
 /// A reference from an trait to one of its associated items. This
 /// contains the item's id, naturally, but also the item's name and
 /// some other high-level details (like whether it is an associated
@@ -2597,6 +2633,9 @@ pub struct TraitItemRef {
     pub kind: AssocItemKind,
     pub span: Span,
     pub defaultness: Defaultness,
+    /// If this is the anonymous associated type that the `impl Trait` return type of a method
+    /// desugars to, the ID of that method.
+    pub impl_trait_fn: Option<TraitItemId>,
 }
 
 /// A reference from an impl to one of its associated items. This
@@ -2614,6 +2653,9 @@ pub struct ImplItemRef<'hir> {
     pub span: Span,
     pub vis: Visibility<'hir>,
     pub defaultness: Defaultness,
+    /// If this is the anonymous associated type that the `impl Trait` return type of a method
+    /// desugars to, the ID of that method.
+    pub impl_trait_fn: Option<ImplItemId>,
 }
 
 #[derive(Copy, Clone, PartialEq, Encodable, Debug, HashStable_Generic)]
@@ -246,6 +246,12 @@ language_item_table! {
 
     FnOnceOutput,            sym::fn_once_output,      fn_once_output,             Target::AssocTy;
 
+    AsyncFn,                 sym::async_fn,            async_fn_trait,             Target::Trait;
+    AsyncFnMut,              sym::async_fn_mut,        async_fn_mut_trait,         Target::Trait;
+    AsyncFnOnce,             sym::async_fn_once,       async_fn_once_trait,        Target::Trait;
+
+    AsyncFnOnceOutput,       sym::async_fn_once_output, async_fn_once_output,      Target::AssocTy;
+
     Future,                  sym::future_trait,        future_trait,               Target::Trait;
     GeneratorState,          sym::generator_state,     gen_state,                  Target::Enum;
     Generator,               sym::generator,           gen_trait,                  Target::Trait;
@@ -899,7 +899,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
                 ty::GenericParamDefKind::Type { has_default, .. } => {
                     Some((param.def_id, has_default))
                 }
-                ty::GenericParamDefKind::Const => None, // FIXME(const_generics:defaults)
+                ty::GenericParamDefKind::Const { .. } => None, // FIXME(const_generics:defaults)
             })
             .peekable();
         let has_default = {
@@ -1593,6 +1593,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         if let Some(exp_found) = exp_found {
             self.suggest_as_ref_where_appropriate(span, &exp_found, diag);
             self.suggest_await_on_expect_found(cause, span, &exp_found, diag);
+            self.note_opaque_type_defining_scope(cause, &exp_found, diag);
         }
 
         // In some (most?) cases cause.body_id points to actual body, but in some cases
@@ -1611,6 +1612,58 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         self.note_error_origin(diag, cause, exp_found);
     }
 
+    /// When a type alias `impl Trait` is expected outside of its defining scope, e.g. when a
+    /// function in another module tries to return a concrete type for it, explains where its
+    /// defining uses have to appear.
+    fn note_opaque_type_defining_scope(
+        &self,
+        cause: &ObligationCause<'tcx>,
+        exp_found: &ty::error::ExpectedFound<Ty<'tcx>>,
+        diag: &mut DiagnosticBuilder<'tcx>,
+    ) {
+        let opaque_hir_id = match *exp_found.expected.kind() {
+            ty::Opaque(def_id, _) => match def_id.as_local() {
+                Some(def_id) => self.tcx.hir().local_def_id_to_hir_id(def_id),
+                None => return,
+            },
+            _ => return,
+        };
+        match self.tcx.hir().get(opaque_hir_id) {
+            Node::Item(Item {
+                kind:
+                    ItemKind::OpaqueTy(hir::OpaqueTy {
+                        impl_trait_fn: None,
+                        origin: hir::OpaqueTyOrigin::Misc,
+                        ..
+                    }),
+                ..
+            }) => {}
+            _ => return,
+        }
+        if cause.body_id == hir::CRATE_HIR_ID {
+            return;
+        }
+
+        // Same walk as `may_define_opaque_type`: the body is in the defining scope if one of its
+        // parent items is the scope.
+        let scope = self.tcx.hir().get_defining_scope(opaque_hir_id);
+        let mut hir_id = cause.body_id;
+        while hir_id != scope && hir_id != hir::CRATE_HIR_ID {
+            hir_id = self.tcx.hir().get_parent_item(hir_id);
+        }
+        if hir_id == scope {
+            return;
+        }
+
+        let scope_def_id = self.tcx.hir().local_def_id(scope).to_def_id();
+        diag.note(&format!(
+            "a defining use of the expected opaque type must appear within the {} `{}` or one \
+             of its children",
+            self.tcx.def_kind(scope_def_id).descr(scope_def_id),
+            self.tcx.def_path_str(scope_def_id),
+        ));
+    }
+
     fn suggest_await_on_expect_found(
         &self,
         cause: &ObligationCause<'tcx>,
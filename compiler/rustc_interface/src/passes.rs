@@ -858,14 +858,14 @@ fn analysis(tcx: TyCtxt<'_>, cnum: CrateNum) -> Result<()> {
     });
 
     sess.time("MIR_effect_checking", || {
-        for def_id in tcx.body_owners() {
+        tcx.par_body_owners(|def_id| {
             mir::transform::check_unsafety::check_unsafety(tcx, def_id);
 
             if tcx.hir().body_const_context(def_id).is_some() {
                 tcx.ensure()
                     .mir_drops_elaborated_and_const_checked(ty::WithOptConstParam::unknown(def_id));
             }
-        }
+        });
     });
 
     sess.time("layout_testing", || layout_test::test_layout(tcx));
@@ -1005,5 +1005,8 @@ pub fn start_codegen<'tcx>(
         }
     }
 
+    // Every pass that can emit lints has run by now.
+    tcx.sess.time("lint_expectation_checking", || rustc_lint::check_expectations(tcx));
+
     codegen
 }
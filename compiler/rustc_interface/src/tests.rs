@@ -1,6 +1,7 @@
 use crate::interface::parse_cfgspecs;
 
 use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::profiling::SummaryFormat;
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
 use rustc_session::config::Strip;
 use rustc_session::config::{build_configuration, build_session_options, to_crate_config};
@@ -252,6 +253,18 @@ fn test_lints_tracking_hash_different_construction_order() {
     assert_eq!(v2.dep_tracking_hash(), v2.clone().dep_tracking_hash());
 }
 
+#[test]
+fn test_lint_config_tracking_hash_different_values() {
+    let mut v1 = Options::default();
+    let mut v2 = Options::default();
+
+    v1.lint_config = vec![(String::from("a"), Level::Allow), (String::from("b"), Level::Deny)];
+    v2.lint_config = vec![(String::from("a"), Level::Allow), (String::from("b"), Level::Warn)];
+
+    assert!(v1.dep_tracking_hash() != v2.dep_tracking_hash());
+    assert!(Options::default().dep_tracking_hash() != v1.dep_tracking_hash());
+}
+
 #[test]
 fn test_search_paths_tracking_hash_different_order() {
     let mut v1 = Options::default();
@@ -470,6 +483,7 @@ fn test_debugging_options_tracking_hash() {
     untracked!(borrowck_stats, true);
     untracked!(deduplicate_diagnostics, true);
     untracked!(dep_tasks, true);
+    untracked!(deterministic_diagnostics, Some(true));
     untracked!(dont_buffer_diagnostics, true);
     untracked!(dump_dep_graph, true);
     untracked!(dump_mir, Some(String::from("abc")));
@@ -477,7 +491,10 @@ fn test_debugging_options_tracking_hash() {
     untracked!(dump_mir_dir, String::from("abc"));
     untracked!(dump_mir_exclude_pass_number, true);
     untracked!(dump_mir_graphviz, true);
+    untracked!(emit_future_incompat_report, true);
     untracked!(emit_stack_sizes, true);
+    untracked!(explain_obligations, true);
+    untracked!(group_macro_diagnostics, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
     untracked!(incremental_ignore_spans, true);
@@ -498,6 +515,7 @@ fn test_debugging_options_tracking_hash() {
     untracked!(parse_only, true);
     untracked!(perf_stats, true);
     untracked!(polonius, true);
+    untracked!(polymorphize_stats, true);
     // `pre_link_arg` is omitted because it just forwards to `pre_link_args`.
     untracked!(pre_link_args, vec![String::from("abc"), String::from("def")]);
     untracked!(print_link_args, true);
@@ -510,6 +528,7 @@ fn test_debugging_options_tracking_hash() {
     untracked!(save_analysis, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
+    untracked!(self_profile_summary, Some(SummaryFormat::Json));
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
     untracked!(strip, Strip::None);
@@ -519,6 +538,7 @@ fn test_debugging_options_tracking_hash() {
     untracked!(time_llvm_passes, true);
     untracked!(time_passes, true);
     untracked!(trace_macros, true);
+    untracked!(translate_lang, Some(String::from("fr-FR")));
     untracked!(trim_diagnostic_paths, false);
     untracked!(ui_testing, true);
     untracked!(unpretty, Some("expanded".to_string()));
@@ -552,6 +572,9 @@ fn test_debugging_options_tracking_hash() {
     tracked!(fuel, Some(("abc".to_string(), 99)));
     tracked!(human_readable_cgu_names, true);
     tracked!(inline_in_all_cgus, Some(true));
+    tracked!(inline_mir, Some(true));
+    tracked!(inline_mir_hint_threshold, 123);
+    tracked!(inline_mir_threshold, 123);
     tracked!(insert_sideeffect, true);
     tracked!(instrument_coverage, true);
     tracked!(instrument_mcount, true);
@@ -568,6 +591,7 @@ fn test_debugging_options_tracking_hash() {
     tracked!(osx_rpath_install_name, true);
     tracked!(panic_abort_tests, true);
     tracked!(plt, Some(true));
+    tracked!(polymorphize, true);
     tracked!(precise_enum_drop_elaboration, false);
     tracked!(print_fuel, Some("abc".to_string()));
     tracked!(profile, true);
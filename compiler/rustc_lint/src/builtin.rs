@@ -582,7 +582,13 @@ impl<'tcx> LateLintPass<'tcx> for MissingDoc {
             return;
         }
 
+        // The anonymous associated types of `impl Trait` return types are documented by
+        // their methods.
         let def_id = cx.tcx.hir().local_def_id(trait_item.hir_id);
+        if cx.tcx.associated_item(def_id).impl_trait_fn.is_some() {
+            return;
+        }
+
         let (article, desc) = cx.tcx.article_and_description(def_id.to_def_id());
 
         self.check_missing_docs_attrs(
@@ -22,7 +22,7 @@ use rustc_ast as ast;
 use rustc_ast::util::lev_distance::find_best_match_for_name;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync;
-use rustc_errors::{struct_span_err, Applicability};
+use rustc_errors::{struct_span_err, Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{CrateNum, DefId};
@@ -258,6 +258,10 @@ impl LintStore {
         self.by_name.insert(name.into(), Removed(reason.into()));
     }
 
+    pub fn is_lint_group(&self, lint_name: &str) -> bool {
+        self.lint_groups.contains_key(lint_name)
+    }
+
     pub fn find_lints(&self, mut lint_name: &str) -> Result<Vec<LintId>, FindLintError> {
         match self.by_name.get(lint_name) {
             Some(&Id(lint_id)) => Ok(vec![lint_id]),
@@ -280,7 +284,41 @@ impl LintStore {
 
     /// Checks the validity of lint names derived from the command line
     pub fn check_lint_name_cmdline(&self, sess: &Session, lint_name: &str, level: Level) {
-        let db = match self.check_lint_name(lint_name, None) {
+        let flag = match level {
+            Level::Allow => "-A",
+            Level::Warn => "-W",
+            Level::Deny => "-D",
+            Level::Forbid => "-F",
+            Level::Expect(_) => {
+                // Expectations belong to `#[expect]` attributes, there is no flag for them.
+                sess.err(&format!("lint `{}` cannot be expected on the command line", lint_name));
+                return;
+            }
+        };
+        if let Some(mut db) = self.check_lint_name_unscoped(sess, lint_name) {
+            db.note(&format!("requested on the command line with `{} {}`", flag, lint_name));
+            db.emit();
+        }
+    }
+
+    /// Checks the validity of lint names read from the lint configuration file
+    pub fn check_lint_name_config(&self, sess: &Session, lint_name: &str, level: Level) {
+        if let Some(mut db) = self.check_lint_name_unscoped(sess, lint_name) {
+            db.note(&format!(
+                "requested in the lint configuration file with `{} = \"{}\"`",
+                lint_name,
+                level.as_str()
+            ));
+            db.emit();
+        }
+    }
+
+    fn check_lint_name_unscoped<'s>(
+        &self,
+        sess: &'s Session,
+        lint_name: &str,
+    ) -> Option<DiagnosticBuilder<'s>> {
+        match self.check_lint_name(lint_name, None) {
             CheckLintNameResult::Ok(_) => None,
             CheckLintNameResult::Warning(ref msg, _) => Some(sess.struct_warn(msg)),
             CheckLintNameResult::NoLint(suggestion) => {
@@ -302,21 +340,6 @@ impl LintStore {
                 ))),
                 _ => None,
             },
-        };
-
-        if let Some(mut db) = db {
-            let msg = format!(
-                "requested on the command line with `{} {}`",
-                match level {
-                    Level::Allow => "-A",
-                    Level::Warn => "-W",
-                    Level::Deny => "-D",
-                    Level::Forbid => "-F",
-                },
-                lint_name
-            );
-            db.note(&msg);
-            db.emit();
         }
     }
 
@@ -0,0 +1,81 @@
+use crate::late::unerased_lint_store;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_middle::lint::struct_lint_level;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::lint::builtin::{
+    ARITHMETIC_OVERFLOW, UNCONDITIONAL_PANIC, UNFULFILLED_LINT_EXPECTATIONS,
+};
+use rustc_session::lint::LintId;
+
+/// Reports every `#[expect(...)]` whose lint was not emitted in its scope.
+///
+/// Lints at the `expect` level are emitted as diagnostics that are never shown, and the
+/// handler records the expectations they fulfil. Queries loaded from the incremental cache
+/// replay their diagnostics, so this has to run after every pass that can emit lints for
+/// the crate, but doesn't depend on which queries were actually executed.
+///
+/// Const propagation only lints while building optimized MIR, which codegen needs for
+/// every function and closure. Check builds don't build it, so expectations of those lints
+/// are not reported there rather than reported falsely.
+pub fn check_expectations(tcx: TyCtxt<'_>) {
+    let lint_levels = tcx.lint_levels(LOCAL_CRATE);
+    if lint_levels.expectations.is_empty() {
+        return;
+    }
+
+    let should_codegen = tcx.sess.opts.output_types.should_codegen();
+    if should_codegen {
+        for def_id in tcx.body_owners() {
+            let hir_id = tcx.hir().local_def_id_to_hir_id(def_id);
+            if tcx.hir().body_owner_kind(hir_id).is_fn_or_closure() {
+                tcx.ensure().optimized_mir(def_id.to_def_id());
+            }
+        }
+    }
+
+    let fulfilled: FxHashSet<_> = tcx
+        .sess
+        .diagnostic()
+        .steal_fulfilled_expectation_ids()
+        .into_iter()
+        .filter_map(|id| lint_levels.stable_expectation_id(id))
+        .collect();
+    let store = unerased_lint_store(tcx);
+    let mir_opt_lints = [LintId::of(ARITHMETIC_OVERFLOW), LintId::of(UNCONDITIONAL_PANIC)];
+
+    for expectation in &lint_levels.expectations {
+        if fulfilled.contains(&expectation.id) {
+            continue;
+        }
+        if !should_codegen {
+            let only_mir_opt_lints = store
+                .find_lints(&expectation.lint_name.as_str())
+                .map_or(false, |ids| ids.iter().all(|id| mir_opt_lints.contains(id)));
+            if only_mir_opt_lints {
+                continue;
+            }
+        }
+
+        let (level, src) = lint_levels.sets.get_lint_level(
+            UNFULFILLED_LINT_EXPECTATIONS,
+            expectation.lint_set,
+            None,
+            tcx.sess,
+        );
+        struct_lint_level(
+            tcx.sess,
+            UNFULFILLED_LINT_EXPECTATIONS,
+            level,
+            src,
+            Some(expectation.span.into()),
+            |lint| {
+                let mut err = lint.build("this lint expectation is unfulfilled");
+                if let Some(rationale) = expectation.reason {
+                    err.note(&rationale.as_str());
+                }
+                err.emit();
+            },
+        );
+    }
+}
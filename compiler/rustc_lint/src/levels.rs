@@ -12,13 +12,14 @@ use rustc_hir::{intravisit, HirId};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::LevelSource;
 use rustc_middle::lint::LintDiagnosticBuilder;
+use rustc_middle::lint::LintExpectation;
 use rustc_middle::lint::{struct_lint_level, LintLevelMap, LintLevelSets, LintSet, LintSource};
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
-use rustc_session::lint::{builtin, Level, Lint, LintId};
+use rustc_session::lint::{builtin, Level, Lint, LintExpectationId, LintId};
 use rustc_session::parse::feature_err;
 use rustc_session::Session;
-use rustc_span::symbol::{sym, Symbol};
+use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::{source_map::MultiSpan, Span, DUMMY_SP};
 
 use std::cmp;
@@ -30,7 +31,7 @@ fn lint_levels(tcx: TyCtxt<'_>, cnum: CrateNum) -> LintLevelMap {
     let mut builder = LintLevelMapBuilder { levels, tcx, store };
     let krate = tcx.hir().krate();
 
-    let push = builder.levels.push(&krate.item.attrs, &store, true);
+    let push = builder.levels.push(&krate.item.attrs, &store, true, Some(hir::CRATE_HIR_ID));
     builder.levels.register_id(hir::CRATE_HIR_ID);
     for macro_def in krate.exported_macros {
         builder.levels.register_id(macro_def.hir_id);
@@ -45,6 +46,8 @@ pub struct LintLevelsBuilder<'s> {
     sess: &'s Session,
     sets: LintLevelSets,
     id_to_set: FxHashMap<HirId, u32>,
+    expectations: Vec<LintExpectation>,
+    stable_expectation_ids: FxHashMap<LintExpectationId, LintExpectationId>,
     cur: u32,
     warn_about_weird_lints: bool,
 }
@@ -61,6 +64,8 @@ impl<'s> LintLevelsBuilder<'s> {
             sets: LintLevelSets::new(),
             cur: 0,
             id_to_set: Default::default(),
+            expectations: Vec::new(),
+            stable_expectation_ids: Default::default(),
             warn_about_weird_lints,
         };
         builder.process_command_line(sess, store);
@@ -72,6 +77,8 @@ impl<'s> LintLevelsBuilder<'s> {
         let mut specs = FxHashMap::default();
         self.sets.lint_cap = sess.opts.lint_cap.unwrap_or(Level::Forbid);
 
+        self.process_lint_config(sess, store, &mut specs);
+
         for &(ref lint_name, level) in &sess.opts.lint_opts {
             store.check_lint_name_cmdline(sess, &lint_name, level);
 
@@ -96,6 +103,46 @@ impl<'s> LintLevelsBuilder<'s> {
         self.sets.list.push(LintSet::CommandLine { specs });
     }
 
+    /// Inserts the levels read from the `--lint-config` file, which the command-line flags
+    /// override. Lint groups are inserted first, so that the level of a single lint takes
+    /// precedence over the level of a group it belongs to.
+    fn process_lint_config(
+        &mut self,
+        sess: &Session,
+        store: &LintStore,
+        specs: &mut FxHashMap<LintId, LevelSource>,
+    ) {
+        let (groups, lints): (Vec<_>, Vec<_>) = sess
+            .opts
+            .lint_config
+            .iter()
+            .partition(|(lint_name, _)| store.is_lint_group(lint_name));
+
+        for &(ref lint_name, level) in groups.into_iter().chain(lints) {
+            let is_tool_lint = lint_name
+                .find("::")
+                .map_or(false, |i| attr::is_known_lint_tool(Ident::from_str(&lint_name[..i])));
+            let ids = match store.find_lints(lint_name) {
+                Ok(ids) => ids,
+                // The lints of tools like clippy are only registered when the tool runs, so a
+                // configuration file shared with the tool doesn't report them as unknown.
+                Err(_) if is_tool_lint => continue,
+                Err(_) => {
+                    store.check_lint_name_config(sess, lint_name, level);
+                    continue;
+                }
+            };
+            store.check_lint_name_config(sess, lint_name, level);
+
+            let level = cmp::min(level, self.sets.lint_cap);
+            let src = LintSource::ConfigFile(Symbol::intern(lint_name));
+            for id in ids {
+                self.check_gated_lint(id, DUMMY_SP);
+                self.insert_spec(specs, id, (level, src));
+            }
+        }
+    }
+
     /// Attempts to insert the `id` to `level_src` map entry. If unsuccessful
     /// (e.g. if a forbid was already inserted on the same scope), then emits a
     /// diagnostic with no change to `specs`.
@@ -126,6 +173,10 @@ impl<'s> LintLevelsBuilder<'s> {
                     LintSource::CommandLine(_) => {
                         diag_builder.note("`forbid` lint level was set on command line");
                     }
+                    LintSource::ConfigFile(_) => {
+                        diag_builder
+                            .note("`forbid` lint level was set in the lint configuration file");
+                    }
                 }
                 diag_builder.emit();
                 return;
@@ -147,18 +198,24 @@ impl<'s> LintLevelsBuilder<'s> {
     /// * Lint attributes are validated, e.g., a `#[forbid]` can't be switched to
     ///   `#[allow]`
     ///
+    /// The expectations of `#[expect]` attributes are identified by `source_hir_id` when
+    /// the attributes are those of an HIR node, and by the attribute ids otherwise.
+    ///
     /// Don't forget to call `pop`!
     pub(crate) fn push(
         &mut self,
         attrs: &[ast::Attribute],
         store: &LintStore,
         is_crate_node: bool,
+        source_hir_id: Option<HirId>,
     ) -> BuilderPush {
         let mut specs = FxHashMap::default();
+        let mut expectations = Vec::new();
+        let mut expect_lint_index = 0;
         let sess = self.sess;
         let bad_attr = |span| struct_span_err!(sess, span, E0452, "malformed lint attribute input");
         for attr in attrs {
-            let level = match Level::from_symbol(attr.name_or_empty()) {
+            let attr_level = match Level::from_attr(attr) {
                 None => continue,
                 Some(lvl) => lvl,
             };
@@ -214,7 +271,29 @@ impl<'s> LintLevelsBuilder<'s> {
                 }
             }
 
-            for li in metas {
+            for (lint_index, li) in metas.iter().enumerate() {
+                // Every lint of an `#[expect]` attribute is a separate expectation.
+                let level = match attr_level {
+                    Level::Expect(_) => {
+                        let unstable_id = LintExpectationId::Unstable {
+                            attr_id: attr.id,
+                            lint_index: lint_index as u16,
+                        };
+                        match source_hir_id {
+                            Some(hir_id) => {
+                                let stable_id = LintExpectationId::Stable {
+                                    hir_id,
+                                    lint_index: expect_lint_index,
+                                };
+                                expect_lint_index += 1;
+                                self.stable_expectation_ids.insert(unstable_id, stable_id);
+                                Level::Expect(stable_id)
+                            }
+                            None => Level::Expect(unstable_id),
+                        }
+                    }
+                    level => level,
+                };
                 let meta_item = match li.meta_item() {
                     Some(meta_item) if meta_item.is_word() => meta_item,
                     _ => {
@@ -262,6 +341,9 @@ impl<'s> LintLevelsBuilder<'s> {
                             self.check_gated_lint(id, attr.span);
                             self.insert_spec(&mut specs, id, (level, src));
                         }
+                        if let Level::Expect(id) = level {
+                            expectations.push((id, name, li.span(), reason));
+                        }
                     }
 
                     CheckLintNameResult::Tool(result) => {
@@ -276,6 +358,9 @@ impl<'s> LintLevelsBuilder<'s> {
                                 for id in ids {
                                     self.insert_spec(&mut specs, *id, (level, src));
                                 }
+                                if let Level::Expect(id) = level {
+                                    expectations.push((id, src.name(), li.span(), reason));
+                                }
                             }
                             Err((Some(ids), new_lint_name)) => {
                                 let lint = builtin::RENAMED_AND_REMOVED_LINTS;
@@ -313,6 +398,9 @@ impl<'s> LintLevelsBuilder<'s> {
                                 for id in ids {
                                     self.insert_spec(&mut specs, *id, (level, src));
                                 }
+                                if let Level::Expect(id) = level {
+                                    expectations.push((id, src.name(), li.span(), reason));
+                                }
                             }
                             Err((None, _)) => {
                                 // If Tool(Err(None, _)) is returned, then either the lint does not
@@ -423,6 +511,7 @@ impl<'s> LintLevelsBuilder<'s> {
                 LintSource::Default => id.to_string(),
                 LintSource::Node(name, _, _) => name.to_string(),
                 LintSource::CommandLine(name) => name.to_string(),
+                LintSource::ConfigFile(name) => name.to_string(),
             };
             let (lint_attr_name, lint_attr_span) = match *src {
                 LintSource::Node(name, span, _) => (name, span),
@@ -449,6 +538,9 @@ impl<'s> LintLevelsBuilder<'s> {
                 LintSource::CommandLine(_) => {
                     diag_builder.note("`forbid` lint level was set on command line");
                 }
+                LintSource::ConfigFile(_) => {
+                    diag_builder.note("`forbid` lint level was set in the lint configuration file");
+                }
             }
             diag_builder.emit();
             // don't set a separate error for every lint in the group
@@ -461,6 +553,11 @@ impl<'s> LintLevelsBuilder<'s> {
             self.sets.list.push(LintSet::Node { specs, parent: prev });
         }
 
+        let lint_set = self.cur;
+        self.expectations.extend(expectations.into_iter().map(|(id, lint_name, span, reason)| {
+            LintExpectation { id, lint_name, span, reason, lint_set }
+        }));
+
         BuilderPush { prev, changed: prev != self.cur }
     }
 
@@ -512,7 +609,12 @@ impl<'s> LintLevelsBuilder<'s> {
     }
 
     pub fn build_map(self) -> LintLevelMap {
-        LintLevelMap { sets: self.sets, id_to_set: self.id_to_set }
+        LintLevelMap {
+            sets: self.sets,
+            id_to_set: self.id_to_set,
+            expectations: self.expectations,
+            stable_expectation_ids: self.stable_expectation_ids,
+        }
     }
 }
 
@@ -528,7 +630,7 @@ impl LintLevelMapBuilder<'_, '_> {
         F: FnOnce(&mut Self),
     {
         let is_crate_hir = id == hir::CRATE_HIR_ID;
-        let push = self.levels.push(attrs, self.store, is_crate_hir);
+        let push = self.levels.push(attrs, self.store, is_crate_hir, Some(id));
         if push.changed {
             self.levels.register_id(id);
         }
@@ -102,7 +102,7 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
         }
 
         let ty = cx.typeck_results().expr_ty(&expr);
-        let type_permits_lack_of_use = check_must_use_ty(cx, ty, &expr, s.span, "", "", 1);
+        let type_permits_lack_of_use = check_must_use_ty(cx, ty, &expr, s.span, "", "", 1, true);
 
         let mut fn_warned = false;
         let mut op_warned = false;
@@ -170,6 +170,7 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
         }
 
         // Returns whether an error has been emitted (and thus another does not need to be later).
+        // `awaitable` is whether the statement's value could be awaited if `ty` is a future.
         fn check_must_use_ty<'tcx>(
             cx: &LateContext<'tcx>,
             ty: Ty<'tcx>,
@@ -178,6 +179,7 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
             descr_pre: &str,
             descr_post: &str,
             plural_len: usize,
+            awaitable: bool,
         ) -> bool {
             if ty.is_unit()
                 || cx.tcx.is_ty_uninhabited_from(
@@ -195,7 +197,25 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
                 ty::Adt(..) if ty.is_box() => {
                     let boxed_ty = ty.boxed_ty();
                     let descr_pre = &format!("{}boxed ", descr_pre);
-                    check_must_use_ty(cx, boxed_ty, expr, span, descr_pre, descr_post, plural_len)
+                    check_must_use_ty(
+                        cx, boxed_ty, expr, span, descr_pre, descr_post, plural_len, false,
+                    )
+                }
+                ty::Adt(def, substs) if Some(def.did) == cx.tcx.lang_items().pin_type() => {
+                    let pointer_ty = substs.type_at(0);
+                    if pointer_ty.is_box() {
+                        // A pinned box of a future is itself a future, so it can be awaited.
+                        let boxed_ty = pointer_ty.boxed_ty();
+                        let descr_pre = &format!("{}pinned boxed ", descr_pre);
+                        check_must_use_ty(
+                            cx, boxed_ty, expr, span, descr_pre, descr_post, plural_len, awaitable,
+                        )
+                    } else {
+                        let descr_pre = &format!("{}pinned ", descr_pre);
+                        check_must_use_ty(
+                            cx, pointer_ty, expr, span, descr_pre, descr_post, plural_len, false,
+                        )
+                    }
                 }
                 ty::Adt(def, _) => check_must_use_def(cx, def.did, span, descr_pre, descr_post),
                 ty::Opaque(def, _) => {
@@ -206,9 +226,33 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
                             predicate.skip_binders()
                         {
                             let def_id = poly_trait_predicate.trait_ref.def_id;
-                            let descr_pre =
-                                &format!("{}implementer{} of ", descr_pre, plural_suffix,);
-                            if check_must_use_def(cx, def_id, span, descr_pre, descr_post) {
+                            if check_must_use_implementer(
+                                cx, def_id, expr, span, descr_pre, descr_post, plural_len,
+                                awaitable,
+                            ) {
+                                has_emitted = true;
+                                break;
+                            }
+                        }
+                    }
+                    has_emitted
+                }
+                ty::Param(_) => {
+                    // A value of a generic type is must-use if one of the traits it is bound by
+                    // is, e.g. the future returned by a function generic over its return type.
+                    let mut has_emitted = false;
+                    for predicate in cx.param_env.caller_bounds() {
+                        if let ty::PredicateAtom::Trait(ref trait_predicate, _) =
+                            predicate.skip_binders()
+                        {
+                            if trait_predicate.self_ty() != ty {
+                                continue;
+                            }
+                            let def_id = trait_predicate.def_id();
+                            if check_must_use_implementer(
+                                cx, def_id, expr, span, descr_pre, descr_post, plural_len,
+                                awaitable,
+                            ) {
                                 has_emitted = true;
                                 break;
                             }
@@ -221,6 +265,13 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
                     for predicate in binder.skip_binder().iter() {
                         if let ty::ExistentialPredicate::Trait(ref trait_ref) = predicate {
                             let def_id = trait_ref.def_id;
+                            if Some(def_id) == cx.tcx.lang_items().future_trait() {
+                                check_must_use_future(
+                                    cx, expr, span, descr_pre, descr_post, plural_len, awaitable,
+                                );
+                                has_emitted = true;
+                                break;
+                            }
                             let descr_post =
                                 &format!(" trait object{}{}", plural_suffix, descr_post,);
                             if check_must_use_def(cx, def_id, span, descr_pre, descr_post) {
@@ -242,8 +293,9 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
                     for (i, ty) in tys.iter().map(|k| k.expect_ty()).enumerate() {
                         let descr_post = &format!(" in tuple element {}", i);
                         let span = *spans.get(i).unwrap_or(&span);
-                        if check_must_use_ty(cx, ty, expr, span, descr_pre, descr_post, plural_len)
-                        {
+                        if check_must_use_ty(
+                            cx, ty, expr, span, descr_pre, descr_post, plural_len, false,
+                        ) {
                             has_emitted = true;
                         }
                     }
@@ -253,7 +305,16 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
                     // If the array is definitely non-empty, we can do `#[must_use]` checking.
                     Some(n) if n != 0 => {
                         let descr_pre = &format!("{}array{} of ", descr_pre, plural_suffix,);
-                        check_must_use_ty(cx, ty, expr, span, descr_pre, descr_post, n as usize + 1)
+                        check_must_use_ty(
+                            cx,
+                            ty,
+                            expr,
+                            span,
+                            descr_pre,
+                            descr_post,
+                            n as usize + 1,
+                            false,
+                        )
                     }
                     // Otherwise, we don't lint, to avoid false positives.
                     _ => false,
@@ -284,6 +345,61 @@ impl<'tcx> LateLintPass<'tcx> for UnusedResults {
             }
         }
 
+        // Checks a value of a type implementing the trait `def_id`, whose use is required by the
+        // trait. Returns whether an error has been emitted.
+        fn check_must_use_implementer(
+            cx: &LateContext<'_>,
+            def_id: DefId,
+            expr: &hir::Expr<'_>,
+            span: Span,
+            descr_pre: &str,
+            descr_post: &str,
+            plural_len: usize,
+            awaitable: bool,
+        ) -> bool {
+            if Some(def_id) == cx.tcx.lang_items().future_trait() {
+                check_must_use_future(cx, expr, span, descr_pre, descr_post, plural_len, awaitable);
+                return true;
+            }
+            let descr_pre = &format!("{}implementer{} of ", descr_pre, pluralize!(plural_len));
+            check_must_use_def(cx, def_id, span, descr_pre, descr_post)
+        }
+
+        // Emits the error for an unused future, suggesting to `.await` it if it's `awaitable`
+        // and the statement is in an `async` body.
+        fn check_must_use_future(
+            cx: &LateContext<'_>,
+            expr: &hir::Expr<'_>,
+            span: Span,
+            descr_pre: &str,
+            descr_post: &str,
+            plural_len: usize,
+            awaitable: bool,
+        ) {
+            cx.struct_span_lint(UNUSED_MUST_USE, span, |lint| {
+                let mut err = lint.build(&format!(
+                    "unused {}future{}{} that must be used",
+                    descr_pre,
+                    pluralize!(plural_len),
+                    descr_post,
+                ));
+                err.note("futures do nothing unless you `.await` or poll them");
+                let in_async_body = cx.enclosing_body.map_or(false, |body| {
+                    let generator_kind = cx.tcx.hir().body(body).generator_kind;
+                    matches!(generator_kind, Some(hir::GeneratorKind::Async(_)))
+                });
+                if awaitable && in_async_body {
+                    err.span_suggestion_verbose(
+                        expr.span.shrink_to_hi(),
+                        "consider `.await`ing the future",
+                        ".await".to_string(),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                err.emit();
+            });
+        }
+
         // Returns whether an error has been emitted (and thus another does not need to be later).
         // FIXME: Args desc_{pre,post}_path could be made lazy by taking Fn() -> &str, but this
         // would make calling it a big awkward. Could also take String (so args are moved), but
@@ -664,6 +780,11 @@ trait UnusedDelimLint {
     fn check_stmt(&mut self, cx: &EarlyContext<'_>, s: &ast::Stmt) {
         match s.kind {
             StmtKind::Local(ref local) if Self::LINT_EXPR_IN_PATTERN_MATCHING_CTX => {
+                // Delimiters around the initializer of a `let...else` statement are often needed
+                // to keep the `else` from being parsed as part of the initializer.
+                if local.els.is_some() {
+                    return;
+                }
                 if let Some(ref value) = local.init {
                     self.check_unused_delims_expr(
                         cx,
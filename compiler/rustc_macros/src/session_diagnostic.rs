@@ -39,6 +39,25 @@ use std::collections::{BTreeSet, HashMap};
 ///     opt_sugg: Some(suggestion, Applicability::MachineApplicable),
 /// });
 /// ```
+///
+/// Instead of being written inline, the messages can be looked up in the message catalogs of
+/// `rustc_errors::translation`, so that they can be translated. The `slug` attribute gives the
+/// identifier of the message, whose value is used for `#[message]` and whose attributes are
+/// used for `#[label]` (the `label` attribute) and `#[label = "name"]` (the `name` attribute).
+/// The fields without an annotation are the arguments of the message:
+/// ```ignore (pseudo-rust)
+/// #[derive(SessionDiagnostic)]
+/// #[error = "E0124"]
+/// #[slug = "typeck-field-already-declared"]
+/// pub struct FieldAlreadyDeclared {
+///     pub field_name: Ident,
+///     #[message]
+///     #[label]
+///     pub span: Span,
+///     #[label = "previous-decl-label"]
+///     pub prev_span: Span,
+/// }
+/// ```
 pub fn session_diagnostic_derive(s: synstructure::Structure<'_>) -> proc_macro2::TokenStream {
     // Names for the diagnostic we build and the session we build it from.
     let diag = format_ident!("diag");
@@ -149,7 +168,13 @@ impl<'a> SessionDiagnosticDerive<'a> {
         }
 
         Self {
-            builder: SessionDiagnosticDeriveBuilder { diag, sess, fields: fields_map, kind: None },
+            builder: SessionDiagnosticDeriveBuilder {
+                diag,
+                sess,
+                fields: fields_map,
+                kind: None,
+                slug: None,
+            },
             structure,
         }
     }
@@ -203,7 +228,9 @@ impl<'a> SessionDiagnosticDerive<'a> {
                         DiagnosticId::Lint(_lint) => todo!(),
                         DiagnosticId::Error(code) => {
                             let (diag, sess) = (&builder.diag, &builder.sess);
+                            let args = builder.generate_args(&structure);
                             quote! {
+                                #args
                                 let mut #diag = #sess.struct_err_with_code("", rustc_errors::DiagnosticId::Error(#code));
                                 #preamble
                                 match self {
@@ -268,18 +295,102 @@ struct SessionDiagnosticDeriveBuilder<'a> {
     /// stores at what Span the kind was first set at (for error reporting purposes, if the kind
     /// was multiply specified).
     kind: Option<(DiagnosticId, proc_macro2::Span)>,
+
+    /// The identifier of the message in the message catalogs, if the messages of the diagnostic
+    /// are translatable rather than written in the attributes.
+    slug: Option<syn::LitStr>,
 }
 
 impl<'a> SessionDiagnosticDeriveBuilder<'a> {
+    /// For translatable diagnostics, binds the arguments of the message, i.e. the fields without
+    /// attributes, before `self` is destructured.
+    fn generate_args(&self, structure: &synstructure::Structure<'_>) -> proc_macro2::TokenStream {
+        let fields = match (&self.slug, &structure.ast().data) {
+            (Some(_), syn::Data::Struct(data)) => &data.fields,
+            _ => return quote! {},
+        };
+        let args = fields
+            .iter()
+            .filter(|field| field.attrs.is_empty())
+            .filter_map(|field| field.ident.as_ref())
+            .map(|ident| {
+                let name = ident.to_string();
+                quote! { (#name, self.#ident.to_string()) }
+            });
+        quote! {
+            let __diagnostic_args: Vec<rustc_errors::DiagnosticArg<'_>> = vec![#(#args),*];
+        }
+    }
+
+    /// Builds the call translating the value of the message, or its attribute `attr`.
+    fn translate_message(&self, attr: Option<&str>) -> proc_macro2::TokenStream {
+        let (sess, slug) = (&self.sess, &self.slug);
+        let attr = match attr {
+            Some(attr) => quote! { Some(#attr) },
+            None => quote! { None },
+        };
+        quote! {
+            #sess.diagnostic().translate(#slug, #attr, &__diagnostic_args)
+        }
+    }
+
+    /// Builds the message of a `#[name = "..."]` attribute: a format string, or the name of an
+    /// attribute of the message for translatable diagnostics.
+    fn build_message(&self, value: &str, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+        if self.slug.is_some() {
+            self.translate_message(Some(value))
+        } else {
+            self.build_format(&value.to_string(), span)
+        }
+    }
+
+    /// Builds the message of a `#[message]` or `#[label]` attribute, which refer to the value and
+    /// the `label` attribute of the message of translatable diagnostics.
+    fn build_translated_message(
+        &self,
+        attr: &syn::Attribute,
+        name: &str,
+    ) -> Result<proc_macro2::TokenStream, SessionDiagnosticDeriveError> {
+        if self.slug.is_none() {
+            throw_span_err!(
+                attr.span().unwrap(),
+                &format!("`#[{}]` requires the `slug` of the message to be specified", name),
+                |diag| diag.help("use the [slug = \"...\"] attribute to set the message identifier")
+            );
+        }
+        Ok(match name {
+            "message" => self.translate_message(None),
+            _ => self.translate_message(Some(name)),
+        })
+    }
+
     fn generate_structure_code(
         &mut self,
         attr: &syn::Attribute,
     ) -> Result<proc_macro2::TokenStream, SessionDiagnosticDeriveError> {
         Ok(match attr.parse_meta()? {
+            syn::Meta::Path(path) if path.is_ident("message") => {
+                let translated = self.build_translated_message(attr, "message")?;
+                let diag = &self.diag;
+                quote! {
+                    #diag.set_primary_message(#translated);
+                }
+            }
             syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(s), .. }) => {
-                let formatted_str = self.build_format(&s.value(), attr.span());
                 let name = attr.path.segments.last().unwrap().ident.to_string();
                 let name = name.as_str();
+                if name == "slug" {
+                    if self.slug.is_some() {
+                        throw_span_err!(attr.span().unwrap(), "`slug` specified multiple times");
+                    }
+                    self.slug = Some(s);
+                    // The slug is used by the other attributes rather than generating code.
+                    return Ok(quote! {});
+                }
+                let formatted_str = match name {
+                    "message" => self.build_message(&s.value(), attr.span()),
+                    _ => self.build_format(&s.value(), attr.span()),
+                };
                 match name {
                     "message" => {
                         let diag = &self.diag;
@@ -383,9 +494,21 @@ impl<'a> SessionDiagnosticDeriveBuilder<'a> {
         // At this point, we need to dispatch based on the attribute key + the
         // type.
         let meta = attr.parse_meta()?;
-        Ok(match meta {
+        let message = match &meta {
             syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(s), .. }) => {
-                let formatted_str = self.build_format(&s.value(), attr.span());
+                Some(self.build_message(&s.value(), attr.span()))
+            }
+            syn::Meta::Path(_) if name == "message" || name == "label" => {
+                Some(self.build_translated_message(attr, name)?)
+            }
+            _ => None,
+        };
+        Ok(match meta {
+            syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(_), .. })
+            | syn::Meta::Path(_)
+                if message.is_some() =>
+            {
+                let formatted_str = message.unwrap();
                 match name {
                     "message" => {
                         if type_matches_path(&info.ty, &["rustc_span", "Span"]) {
@@ -512,13 +635,12 @@ impl<'a> SessionDiagnosticDeriveBuilder<'a> {
                                         .ident
                                         .to_string();
                                     let name = name.as_str();
-                                    let formatted_str = self.build_format(&s.value(), arg.span());
                                     match name {
                                         "message" => {
-                                            msg = Some(formatted_str);
+                                            msg = Some(self.build_message(&s.value(), arg.span()));
                                         }
                                         "code" => {
-                                            code = Some(formatted_str);
+                                            code = Some(self.build_format(&s.value(), arg.span()));
                                         }
                                         other => throw_span_err!(
                                             arg.span().unwrap(),
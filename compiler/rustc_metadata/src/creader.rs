@@ -860,6 +860,7 @@ impl<'a> CrateLoader<'a> {
     fn report_unused_deps(&mut self, krate: &ast::Crate) {
         // Make a point span rather than covering the whole file
         let span = krate.span.shrink_to_lo();
+        let mut unused_externs = Vec::new();
         // Complain about anything left over
         for (name, entry) in self.sess.opts.externs.iter() {
             if let ExternLocation::FoundInLibrarySearchDirectories = entry.location {
@@ -867,6 +868,7 @@ impl<'a> CrateLoader<'a> {
                 continue;
             }
             if !self.used_extern_options.contains(&Symbol::intern(name)) {
+                unused_externs.push(name.as_str());
                 self.sess.parse_sess.buffer_lint(
                     lint::builtin::UNUSED_CRATE_DEPENDENCIES,
                     span,
@@ -879,6 +881,12 @@ impl<'a> CrateLoader<'a> {
                 );
             }
         }
+
+        // Let the build tool know which dependencies this target doesn't need, so that it
+        // can warn about the ones unused by all the targets of the package.
+        if self.sess.opts.json_unused_externs {
+            self.sess.diagnostic().emit_unused_externs(&unused_externs);
+        }
     }
 
     pub fn postprocess(&mut self, krate: &ast::Crate) {
@@ -637,7 +637,7 @@ impl EntryKind {
             EntryKind::TypeParam => DefKind::TyParam,
             EntryKind::ConstParam => DefKind::ConstParam,
             EntryKind::OpaqueTy => DefKind::OpaqueTy,
-            EntryKind::AssocType(_) => DefKind::AssocTy,
+            EntryKind::AssocType(..) => DefKind::AssocTy,
             EntryKind::Mod(_) => DefKind::Mod,
             EntryKind::Variant(_) => DefKind::Variant,
             EntryKind::Trait(_) => DefKind::Trait,
@@ -949,6 +949,14 @@ impl<'a, 'tcx> CrateMetadataRef<'a> {
         self.root.tables.ty.get(self, id).unwrap().decode((self, tcx))
     }
 
+    fn get_const_param_default(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        id: DefIndex,
+    ) -> rustc_middle::ty::Const<'tcx> {
+        self.root.tables.const_defaults.get(self, id).unwrap().decode((self, tcx))
+    }
+
     fn get_stability(&self, id: DefIndex) -> Option<attr::Stability> {
         match self.is_proc_macro(id) {
             true => self.root.proc_macro_data.as_ref().unwrap().stability,
@@ -1230,6 +1238,16 @@ impl<'a, 'tcx> CrateMetadataRef<'a> {
             .unwrap_or_default()
     }
 
+    fn get_layout_only_generic_params(&self, id: DefIndex) -> FiniteBitSet<u32> {
+        self.root
+            .tables
+            .layout_only_generic_params
+            .get(self, id)
+            .filter(|_| !self.is_proc_macro(id))
+            .map(|params| params.decode(self))
+            .unwrap_or_default()
+    }
+
     fn get_promoted_mir(&self, tcx: TyCtxt<'tcx>, id: DefIndex) -> IndexVec<Promoted, Body<'tcx>> {
         self.root
             .tables
@@ -1262,13 +1280,17 @@ impl<'a, 'tcx> CrateMetadataRef<'a> {
         let parent = self.local_def_id(def_key.parent.unwrap());
         let ident = self.item_ident(id, sess);
 
-        let (kind, container, has_self) = match self.kind(id) {
-            EntryKind::AssocConst(container, _, _) => (ty::AssocKind::Const, container, false),
+        let (kind, container, has_self, impl_trait_fn) = match self.kind(id) {
+            EntryKind::AssocConst(container, _, _) => {
+                (ty::AssocKind::Const, container, false, None)
+            }
             EntryKind::AssocFn(data) => {
                 let data = data.decode(self);
-                (ty::AssocKind::Fn, data.container, data.has_self)
+                (ty::AssocKind::Fn, data.container, data.has_self, None)
+            }
+            EntryKind::AssocType(container, impl_trait_fn) => {
+                (ty::AssocKind::Type, container, false, impl_trait_fn)
             }
-            EntryKind::AssocType(container) => (ty::AssocKind::Type, container, false),
             _ => bug!("cannot get associated-item of `{:?}`", def_key),
         };
 
@@ -1280,6 +1302,7 @@ impl<'a, 'tcx> CrateMetadataRef<'a> {
             def_id: self.local_def_id(id),
             container: container.with_def_id(parent),
             fn_has_self_parameter: has_self,
+            impl_trait_fn: impl_trait_fn.map(|index| self.local_def_id(index)),
         }
     }
 
@@ -989,7 +989,8 @@ impl EncodeContext<'a, 'tcx> {
             }
             ty::AssocKind::Type => {
                 self.encode_explicit_item_bounds(def_id);
-                EntryKind::AssocType(container)
+                let impl_trait_fn = trait_item.impl_trait_fn.map(|def_id| def_id.index);
+                EntryKind::AssocType(container, impl_trait_fn)
             }
         });
         record!(self.tables.visibility[def_id] <- self.tcx.visibility(def_id));
@@ -1072,7 +1073,10 @@ impl EncodeContext<'a, 'tcx> {
                     has_self: impl_item.fn_has_self_parameter,
                 }))
             }
-            ty::AssocKind::Type => EntryKind::AssocType(container)
+            ty::AssocKind::Type => {
+                let impl_trait_fn = impl_item.impl_trait_fn.map(|def_id| def_id.index);
+                EntryKind::AssocType(container, impl_trait_fn)
+            }
         });
         record!(self.tables.visibility[def_id] <- self.tcx.visibility(def_id));
         record!(self.tables.span[def_id] <- ast_item.span);
@@ -1129,6 +1133,11 @@ impl EncodeContext<'a, 'tcx> {
                 record!(self.tables.unused_generic_params[def_id.to_def_id()] <- unused);
             }
 
+            let layout_only = self.tcx.layout_only_generic_params(def_id);
+            if !layout_only.is_empty() {
+                record!(self.tables.layout_only_generic_params[def_id.to_def_id()] <- layout_only);
+            }
+
             let abstract_const = self.tcx.mir_abstract_const(def_id);
             if let Ok(Some(abstract_const)) = abstract_const {
                 record!(self.tables.mir_abstract_consts[def_id.to_def_id()] <- abstract_const);
@@ -1802,13 +1811,18 @@ impl EncodeContext<'a, 'tcx> {
                         self.encode_stability(def_id.to_def_id());
                     }
                 }
-                GenericParamKind::Const { .. } => {
+                GenericParamKind::Const { ref default, .. } => {
                     self.encode_info_for_generic_param(
                         def_id.to_def_id(),
                         EntryKind::ConstParam,
                         true,
                     );
-                    // FIXME(const_generics:defaults)
+                    if default.is_some() {
+                        let def_id = def_id.to_def_id();
+                        let default = self.tcx.const_param_default(def_id);
+                        record!(self.tables.const_defaults[def_id] <- default);
+                        self.encode_stability(def_id);
+                    }
                 }
             }
         }
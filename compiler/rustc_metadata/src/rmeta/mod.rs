@@ -288,6 +288,7 @@ define_tables! {
     const_stability: Table<DefIndex, Lazy<attr::ConstStability>>,
     deprecation: Table<DefIndex, Lazy<attr::Deprecation>>,
     ty: Table<DefIndex, Lazy!(Ty<'tcx>)>,
+    const_defaults: Table<DefIndex, Lazy!(ty::Const<'tcx>)>,
     fn_sig: Table<DefIndex, Lazy!(ty::PolyFnSig<'tcx>)>,
     impl_trait_ref: Table<DefIndex, Lazy!(ty::TraitRef<'tcx>)>,
     inherent_impls: Table<DefIndex, Lazy<[DefIndex]>>,
@@ -304,6 +305,7 @@ define_tables! {
     promoted_mir: Table<DefIndex, Lazy!(IndexVec<mir::Promoted, mir::Body<'tcx>>)>,
     mir_abstract_consts: Table<DefIndex, Lazy!(&'tcx [mir::abstract_const::Node<'tcx>])>,
     unused_generic_params: Table<DefIndex, Lazy<FiniteBitSet<u32>>>,
+    layout_only_generic_params: Table<DefIndex, Lazy<FiniteBitSet<u32>>>,
     // `def_keys` and `def_path_hashes` represent a lazy version of a
     // `DefPathTable`. This allows us to avoid deserializing an entire
     // `DefPathTable` up front, since we may only ever use a few
@@ -341,7 +343,7 @@ enum EntryKind {
     Trait(Lazy<TraitData>),
     Impl(Lazy<ImplData>),
     AssocFn(Lazy<AssocFnData>),
-    AssocType(AssocContainer),
+    AssocType(AssocContainer, Option<DefIndex>),
     AssocConst(AssocContainer, mir::ConstQualifs, Lazy<RenderedConst>),
     TraitAlias,
 }
@@ -5,7 +5,7 @@ use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_errors::{DiagnosticBuilder, DiagnosticId};
 use rustc_hir::HirId;
-use rustc_session::lint::{builtin, Level, Lint, LintId};
+use rustc_session::lint::{builtin, Level, Lint, LintExpectationId, LintId};
 use rustc_session::{DiagnosticMessageId, Session};
 use rustc_span::hygiene::MacroKind;
 use rustc_span::source_map::{DesugaringKind, ExpnKind, MultiSpan};
@@ -23,6 +23,9 @@ pub enum LintSource {
 
     /// Lint level was set by a command-line flag.
     CommandLine(Symbol),
+
+    /// Lint level was set by the lint configuration file passed with `--lint-config`.
+    ConfigFile(Symbol),
 }
 
 impl LintSource {
@@ -31,6 +34,7 @@ impl LintSource {
             LintSource::Default => symbol::kw::Default,
             LintSource::Node(name, _, _) => name,
             LintSource::CommandLine(name) => name,
+            LintSource::ConfigFile(name) => name,
         }
     }
 
@@ -39,6 +43,7 @@ impl LintSource {
             LintSource::Default => DUMMY_SP,
             LintSource::Node(_, span, _) => span,
             LintSource::CommandLine(_) => DUMMY_SP,
+            LintSource::ConfigFile(_) => DUMMY_SP,
         }
     }
 }
@@ -136,9 +141,26 @@ impl LintLevelSets {
     }
 }
 
+/// A lint named in an `#[expect(...)]` attribute (RFC 2383).
+#[derive(Clone, Copy, HashStable)]
+pub struct LintExpectation {
+    pub id: LintExpectationId,
+    /// The lint name as written in the attribute.
+    pub lint_name: Symbol,
+    /// The span of the lint name.
+    pub span: Span,
+    pub reason: Option<Symbol>,
+    /// The lint set in effect for the node carrying the attribute, used to
+    /// find the level of `unfulfilled_lint_expectations` itself.
+    pub lint_set: u32,
+}
+
 pub struct LintLevelMap {
     pub sets: LintLevelSets,
     pub id_to_set: FxHashMap<HirId, u32>,
+    pub expectations: Vec<LintExpectation>,
+    /// The `Stable` expectation id of each `Unstable` one set for the early lint passes.
+    pub stable_expectation_ids: FxHashMap<LintExpectationId, LintExpectationId>,
 }
 
 impl LintLevelMap {
@@ -157,12 +179,24 @@ impl LintLevelMap {
     ) -> Option<LevelSource> {
         self.id_to_set.get(&id).map(|idx| self.sets.get_lint_level(lint, *idx, None, session))
     }
+
+    /// Converts the id of an expectation fulfilled by an early lint pass to the id of the
+    /// expectation in `expectations`, returning `None` if there is none for it.
+    pub fn stable_expectation_id(&self, id: LintExpectationId) -> Option<LintExpectationId> {
+        match id {
+            LintExpectationId::Unstable { .. } => self.stable_expectation_ids.get(&id).copied(),
+            LintExpectationId::Stable { .. } => Some(id),
+        }
+    }
 }
 
 impl<'a> HashStable<StableHashingContext<'a>> for LintLevelMap {
     #[inline]
     fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHasher) {
-        let LintLevelMap { ref sets, ref id_to_set } = *self;
+        // The attribute ids of `stable_expectation_ids` are only valid within a session, but
+        // it is implied by the rest of the map.
+        let LintLevelMap { ref sets, ref id_to_set, ref expectations, stable_expectation_ids: _ } =
+            *self;
 
         id_to_set.hash_stable(hcx, hasher);
 
@@ -188,6 +222,8 @@ impl<'a> HashStable<StableHashingContext<'a>> for LintLevelMap {
                     }
                 }
             }
+
+            expectations.hash_stable(hcx, hasher);
         })
     }
 }
@@ -225,20 +261,30 @@ pub fn struct_lint_level<'s, 'd>(
         span: Option<MultiSpan>,
         decorate: Box<dyn for<'b> FnOnce(LintDiagnosticBuilder<'b>) + 'd>,
     ) {
+        // Check for future incompatibility lints and issue a stronger warning.
+        let lint_id = LintId::of(lint);
+        let future_incompatible = lint.future_incompatible;
+        let has_future_breakage = future_incompatible.is_some();
+
         let mut err = match (level, span) {
+            // Allowed future-incompatible lints are still built, to be recorded for the
+            // future incompatibility report (see `-Z emit-future-incompat-report`).
+            (Level::Allow, Some(span)) if has_future_breakage => sess.struct_span_allow(span, ""),
+            (Level::Allow, None) if has_future_breakage => sess.struct_allow(""),
             (Level::Allow, _) => {
                 return;
             }
+            // The lint is suppressed, but it fulfils the `#[expect]` that suppressed it. This
+            // is recorded by emitting a diagnostic that is never shown, so that the queries
+            // loaded from the incremental cache replay it like their other diagnostics.
+            (Level::Expect(id), Some(span)) => sess.struct_span_expect(span, "", id),
+            (Level::Expect(id), None) => sess.struct_expect("", id),
             (Level::Warn, Some(span)) => sess.struct_span_warn(span, ""),
             (Level::Warn, None) => sess.struct_warn(""),
             (Level::Deny | Level::Forbid, Some(span)) => sess.struct_span_err(span, ""),
             (Level::Deny | Level::Forbid, None) => sess.struct_err(""),
         };
 
-        // Check for future incompatibility lints and issue a stronger warning.
-        let lint_id = LintId::of(lint);
-        let future_incompatible = lint.future_incompatible;
-
         // If this code originates in a foreign macro, aka something that this crate
         // did not itself author, then it's likely that there's nothing this crate
         // can do about it. We probably want to skip the lint entirely.
@@ -259,8 +305,16 @@ pub fn struct_lint_level<'s, 'd>(
             }
         }
 
+        if let Level::Expect(_) = level {
+            err.emit();
+            return;
+        }
+
         let name = lint.name_lower();
         match src {
+            // Allowed lints are only reported in the future incompatibility report, where
+            // how their level was set doesn't matter.
+            _ if level == Level::Allow => {}
             LintSource::Default => {
                 sess.diag_note_once(
                     &mut err,
@@ -273,7 +327,7 @@ pub fn struct_lint_level<'s, 'd>(
                     Level::Warn => "-W",
                     Level::Deny => "-D",
                     Level::Forbid => "-F",
-                    Level::Allow => panic!(),
+                    Level::Allow | Level::Expect(_) => panic!(),
                 };
                 let hyphen_case_lint_name = name.replace("_", "-");
                 if lint_flag_val.as_str() == name {
@@ -297,6 +351,28 @@ pub fn struct_lint_level<'s, 'd>(
                     );
                 }
             }
+            LintSource::ConfigFile(lint_config_name) => {
+                let level_str = level.as_str();
+                if lint_config_name.as_str() == name {
+                    sess.diag_note_once(
+                        &mut err,
+                        DiagnosticMessageId::from(lint),
+                        &format!(
+                            "requested in the lint configuration file with `{} = \"{}\"`",
+                            name, level_str
+                        ),
+                    );
+                } else {
+                    sess.diag_note_once(
+                        &mut err,
+                        DiagnosticMessageId::from(lint),
+                        &format!(
+                            "`{} = \"{}\"` implied by `{} = \"{}\"` in the lint configuration file",
+                            name, level_str, lint_config_name, level_str
+                        ),
+                    );
+                }
+            }
             LintSource::Node(lint_attr_name, src, reason) => {
                 if let Some(rationale) = reason {
                     err.note(&rationale.as_str());
@@ -321,7 +397,7 @@ pub fn struct_lint_level<'s, 'd>(
             }
         }
 
-        err.code(DiagnosticId::Lint(name));
+        err.code(DiagnosticId::Lint { name, has_future_breakage });
 
         if let Some(future_incompatible) = future_incompatible {
             const STANDARD_MESSAGE: &str = "this was previously accepted by the compiler but is being phased out; \
@@ -37,6 +37,17 @@ impl<'tcx> TyCtxt<'tcx> {
         }
     }
 
+    /// Returns the closure kind corresponding to the `AsyncFn`-family trait `id`, if any.
+    pub fn async_fn_trait_kind_from_lang_item(self, id: DefId) -> Option<ty::ClosureKind> {
+        let items = self.lang_items();
+        match Some(id) {
+            x if x == items.async_fn_trait() => Some(ty::ClosureKind::Fn),
+            x if x == items.async_fn_mut_trait() => Some(ty::ClosureKind::FnMut),
+            x if x == items.async_fn_once_trait() => Some(ty::ClosureKind::FnOnce),
+            _ => None,
+        }
+    }
+
     pub fn is_weak_lang_item(self, item_def_id: DefId) -> bool {
         self.lang_items().is_weak_lang_item(item_def_id)
     }
@@ -118,6 +118,12 @@ rustc_queries! {
             cache_on_disk_if { key.is_local() }
         }
 
+        /// Given the `DefId` of a const generic parameter with a default, computes
+        /// that default, e.g. `3` for `N` in `struct Foo<const N: usize = 3>`.
+        query const_param_default(param: DefId) -> &'tcx ty::Const<'tcx> {
+            desc { |tcx| "computing const default for `{}`", tcx.def_path_str(param) }
+        }
+
         query analysis(key: CrateNum) -> Result<(), ErrorReported> {
             eval_always
             desc { "running analysis passes on this crate" }
@@ -338,6 +344,27 @@ rustc_queries! {
             }
         }
 
+        /// Checks whether `key.0` (transitively) calls the function `key.1`. Used by the MIR
+        /// inliner to detect cycles between instances without relying on `HirId` ordering.
+        query mir_callgraph_reachable(key: (ty::Instance<'tcx>, LocalDefId)) -> bool {
+            fatal_cycle
+            desc { |tcx|
+                "computing if `{}` (transitively) calls `{}`",
+                key.0,
+                tcx.def_path_str(key.1.to_def_id()),
+            }
+        }
+
+        /// Obtains all the callees of the given instance's MIR that may be inlined. The
+        /// callees are returned unresolved, with the substitutions of the MIR body.
+        query mir_inliner_callees(key: ty::InstanceDef<'tcx>) -> &'tcx [(DefId, SubstsRef<'tcx>)] {
+            fatal_cycle
+            desc { |tcx|
+                "computing all local function calls in `{}`",
+                tcx.def_path_str(key.def_id()),
+            }
+        }
+
         /// Returns coverage summary info for a function, after executing the `InstrumentCoverage`
         /// MIR pass (assuming the -Zinstrument-coverage option is enabled).
         query coverageinfo(key: DefId) -> mir::CoverageInfo {
@@ -1418,6 +1445,13 @@ rustc_queries! {
                     tcx.def_path_str(key)
             }
         }
+        query layout_only_generic_params(key: DefId) -> FiniteBitSet<u32> {
+            cache_on_disk_if { key.is_local() }
+            desc {
+                |tcx| "determining which generic parameters are only used for their layout by `{}`",
+                    tcx.def_path_str(key)
+            }
+        }
         query backend_optimization_level(_: CrateNum) -> OptLevel {
             desc { "optimization level used by backend" }
         }
@@ -500,6 +500,8 @@ fn polymorphize<'tcx>(
     debug!("polymorphize({:?}, {:?})", def_id, substs);
     let unused = tcx.unused_generic_params(def_id);
     debug!("polymorphize: unused={:?}", unused);
+    let layout_only = tcx.layout_only_generic_params(def_id);
+    debug!("polymorphize: layout_only={:?}", layout_only);
 
     // If this is a closure or generator then we need to handle the case where another closure
     // from the function is captured as an upvar and hasn't been polymorphized. In this case,
@@ -569,18 +571,49 @@ fn polymorphize<'tcx>(
                 },
 
             // Simple case: If parameter is a const or type parameter..
-            ty::GenericParamDefKind::Const | ty::GenericParamDefKind::Type { .. } if
+            ty::GenericParamDefKind::Const { .. } | ty::GenericParamDefKind::Type { .. } if
                 // ..and is within range and unused..
                 unused.contains(param.index).unwrap_or(false) =>
                     // ..then use the identity for this parameter.
                     tcx.mk_param_from_def(param),
 
+            // Layout case: If parameter is a type parameter that is only used for its layout..
+            ty::GenericParamDefKind::Type { .. } if
+                layout_only.contains(param.index).unwrap_or(false) => {
+                    // ..then use a canonical type with the same layout, if there is one.
+                    let ty = substs[param.index as usize].expect_ty();
+                    layout_equivalent_ty(tcx, ty).unwrap_or(ty).into()
+                },
+
             // Otherwise, use the parameter as before.
             _ => substs[param.index as usize],
         }
     })
 }
 
+/// Returns a canonical type with the same size and ABI alignment as `ty`: an array of the
+/// unsigned integer type whose size and alignment is the alignment of `ty`. Nothing else about
+/// the layout of `ty` is preserved, not even its preferred alignment. Returns `None` if `ty` is
+/// still generic, its layout can't be computed or there is no such integer type.
+fn layout_equivalent_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    if ty.needs_subst() {
+        return None;
+    }
+
+    let param_env = ty::ParamEnv::reveal_all();
+    let layout = tcx.layout_of(param_env.and(ty)).ok()?;
+    let align = layout.align.abi.bytes();
+    let unit = [tcx.types.u8, tcx.types.u16, tcx.types.u32, tcx.types.u64, tcx.types.u128]
+        .iter()
+        .copied()
+        .find(|&unit| {
+            tcx.layout_of(param_env.and(unit)).map_or(false, |unit_layout| {
+                unit_layout.size.bytes() == align && unit_layout.align.abi.bytes() == align
+            })
+        })?;
+    Some(tcx.mk_array(unit, layout.size.bytes() / align))
+}
+
 fn needs_fn_once_adapter_shim(
     actual_closure_kind: ty::ClosureKind,
     trait_closure_kind: ty::ClosureKind,
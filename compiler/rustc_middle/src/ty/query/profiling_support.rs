@@ -224,6 +224,51 @@ where
     }
 }
 
+/// The queries whose per-item cost is reported by `-Z self-profile-summary`.
+const SUMMARY_ITEM_QUERIES: &[&str] = &["typeck", "mir_borrowck", "optimized_mir"];
+
+/// Gives the item a query key refers to, for the "most expensive items"
+/// section of `-Z self-profile-summary`. Keys that don't refer to a single
+/// item are not reported.
+trait IntoSummaryItem {
+    fn summary_item(&self) -> Option<DefId>;
+}
+
+impl<T> IntoSummaryItem for T {
+    default fn summary_item(&self) -> Option<DefId> {
+        None
+    }
+}
+
+impl<T: SpecIntoSummaryItem> IntoSummaryItem for T {
+    fn summary_item(&self) -> Option<DefId> {
+        Some(self.spec_summary_item())
+    }
+}
+
+#[rustc_specialization_trait]
+trait SpecIntoSummaryItem {
+    fn spec_summary_item(&self) -> DefId;
+}
+
+impl SpecIntoSummaryItem for DefId {
+    fn spec_summary_item(&self) -> DefId {
+        *self
+    }
+}
+
+impl SpecIntoSummaryItem for LocalDefId {
+    fn spec_summary_item(&self) -> DefId {
+        self.to_def_id()
+    }
+}
+
+impl SpecIntoSummaryItem for WithOptConstParam<LocalDefId> {
+    fn spec_summary_item(&self) -> DefId {
+        self.did.to_def_id()
+    }
+}
+
 /// Allocate the self-profiling query strings for a single query cache. This
 /// method is called from `alloc_self_profile_query_strings` which knows all
 /// the queries via macro magic.
@@ -239,6 +284,22 @@ pub(super) fn alloc_self_profile_query_strings_for_query_cache<'tcx, C>(
     tcx.prof.with_profiler(|profiler| {
         let event_id_builder = profiler.event_id_builder();
 
+        // Tell the summary which query each invocation belongs to, and for the
+        // queries that are interesting per item, which item it was for.
+        if profiler.summary_enabled() {
+            let report_items = SUMMARY_ITEM_QUERIES.contains(&query_name);
+            let query_keys_and_indices: Vec<_> = query_state
+                .iter_results(|results| results.map(|(k, _, i)| (k.clone(), i)).collect());
+            for (query_key, dep_node_index) in query_keys_and_indices {
+                let item = if report_items {
+                    query_key.summary_item().map(|def_id| tcx.def_path_str(def_id))
+                } else {
+                    None
+                };
+                profiler.name_query_invocation_for_summary(dep_node_index.into(), query_name, item);
+            }
+        }
+
         // Walk the entire query cache and allocate the appropriate
         // string representations. Each cache entry is uniquely
         // identified by its dep_node_index.
@@ -1,6 +1,7 @@
 //! Error reporting machinery for lifetime errors.
 
 use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_hir as hir;
 use rustc_infer::infer::{
     error_reporting::nice_region_error::NiceRegionError,
     error_reporting::unexpected_hidden_region_diagnostic, NLLRegionVariableOrigin,
@@ -364,13 +365,29 @@ impl<'a, 'tcx> MirBorrowckCtxt<'a, 'tcx> {
             .sess
             .struct_span_err(*span, "captured variable cannot escape `FnMut` closure body");
 
+        let tcx = self.infcx.tcx;
         let mut output_ty = self.regioncx.universal_regions().unnormalized_output_ty;
-        if let ty::Opaque(def_id, _) = *output_ty.kind() {
-            output_ty = self.infcx.tcx.type_of(def_id)
+        if let ty::Opaque(def_id, substs) = *output_ty.kind() {
+            output_ty = tcx.type_of(def_id).subst(tcx, substs)
         };
 
         debug!("report_fnmut_error: output_ty={:?}", output_ty);
 
+        // The body of an `async` closure is a generator returned by the closure, wrapped in a
+        // `GenFuture`.
+        let is_async_closure = match *output_ty.kind() {
+            ty::Adt(def, substs) if tcx.is_diagnostic_item(sym::gen_future, def.did) => {
+                match *substs.type_at(0).kind() {
+                    ty::Generator(generator_def_id, ..) => {
+                        tcx.generator_kind(generator_def_id)
+                            == Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Closure))
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
         let message = match output_ty.kind() {
             ty::Closure(_, _) => {
                 "returns a closure that contains a reference to a captured variable, which then \
@@ -406,6 +423,12 @@ impl<'a, 'tcx> MirBorrowckCtxt<'a, 'tcx> {
              executing...",
         );
         diag.note("...therefore, they cannot allow references to captured variables to escape");
+        if is_async_closure {
+            diag.note(
+                "the future returned by an `async` closure cannot borrow from the closure \
+                 itself, so `async` closures cannot mutate their captured variables",
+            );
+        }
 
         diag
     }
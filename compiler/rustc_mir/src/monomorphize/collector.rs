@@ -274,23 +274,33 @@ impl<'tcx> InliningMap<'tcx> {
     }
 }
 
+/// Maps every polymorphized instance to the instances that were polymorphized into it. Only
+/// recorded for `-Z polymorphize-stats`.
+type MergedInstances<'tcx> = FxHashMap<Instance<'tcx>, FxHashSet<Instance<'tcx>>>;
+
 pub fn collect_crate_mono_items(
     tcx: TyCtxt<'_>,
     mode: MonoItemCollectionMode,
 ) -> (FxHashSet<MonoItem<'_>>, InliningMap<'_>) {
     let _prof_timer = tcx.prof.generic_activity("monomorphization_collector");
 
-    let roots =
+    let mut roots =
         tcx.sess.time("monomorphization_collector_root_collections", || collect_roots(tcx, mode));
 
     debug!("building mono item graph, beginning at roots");
 
     let mut visited = MTLock::new(FxHashSet::default());
     let mut inlining_map = MTLock::new(InliningMap::new());
+    let mut merged_instances = MTLock::new(MergedInstances::default());
 
     {
         let visited: MTRef<'_, _> = &mut visited;
         let inlining_map: MTRef<'_, _> = &mut inlining_map;
+        let merged_instances: MTRef<'_, _> = &mut merged_instances;
+
+        for root in &mut roots {
+            polymorphize_mono_item(tcx, root, merged_instances);
+        }
 
         tcx.sess.time("monomorphization_collector_graph_walk", || {
             par_iter(roots).for_each(|root| {
@@ -300,15 +310,49 @@ pub fn collect_crate_mono_items(
                     dummy_spanned(root),
                     visited,
                     &mut recursion_depths,
+                    &mut Vec::new(),
                     inlining_map,
+                    merged_instances,
                 );
             });
         });
     }
 
+    if tcx.sess.opts.debugging_opts.polymorphize_stats {
+        report_merged_instances(tcx, merged_instances.into_inner());
+    }
+
     (visited.into_inner(), inlining_map.into_inner())
 }
 
+/// Reports the polymorphized instances that more than one instance was merged into, along with
+/// the total number of merged instances, as a note.
+fn report_merged_instances(tcx: TyCtxt<'_>, merged_instances: MergedInstances<'_>) {
+    let mut merged: Vec<(String, Vec<String>)> = merged_instances
+        .into_iter()
+        .filter(|(_, instances)| instances.len() > 1)
+        .map(|(polymorphized, instances)| {
+            let mut instances: Vec<_> = instances.iter().map(|i| i.to_string()).collect();
+            instances.sort();
+            (polymorphized.to_string(), instances)
+        })
+        .collect();
+    merged.sort();
+
+    let total: usize = merged.iter().map(|(_, instances)| instances.len()).sum();
+    let mut diag = tcx.sess.struct_note_without_error(&format!(
+        "polymorphization merged {} instances into {}",
+        total,
+        merged.len()
+    ));
+    for (polymorphized, instances) in &merged {
+        for instance in instances {
+            diag.note(&format!("`{}` was merged into `{}`", instance, polymorphized));
+        }
+    }
+    diag.emit();
+}
+
 // Find all non-generic items by walking the HIR. These items serve as roots to
 // start monomorphizing from.
 fn collect_roots(tcx: TyCtxt<'_>, mode: MonoItemCollectionMode) -> Vec<MonoItem<'_>> {
@@ -342,7 +386,9 @@ fn collect_items_rec<'tcx>(
     starting_point: Spanned<MonoItem<'tcx>>,
     visited: MTRef<'_, MTLock<FxHashSet<MonoItem<'tcx>>>>,
     recursion_depths: &mut DefIdMap<usize>,
+    instantiation_chain: &mut Vec<Instance<'tcx>>,
     inlining_map: MTRef<'_, MTLock<InliningMap<'tcx>>>,
+    merged_instances: MTRef<'_, MTLock<MergedInstances<'tcx>>>,
 ) {
     if !visited.lock_mut().insert(starting_point.node) {
         // We've been here already, no need to search again.
@@ -376,9 +422,15 @@ fn collect_items_rec<'tcx>(
             debug_assert!(should_codegen_locally(tcx, &instance));
 
             // Keep track of the monomorphization recursion depth
-            recursion_depth_reset =
-                Some(check_recursion_limit(tcx, instance, starting_point.span, recursion_depths));
-            check_type_length_limit(tcx, instance);
+            recursion_depth_reset = Some(check_recursion_limit(
+                tcx,
+                instance,
+                starting_point.span,
+                recursion_depths,
+                instantiation_chain,
+            ));
+            check_type_length_limit(tcx, instance, instantiation_chain);
+            instantiation_chain.push(instance);
 
             rustc_data_structures::stack::ensure_sufficient_stack(|| {
                 collect_neighbours(tcx, instance, &mut neighbors);
@@ -389,14 +441,27 @@ fn collect_items_rec<'tcx>(
         }
     }
 
+    for neighbour in &mut neighbors {
+        polymorphize_mono_item(tcx, &mut neighbour.node, merged_instances);
+    }
+
     record_accesses(tcx, starting_point.node, neighbors.iter().map(|i| &i.node), inlining_map);
 
     for neighbour in neighbors {
-        collect_items_rec(tcx, neighbour, visited, recursion_depths, inlining_map);
+        collect_items_rec(
+            tcx,
+            neighbour,
+            visited,
+            recursion_depths,
+            instantiation_chain,
+            inlining_map,
+            merged_instances,
+        );
     }
 
     if let Some((def_id, depth)) = recursion_depth_reset {
         recursion_depths.insert(def_id, depth);
+        instantiation_chain.pop();
     }
 
     debug!("END collect_items_rec({})", starting_point.node);
@@ -455,11 +520,61 @@ fn shrunk_instance_name(
     }
 }
 
+/// Returns the functions of the chain of instantiations that led to `instance`, from a root
+/// mono item, and the range of the first cycle that repeats in them, if any.
+fn instantiation_chain_cycle<'tcx>(
+    instantiation_chain: &[Instance<'tcx>],
+    instance: Instance<'tcx>,
+) -> (Vec<DefId>, Option<Range<usize>>) {
+    let def_ids: Vec<DefId> = instantiation_chain
+        .iter()
+        .chain(iter::once(&instance))
+        .map(|instance| instance.def_id())
+        .collect();
+    let cycle = def_ids.iter().enumerate().find_map(|(start, def_id)| {
+        let len = def_ids[start + 1..].iter().position(|other| other == def_id)? + 1;
+        Some(start..start + len)
+    });
+    (def_ids, cycle)
+}
+
+/// Describes the chain of instantiations that led to `instance`, from a root mono item, by
+/// the paths of the functions. When a function is instantiated again, the chain is cut at
+/// the cycle that repeats, as it would otherwise be as long as the recursion limit.
+fn describe_instantiation_chain<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    instantiation_chain: &[Instance<'tcx>],
+    instance: Instance<'tcx>,
+) -> String {
+    let describe = |def_ids: &[DefId]| {
+        def_ids
+            .iter()
+            .map(|&def_id| format!("`{}`", tcx.def_path_str(def_id)))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    };
+
+    let (def_ids, cycle) = instantiation_chain_cycle(instantiation_chain, instance);
+    match cycle {
+        None => format!("the chain of instantiations that led here is {}", describe(&def_ids)),
+        Some(cycle) if cycle.start == 0 => format!(
+            "the chain of instantiations that led here repeats {}",
+            describe(&def_ids[cycle]),
+        ),
+        Some(cycle) => format!(
+            "the chain of instantiations that led here starts with {} and then repeats {}",
+            describe(&def_ids[..cycle.start]),
+            describe(&def_ids[cycle]),
+        ),
+    }
+}
+
 fn check_recursion_limit<'tcx>(
     tcx: TyCtxt<'tcx>,
     instance: Instance<'tcx>,
     span: Span,
     recursion_depths: &mut DefIdMap<usize>,
+    instantiation_chain: &[Instance<'tcx>],
 ) -> (DefId, usize) {
     let def_id = instance.def_id();
     let recursion_depth = recursion_depths.get(&def_id).cloned().unwrap_or(0);
@@ -487,6 +602,17 @@ fn check_recursion_limit<'tcx>(
         if let Some(path) = written_to_path {
             err.note(&format!("the full type name has been written to '{}'", path.display()));
         }
+        err.note(&describe_instantiation_chain(tcx, instantiation_chain, instance));
+        // A function instantiating itself again and again rarely ends, but a cycle through
+        // several functions may just be deep, so a limit letting it through is suggested.
+        let (_, cycle) = instantiation_chain_cycle(instantiation_chain, instance);
+        if cycle.map_or(true, |cycle| cycle.len() > 1) {
+            err.help(&format!(
+                "consider adding a `#![recursion_limit = \"{}\"]` attribute to your crate (`{}`)",
+                adjusted_recursion_depth + 1,
+                tcx.crate_name(LOCAL_CRATE),
+            ));
+        }
         err.emit();
         FatalError.raise();
     }
@@ -496,7 +622,11 @@ fn check_recursion_limit<'tcx>(
     (def_id, recursion_depth)
 }
 
-fn check_type_length_limit<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) {
+fn check_type_length_limit<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    instance: Instance<'tcx>,
+    instantiation_chain: &[Instance<'tcx>],
+) {
     let type_length = instance
         .substs
         .iter()
@@ -521,6 +651,7 @@ fn check_type_length_limit<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) {
         if let Some(path) = written_to_path {
             diag.note(&format!("the full type name has been written to '{}'", path.display()));
         }
+        diag.note(&describe_instantiation_chain(tcx, instantiation_chain, instance));
         diag.help(&format!(
             "consider adding a `#![type_length_limit=\"{}\"]` attribute to your crate",
             type_length
@@ -609,7 +740,7 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
                             ty::ClosureKind::FnOnce,
                         );
                         if should_codegen_locally(self.tcx, &instance) {
-                            self.output.push(create_fn_mono_item(self.tcx, instance, span));
+                            self.output.push(create_fn_mono_item(instance, span));
                         }
                     }
                     _ => bug!(),
@@ -621,7 +752,7 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
                     tcx.require_lang_item(LangItem::ExchangeMalloc, None);
                 let instance = Instance::mono(tcx, exchange_malloc_fn_def_id);
                 if should_codegen_locally(tcx, &instance) {
-                    self.output.push(create_fn_mono_item(self.tcx, instance, span));
+                    self.output.push(create_fn_mono_item(instance, span));
                 }
             }
             mir::Rvalue::ThreadLocalRef(def_id) => {
@@ -773,7 +904,7 @@ fn visit_instance_use<'tcx>(
         ty::InstanceDef::DropGlue(_, None) => {
             // Don't need to emit noop drop glue if we are calling directly.
             if !is_direct_call {
-                output.push(create_fn_mono_item(tcx, instance, source));
+                output.push(create_fn_mono_item(instance, source));
             }
         }
         ty::InstanceDef::DropGlue(_, Some(_))
@@ -783,7 +914,7 @@ fn visit_instance_use<'tcx>(
         | ty::InstanceDef::Item(..)
         | ty::InstanceDef::FnPtrShim(..)
         | ty::InstanceDef::CloneShim(..) => {
-            output.push(create_fn_mono_item(tcx, instance, source));
+            output.push(create_fn_mono_item(instance, source));
         }
     }
 }
@@ -927,13 +1058,27 @@ fn find_vtable_types_for_unsizing<'tcx>(
     }
 }
 
-fn create_fn_mono_item<'tcx>(
-    tcx: TyCtxt<'tcx>,
-    instance: Instance<'tcx>,
-    source: Span,
-) -> Spanned<MonoItem<'tcx>> {
+/// Creates a `MonoItem` for `instance`. The instance is polymorphized by `polymorphize_mono_item`
+/// before the item is added to the mono item graph.
+fn create_fn_mono_item<'tcx>(instance: Instance<'tcx>, source: Span) -> Spanned<MonoItem<'tcx>> {
     debug!("create_fn_mono_item(instance={})", instance);
-    respan(source, MonoItem::Fn(instance.polymorphize(tcx)))
+    respan(source, MonoItem::Fn(instance))
+}
+
+/// Replaces the instance of a function mono item by its polymorphized instance, recording which
+/// instances were merged for `-Z polymorphize-stats`.
+fn polymorphize_mono_item<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    mono_item: &mut MonoItem<'tcx>,
+    merged_instances: MTRef<'_, MTLock<MergedInstances<'tcx>>>,
+) {
+    if let MonoItem::Fn(instance) = *mono_item {
+        let polymorphized = instance.polymorphize(tcx);
+        if tcx.sess.opts.debugging_opts.polymorphize_stats {
+            merged_instances.lock_mut().entry(polymorphized).or_default().insert(instance);
+        }
+        *mono_item = MonoItem::Fn(polymorphized);
+    }
 }
 
 /// Creates a `MonoItem` for each method that is referenced by the vtable for
@@ -968,7 +1113,7 @@ fn create_mono_items_for_vtable_methods<'tcx>(
                     .unwrap()
                 })
                 .filter(|&instance| should_codegen_locally(tcx, &instance))
-                .map(|item| create_fn_mono_item(tcx, item, source));
+                .map(|item| create_fn_mono_item(item, source));
             output.extend(methods);
         }
 
@@ -1092,7 +1237,7 @@ impl RootCollector<'_, 'v> {
             debug!("RootCollector::push_if_root: found root def_id={:?}", def_id);
 
             let instance = Instance::mono(self.tcx, def_id.to_def_id());
-            self.output.push(create_fn_mono_item(self.tcx, instance, DUMMY_SP));
+            self.output.push(create_fn_mono_item(instance, DUMMY_SP));
         }
     }
 
@@ -1129,7 +1274,7 @@ impl RootCollector<'_, 'v> {
         .unwrap()
         .unwrap();
 
-        self.output.push(create_fn_mono_item(self.tcx, start_instance, DUMMY_SP));
+        self.output.push(create_fn_mono_item(start_instance, DUMMY_SP));
     }
 }
 
@@ -1178,7 +1323,8 @@ fn create_mono_items_for_default_impls<'tcx>(
                     let substs =
                         InternalSubsts::for_item(tcx, method.def_id, |param, _| match param.kind {
                             GenericParamDefKind::Lifetime => tcx.lifetimes.re_erased.into(),
-                            GenericParamDefKind::Type { .. } | GenericParamDefKind::Const => {
+                            GenericParamDefKind::Type { .. }
+                            | GenericParamDefKind::Const { .. } => {
                                 trait_ref.substs[param.index as usize]
                             }
                         });
@@ -1186,7 +1332,7 @@ fn create_mono_items_for_default_impls<'tcx>(
                         .unwrap()
                         .unwrap();
 
-                    let mono_item = create_fn_mono_item(tcx, instance, DUMMY_SP);
+                    let mono_item = create_fn_mono_item(instance, DUMMY_SP);
                     if mono_item.node.is_instantiable(tcx) && should_codegen_locally(tcx, &instance)
                     {
                         output.push(mono_item);
@@ -1224,7 +1370,7 @@ fn collect_miri<'tcx>(
         GlobalAlloc::Function(fn_instance) => {
             if should_codegen_locally(tcx, &fn_instance) {
                 trace!("collecting {:?} with {:#?}", alloc_id, fn_instance);
-                output.push(create_fn_mono_item(tcx, fn_instance, DUMMY_SP));
+                output.push(create_fn_mono_item(fn_instance, DUMMY_SP));
             }
         }
     }
@@ -210,6 +210,13 @@ pub fn partition<'tcx>(
 
     result.sort_by_cached_key(|cgu| cgu.name().as_str());
 
+    tcx.prof.with_profiler(|profiler| {
+        for cgu in &result {
+            let name = cgu.name().as_str();
+            profiler.record_cgu_size_for_summary(&name, cgu.items().len(), cgu.size_estimate());
+        }
+    });
+
     result
 }
 
@@ -2,8 +2,13 @@
 //! =========================
 //!
 //! This module implements an analysis of functions, methods and closures to determine which
-//! generic parameters are unused (and eventually, in what ways generic parameters are used - only
-//! for their size, offset of a field, etc.).
+//! generic parameters are unused, and which are only used for their layout - i.e. only passed to
+//! `size_of` and friends (and eventually, in what other ways generic parameters are used - offset
+//! of a field, etc.).
+//!
+//! Unused parameters are replaced by the identity parameter when polymorphizing an instance.
+//! Parameters only used for their layout are replaced by a canonical type with the same size and
+//! alignment, so that instantiations with types of the same layout share a single instance.
 
 use rustc_hir::{def::DefKind, def_id::DefId};
 use rustc_index::bit_set::FiniteBitSet;
@@ -15,15 +20,17 @@ use rustc_middle::ty::{
     self,
     fold::{TypeFoldable, TypeVisitor},
     query::Providers,
-    subst::SubstsRef,
+    subst::{GenericArgKind, SubstsRef},
     Const, Ty, TyCtxt,
 };
 use rustc_span::symbol::sym;
+use rustc_target::spec::abi::Abi;
 use std::convert::TryInto;
 
 /// Provide implementations of queries relating to polymorphization analysis.
 pub fn provide(providers: &mut Providers) {
     providers.unused_generic_params = unused_generic_params;
+    providers.layout_only_generic_params = layout_only_generic_params;
 }
 
 /// Determine which generic parameters are used by the function/method/closure represented by
@@ -31,7 +38,63 @@ pub fn provide(providers: &mut Providers) {
 /// indicates all parameters are used).
 fn unused_generic_params(tcx: TyCtxt<'_>, def_id: DefId) -> FiniteBitSet<u32> {
     debug!("unused_generic_params({:?})", def_id);
+    let unused_parameters = analyze_generic_params(tcx, def_id, false);
+
+    // Emit errors for debugging and testing if enabled.
+    if !unused_parameters.is_empty() {
+        emit_polymorphization_error(
+            tcx,
+            def_id,
+            &unused_parameters,
+            "item has unused generic parameters",
+            "is unused",
+        );
+    }
+
+    unused_parameters
+}
+
+/// Determine which generic parameters of the function/method/closure represented by `def_id`
+/// are used, but only for their size and alignment. Returns a bitset where bits representing
+/// those parameters are set, which never overlaps with the result of `unused_generic_params`.
+fn layout_only_generic_params(tcx: TyCtxt<'_>, def_id: DefId) -> FiniteBitSet<u32> {
+    debug!("layout_only_generic_params({:?})", def_id);
+    let unused = tcx.unused_generic_params(def_id);
+    let unused_or_layout_only = analyze_generic_params(tcx, def_id, true);
+
+    let mut layout_only_parameters = FiniteBitSet::new_empty();
+    for index in 0..tcx.generics_of(def_id).count().try_into().unwrap() {
+        if unused_or_layout_only.contains(index).unwrap_or(false)
+            && !unused.contains(index).unwrap_or(false)
+        {
+            layout_only_parameters.set(index);
+        }
+    }
+    debug!("layout_only_generic_params: layout_only_parameters={:?}", layout_only_parameters);
+
+    // Emit errors for debugging and testing if enabled.
+    if !layout_only_parameters.is_empty() {
+        emit_polymorphization_error(
+            tcx,
+            def_id,
+            &layout_only_parameters,
+            "item has generic parameters only used for their layout",
+            "is only used for its layout",
+        );
+    }
 
+    layout_only_parameters
+}
+
+/// Determine which generic parameters are not used by the function/method/closure represented by
+/// `def_id`. If `ignore_layout_uses` is set, uses which only depend on the size and alignment of
+/// a parameter are not counted. Returns a bitset where bits representing parameters that are not
+/// used are set.
+fn analyze_generic_params(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    ignore_layout_uses: bool,
+) -> FiniteBitSet<u32> {
     if !tcx.sess.opts.debugging_opts.polymorphize {
         // If polymorphization disabled, then all parameters are used.
         return FiniteBitSet::new_empty();
@@ -69,17 +132,20 @@ fn unused_generic_params(tcx: TyCtxt<'_>, def_id: DefId) -> FiniteBitSet<u32> {
 
     // Visit MIR and accumululate used generic parameters.
     let body = tcx.optimized_mir(def_id);
-    let mut vis = MarkUsedGenericParams { tcx, def_id, unused_parameters: &mut unused_parameters };
+    let mut vis = MarkUsedGenericParams {
+        tcx,
+        def_id,
+        unused_parameters: &mut unused_parameters,
+        ignore_layout_uses,
+    };
     vis.visit_body(body);
     debug!("unused_generic_params: (after visitor) unused_parameters={:?}", unused_parameters);
 
     mark_used_by_predicates(tcx, def_id, &mut unused_parameters);
-    debug!("unused_generic_params: (end) unused_parameters={:?}", unused_parameters);
-
-    // Emit errors for debugging and testing if enabled.
-    if !unused_parameters.is_empty() {
-        emit_unused_generic_params_error(tcx, def_id, generics, &unused_parameters);
+    if ignore_layout_uses {
+        mark_used_by_non_sized_predicates(tcx, def_id, &mut unused_parameters);
     }
+    debug!("unused_generic_params: (end) unused_parameters={:?}", unused_parameters);
 
     unused_parameters
 }
@@ -142,7 +208,12 @@ fn mark_used_by_predicates<'tcx>(
             };
 
             if any_param_used {
-                let mut vis = MarkUsedGenericParams { tcx, def_id, unused_parameters };
+                let mut vis = MarkUsedGenericParams {
+                    tcx,
+                    def_id,
+                    unused_parameters,
+                    ignore_layout_uses: false,
+                };
                 predicate.visit_with(&mut vis);
             }
         }
@@ -153,15 +224,50 @@ fn mark_used_by_predicates<'tcx>(
     }
 }
 
+/// Mark all generic parameters mentioned in predicates other than `Sized` bounds as used. A
+/// parameter that is only used for its layout is replaced by another type with the same layout,
+/// which wouldn't satisfy the predicates on the parameter.
+fn mark_used_by_non_sized_predicates<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    unused_parameters: &mut FiniteBitSet<u32>,
+) {
+    let def_id = tcx.closure_base_def_id(def_id);
+    let predicates = tcx.explicit_predicates_of(def_id);
+    let sized_trait = tcx.lang_items().sized_trait();
+    debug!("mark_used_by_non_sized_predicates: predicates_of={:?}", predicates);
+
+    for (predicate, _) in predicates.predicates {
+        if let ty::PredicateAtom::Trait(trait_predicate, _) = predicate.skip_binders() {
+            if Some(trait_predicate.def_id()) == sized_trait {
+                continue;
+            }
+        }
+
+        let mut vis = MarkUsedGenericParams {
+            tcx,
+            def_id,
+            unused_parameters,
+            ignore_layout_uses: false,
+        };
+        predicate.visit_with(&mut vis);
+    }
+
+    if let Some(parent) = predicates.parent {
+        mark_used_by_non_sized_predicates(tcx, parent, unused_parameters);
+    }
+}
+
 /// Emit errors for the function annotated by `#[rustc_polymorphize_error]`, labelling each generic
-/// parameter which was unused.
-fn emit_unused_generic_params_error<'tcx>(
+/// parameter in `parameters` (those which were unused, or only used for their layout).
+fn emit_polymorphization_error<'tcx>(
     tcx: TyCtxt<'tcx>,
     def_id: DefId,
-    generics: &'tcx ty::Generics,
-    unused_parameters: &FiniteBitSet<u32>,
+    parameters: &FiniteBitSet<u32>,
+    message: &str,
+    label: &str,
 ) {
-    debug!("emit_unused_generic_params_error: def_id={:?}", def_id);
+    debug!("emit_polymorphization_error: def_id={:?}", def_id);
     let base_def_id = tcx.closure_base_def_id(def_id);
     if !tcx
         .get_attrs(base_def_id)
@@ -171,21 +277,21 @@ fn emit_unused_generic_params_error<'tcx>(
         return;
     }
 
-    debug!("emit_unused_generic_params_error: unused_parameters={:?}", unused_parameters);
+    debug!("emit_polymorphization_error: parameters={:?}", parameters);
     let fn_span = match tcx.opt_item_name(def_id) {
         Some(ident) => ident.span,
         _ => tcx.def_span(def_id),
     };
 
-    let mut err = tcx.sess.struct_span_err(fn_span, "item has unused generic parameters");
+    let mut err = tcx.sess.struct_span_err(fn_span, message);
 
-    let mut next_generics = Some(generics);
+    let mut next_generics = Some(tcx.generics_of(def_id));
     while let Some(generics) = next_generics {
         for param in &generics.params {
-            if unused_parameters.contains(param.index).unwrap_or(false) {
-                debug!("emit_unused_generic_params_error: param={:?}", param);
+            if parameters.contains(param.index).unwrap_or(false) {
+                debug!("emit_polymorphization_error: param={:?}", param);
                 let def_span = tcx.def_span(param.def_id);
-                err.span_label(def_span, &format!("generic parameter `{}` is unused", param.name));
+                err.span_label(def_span, &format!("generic parameter `{}` {}", param.name, label));
             }
         }
 
@@ -200,6 +306,9 @@ struct MarkUsedGenericParams<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     def_id: DefId,
     unused_parameters: &'a mut FiniteBitSet<u32>,
+    /// Whether to skip uses of parameters which only depend on their layout, see
+    /// `layout_only_callee_params`.
+    ignore_layout_uses: bool,
 }
 
 impl<'a, 'tcx> MarkUsedGenericParams<'a, 'tcx> {
@@ -219,6 +328,34 @@ impl<'a, 'tcx> MarkUsedGenericParams<'a, 'tcx> {
         }
         debug!("visit_child_body: unused_parameters={:?}", self.unused_parameters);
     }
+
+    /// Returns the generic parameters of the callee `def_id` which don't depend on anything but
+    /// the size and ABI alignment of their argument: all parameters of the `size_of` and
+    /// `min_align_of` intrinsics, and the unused and layout-only parameters of upstream functions.
+    /// `pref_align_of` isn't one of them, as the canonical type of `layout_equivalent_ty` doesn't
+    /// preserve the preferred alignment. Local functions are not
+    /// considered, since they could be recursive and result in a query cycle, and neither are
+    /// trait methods, which could resolve to an impl that uses its parameters differently.
+    fn layout_only_callee_params(&self, def_id: DefId) -> FiniteBitSet<u32> {
+        let mut params = FiniteBitSet::new_empty();
+        if self.tcx.fn_sig(def_id).abi() == Abi::RustIntrinsic {
+            let name = self.tcx.item_name(def_id);
+            if matches!(name, sym::size_of | sym::min_align_of) {
+                params.set_range(0..self.tcx.generics_of(def_id).count().try_into().unwrap());
+            }
+        } else if !def_id.is_local() && self.tcx.trait_of_item(def_id).is_none() {
+            let unused = self.tcx.unused_generic_params(def_id);
+            let layout_only = self.tcx.layout_only_generic_params(def_id);
+            for index in 0..self.tcx.generics_of(def_id).count().try_into().unwrap() {
+                if unused.contains(index).unwrap_or(false)
+                    || layout_only.contains(index).unwrap_or(false)
+                {
+                    params.set(index);
+                }
+            }
+        }
+        params
+    }
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for MarkUsedGenericParams<'a, 'tcx> {
@@ -301,6 +438,23 @@ impl<'a, 'tcx> TypeVisitor<'tcx> for MarkUsedGenericParams<'a, 'tcx> {
                 self.visit_child_body(def_id, substs);
                 false
             }
+            ty::FnDef(def_id, substs) if self.ignore_layout_uses => {
+                debug!("visit_ty: def_id={:?}", def_id);
+                let layout_only = self.layout_only_callee_params(def_id);
+                for (i, arg) in substs.iter().enumerate() {
+                    // Only a parameter passed as is keeps its layout when it's replaced by the
+                    // canonical type: the layout of e.g. `Option<T>` also depends on the niches
+                    // of `T`, which the canonical type doesn't have.
+                    let is_param = match arg.unpack() {
+                        GenericArgKind::Type(ty) => matches!(ty.kind(), ty::Param(_)),
+                        _ => false,
+                    };
+                    if !is_param || !layout_only.contains(i.try_into().unwrap()).unwrap_or(false) {
+                        arg.visit_with(self);
+                    }
+                }
+                false
+            }
             ty::Param(param) => {
                 debug!("visit_ty: param={:?}", param);
                 self.unused_parameters.clear(param.index);
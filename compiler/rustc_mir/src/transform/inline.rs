@@ -1,7 +1,7 @@
 //! Inlining pass for MIR functions
 
 use rustc_attr as attr;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_index::bit_set::BitSet;
 use rustc_index::vec::{Idx, IndexVec};
 use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
@@ -16,13 +16,21 @@ use crate::transform::MirPass;
 use std::collections::VecDeque;
 use std::iter;
 
-const DEFAULT_THRESHOLD: usize = 50;
-const HINT_THRESHOLD: usize = 100;
+crate use cycle::mir_callgraph_reachable;
+crate use cycle::mir_inliner_callees;
+
+mod cycle;
 
 const INSTR_COST: usize = 5;
 const CALL_PENALTY: usize = 25;
+// Calls through function pointers can't be inlined later on, and stop LLVM from
+// optimizing across them.
+const INDIRECT_CALL_PENALTY: usize = 35;
 const LANDINGPAD_PENALTY: usize = 50;
 const RESUME_PENALTY: usize = 45;
+// Uses of a parameter which is a constant at the call site are usually folded away once
+// the callee is inlined, so they're credited back to the cost of the callee.
+const CONST_ARG_USE_BONUS: usize = INSTR_COST;
 
 const UNKNOWN_SIZE_COST: usize = 10;
 
@@ -36,23 +44,36 @@ struct CallSite<'tcx> {
     location: SourceInfo,
 }
 
+/// Returns `true` if MIR inlining should run for this session. Inlining is only on by default
+/// with `-Zmir-opt-level=2` or higher, and can be forced either way with `-Zinline-mir`.
+///
+/// The optimized MIR of `#[inline]` and generic functions is encoded in the crate metadata
+/// after inlining, so downstream crates get the inlined bodies without redoing the work.
+crate fn is_enabled(tcx: TyCtxt<'_>) -> bool {
+    let opts = &tcx.sess.opts.debugging_opts;
+    opts.inline_mir.unwrap_or(opts.mir_opt_level >= 2)
+}
+
 impl<'tcx> MirPass<'tcx> for Inline {
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
-        if tcx.sess.opts.debugging_opts.mir_opt_level >= 2 {
-            if tcx.sess.opts.debugging_opts.instrument_coverage {
-                // The current implementation of source code coverage injects code region counters
-                // into the MIR, and assumes a 1-to-1 correspondence between MIR and source-code-
-                // based function.
-                debug!("function inlining is disabled when compiling with `instrument_coverage`");
-            } else {
-                Inliner {
-                    tcx,
-                    param_env: tcx.param_env_reveal_all_normalized(body.source.def_id()),
-                    codegen_fn_attrs: tcx.codegen_fn_attrs(body.source.def_id()),
-                }
-                .run_pass(body);
-            }
+        if !is_enabled(tcx) {
+            return;
         }
+
+        if tcx.sess.opts.debugging_opts.instrument_coverage {
+            // The current implementation of source code coverage injects code region counters
+            // into the MIR, and assumes a 1-to-1 correspondence between MIR and source-code-
+            // based function.
+            debug!("function inlining is disabled when compiling with `instrument_coverage`");
+            return;
+        }
+
+        Inliner {
+            tcx,
+            param_env: tcx.param_env_reveal_all_normalized(body.source.def_id()),
+            codegen_fn_attrs: tcx.codegen_fn_attrs(body.source.def_id()),
+        }
+        .run_pass(body);
     }
 }
 
@@ -64,12 +85,11 @@ struct Inliner<'tcx> {
 
 impl Inliner<'tcx> {
     fn run_pass(&self, caller_body: &mut Body<'tcx>) {
-        // Keep a queue of callsites to try inlining on. We take
-        // advantage of the fact that queries detect cycles here to
-        // allow us to try and fetch the fully optimized MIR of a
-        // call; if it succeeds, we can inline it and we know that
-        // they do not call us.  Otherwise, we just don't try to
-        // inline.
+        // Keep a queue of callsites to try inlining on. Before fetching
+        // the fully optimized MIR of a local callee we ask the
+        // `mir_callgraph_reachable` query whether the callee can
+        // (transitively) call us; if it can, fetching its optimized MIR
+        // could end up in a query cycle, so we don't try to inline it.
         //
         // We use a queue so that we inline "broadly" before we inline
         // in depth. It is unclear if this is the best heuristic,
@@ -107,29 +127,31 @@ impl Inliner<'tcx> {
                 }
 
                 let callee_body = if let Some(callee_def_id) = callsite.callee.as_local() {
-                    let callee_hir_id = self.tcx.hir().local_def_id_to_hir_id(callee_def_id);
-                    // Avoid a cycle here by only using `optimized_mir` only if we have
-                    // a lower `HirId` than the callee. This ensures that the callee will
-                    // not inline us. This trick only works without incremental compilation.
-                    // So don't do it if that is enabled. Also avoid inlining into generators,
-                    // since their `optimized_mir` is used for layout computation, which can
-                    // create a cycle, even when no attempt is made to inline the function
-                    // in the other direction.
-                    if !self.tcx.dep_graph.is_fully_enabled()
-                        && self_hir_id < callee_hir_id
-                        && caller_body.generator_kind.is_none()
-                    {
-                        self.tcx.optimized_mir(callsite.callee)
-                    } else {
+                    // Avoid inlining into generators, since their `optimized_mir` is used for
+                    // layout computation, which can create a cycle, even when no attempt is
+                    // made to inline the function in the other direction.
+                    if caller_body.generator_kind.is_some() {
+                        continue;
+                    }
+
+                    // Never inline a function into itself.
+                    if callee_def_id == def_id.expect_local() {
+                        continue;
+                    }
+
+                    if !self.check_callee_cycle(callsite, callee_def_id, def_id.expect_local()) {
+                        debug!("checking whether to inline callsite {:?} - cycle", callsite);
                         continue;
                     }
+
+                    self.tcx.optimized_mir(callsite.callee)
                 } else {
                     // This cannot result in a cycle since the callee MIR is from another crate
                     // and is already optimized.
                     self.tcx.optimized_mir(callsite.callee)
                 };
 
-                let callee_body = if self.consider_optimizing(callsite, callee_body) {
+                let callee_body = if self.consider_optimizing(caller_body, callsite, callee_body) {
                     self.tcx.subst_and_normalize_erasing_regions(
                         &callsite.substs,
                         self.param_env,
@@ -209,7 +231,9 @@ impl Inliner<'tcx> {
                         .ok()
                         .flatten()?;
 
-                if let InstanceDef::Virtual(..) = instance.def {
+                // Only items have MIR we can inline; shims are built on demand
+                // and virtual calls cannot be resolved statically.
+                if !matches!(instance.def, InstanceDef::Item(_)) {
                     return None;
                 }
 
@@ -225,15 +249,53 @@ impl Inliner<'tcx> {
         None
     }
 
-    fn consider_optimizing(&self, callsite: CallSite<'tcx>, callee_body: &Body<'tcx>) -> bool {
+    /// Returns `false` if inlining the local `callee_def_id` into `caller_def_id` could create a
+    /// query cycle, i.e. if the callee can (transitively) reach the caller.
+    fn check_callee_cycle(
+        &self,
+        callsite: CallSite<'tcx>,
+        callee_def_id: LocalDefId,
+        caller_def_id: LocalDefId,
+    ) -> bool {
+        // Constructors never call anything, so they cannot be part of a cycle.
+        if self.tcx.is_constructor(callsite.callee) {
+            return true;
+        }
+
+        // Avoid the query if we can: a callee with a higher `HirId` than us will never
+        // inline us, as it will see a lower `HirId` when checking. This trick only works
+        // without incremental compilation, where `HirId`s are not stable across sessions.
+        if !self.tcx.dep_graph.is_fully_enabled() {
+            let hir = self.tcx.hir();
+            if hir.local_def_id_to_hir_id(caller_def_id) < hir.local_def_id_to_hir_id(callee_def_id)
+            {
+                return true;
+            }
+        }
+
+        let callee = Instance::new(callsite.callee, callsite.substs);
+        !self.tcx.mir_callgraph_reachable((callee, caller_def_id))
+    }
+
+    fn consider_optimizing(
+        &self,
+        caller_body: &Body<'tcx>,
+        callsite: CallSite<'tcx>,
+        callee_body: &Body<'tcx>,
+    ) -> bool {
         debug!("consider_optimizing({:?})", callsite);
-        self.should_inline(callsite, callee_body)
+        self.should_inline(caller_body, callsite, callee_body)
             && self.tcx.consider_optimizing(|| {
                 format!("Inline {:?} into {:?}", callee_body.span, callsite)
             })
     }
 
-    fn should_inline(&self, callsite: CallSite<'tcx>, callee_body: &Body<'tcx>) -> bool {
+    fn should_inline(
+        &self,
+        caller_body: &Body<'tcx>,
+        callsite: CallSite<'tcx>,
+        callee_body: &Body<'tcx>,
+    ) -> bool {
         debug!("should_inline({:?})", callsite);
         let tcx = self.tcx;
 
@@ -289,7 +351,11 @@ impl Inliner<'tcx> {
             }
         }
 
-        let mut threshold = if hinted { HINT_THRESHOLD } else { DEFAULT_THRESHOLD };
+        let mut threshold = if hinted {
+            self.tcx.sess.opts.debugging_opts.inline_mir_hint_threshold
+        } else {
+            self.tcx.sess.opts.debugging_opts.inline_mir_threshold
+        };
 
         // Significantly lower the threshold for inlining cold functions
         if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::COLD) {
@@ -372,6 +438,13 @@ impl Inliner<'tcx> {
                         cost += LANDINGPAD_PENALTY;
                     }
                 }
+                TerminatorKind::Call { cleanup, .. } => {
+                    cost += INDIRECT_CALL_PENALTY;
+                    if cleanup.is_some() {
+                        cost += LANDINGPAD_PENALTY;
+                    }
+                }
+                TerminatorKind::InlineAsm { .. } => cost += CALL_PENALTY,
                 TerminatorKind::Assert { cleanup, .. } => {
                     cost += CALL_PENALTY;
 
@@ -409,6 +482,10 @@ impl Inliner<'tcx> {
             }
         }
 
+        let bonus = const_arg_bonus(caller_body, callsite, callee_body);
+        debug!("    constant arguments bonus = {}", bonus);
+        let cost = cost.saturating_sub(bonus);
+
         if let attr::InlineAttr::Always = codegen_fn_attrs.inline {
             debug!("INLINING {:?} because inline(always) [cost={}]", callsite, cost);
             true
@@ -674,6 +751,46 @@ impl Inliner<'tcx> {
     }
 }
 
+/// Returns how much of the cost of `callee_body` is expected to be folded away after inlining,
+/// because the corresponding arguments are constants at the call site.
+fn const_arg_bonus<'tcx>(
+    caller_body: &Body<'tcx>,
+    callsite: CallSite<'tcx>,
+    callee_body: &Body<'tcx>,
+) -> usize {
+    let args = match caller_body[callsite.bb].terminator().kind {
+        TerminatorKind::Call { ref args, .. } => args,
+        _ => return 0,
+    };
+    // The arguments of "rust-call" functions are untupled when inlining, so they don't map
+    // directly to the parameters of the callee.
+    if args.len() != callee_body.arg_count {
+        return 0;
+    }
+
+    let mut counter = LocalUseCounter { uses: IndexVec::from_elem(0, &callee_body.local_decls) };
+    counter.visit_body(callee_body);
+
+    args.iter()
+        .zip(callee_body.args_iter())
+        .filter(|(arg, _)| matches!(arg, Operand::Constant(_)))
+        .map(|(_, local)| counter.uses[local] * CONST_ARG_USE_BONUS)
+        .sum()
+}
+
+/// Counts the uses of each local of a body.
+struct LocalUseCounter {
+    uses: IndexVec<Local, usize>,
+}
+
+impl<'tcx> Visitor<'tcx> for LocalUseCounter {
+    fn visit_local(&mut self, &local: &Local, context: PlaceContext, _location: Location) {
+        if context.is_use() {
+            self.uses[local] += 1;
+        }
+    }
+}
+
 fn type_size_of<'tcx>(
     tcx: TyCtxt<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
@@ -82,6 +82,8 @@ pub(crate) fn provide(providers: &mut Providers) {
         promoted_mir_of_const_arg: |tcx, (did, param_did)| {
             promoted_mir(tcx, ty::WithOptConstParam { did, const_param_did: Some(param_did) })
         },
+        mir_callgraph_reachable: inline::mir_callgraph_reachable,
+        mir_inliner_callees: inline::mir_inliner_callees,
         ..*providers
     };
     instrument_coverage::provide(providers);
@@ -331,6 +333,16 @@ fn mir_drops_elaborated_and_const_checked<'tcx>(
         tcx.ensure().mir_borrowck(def.did);
     }
 
+    // The MIR inliner computes the call graph from `mir_promoted`, so make sure the
+    // callees have been collected before we steal it.
+    if def.const_param_did.is_none() && inline::is_enabled(tcx) {
+        let hir_id = tcx.hir().local_def_id_to_hir_id(def.did);
+        if tcx.hir().body_owner_kind(hir_id).is_fn_or_closure() {
+            let def = ty::WithOptConstParam::unknown(def.did.to_def_id());
+            tcx.ensure().mir_inliner_callees(ty::InstanceDef::Item(def));
+        }
+    }
+
     let (body, _) = tcx.mir_promoted(def);
     let mut body = body.steal();
 
@@ -56,6 +56,24 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             ExprKind::Match { scrutinee, arms } => {
                 this.match_expr(destination, expr_span, block, scrutinee, arms)
             }
+            ExprKind::If { cond, then, otherwise } => {
+                // [block] -/cond/-> [then_block] -/then/-> [then_end] -----+
+                //            | (no match)                                 |
+                //       [else_block] -/otherwise/-> [else_end] --> [join_block]
+                let outer_source_scope = this.source_scope;
+                let (then_block, else_block) = this.lower_let_chain(block, cond, true);
+                let then_end = unpack!(this.into(destination, then_block, then));
+                this.source_scope = outer_source_scope;
+                let else_end = unpack!(this.into(destination, else_block, otherwise));
+
+                let join_block = this.cfg.start_new_block();
+                this.cfg.goto(then_end, source_info, join_block);
+                this.cfg.goto(else_end, source_info, join_block);
+                join_block.unit()
+            }
+            ExprKind::Let { .. } => {
+                span_bug!(expr_span, "`let` expression outside of a `let` chain: {:?}", expr)
+            }
             ExprKind::NeverToAny { source } => {
                 let source = this.hir.mirror(source);
                 let is_call = match source.kind {
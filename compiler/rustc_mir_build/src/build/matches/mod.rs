@@ -244,6 +244,10 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                         ArmHasGuard(arm.guard.is_some()),
                         Some((Some(&scrutinee_place), scrutinee_span)),
                     );
+                    let scope = match arm.guard {
+                        Some(Guard::IfLet(ref e)) => this.declare_guard_bindings(e.clone(), scope),
+                        _ => scope,
+                    };
 
                     let arm_block = this.bind_pattern(
                         outer_source_info,
@@ -275,6 +279,142 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         end_block.unit()
     }
 
+    /// Declares the bindings of the `let`s in an `if let` guard, which are
+    /// visible in the arm body. The guard is lowered once for each candidate of
+    /// an or-pattern, so its bindings are declared here once for the whole arm
+    /// rather than by `lower_let_chain`.
+    ///
+    /// Their drops are scheduled in the arm scope when the guard is lowered, so
+    /// that they are dropped on the guard-failure edge, which leaves the arm scope
+    /// (see `bind_and_guard_matched_candidate`), as well as at the end of the arm.
+    fn declare_guard_bindings(
+        &mut self,
+        guard: ExprRef<'tcx>,
+        visibility_scope: Option<SourceScope>,
+    ) -> Option<SourceScope> {
+        let guard = self.hir.mirror(guard);
+        match guard.kind {
+            ExprKind::Scope { value, .. } => self.declare_guard_bindings(value, visibility_scope),
+            ExprKind::LogicalOp { op: LogicalOp::And, lhs, rhs } => {
+                let visibility_scope = self.declare_guard_bindings(lhs, visibility_scope);
+                self.declare_guard_bindings(rhs, visibility_scope)
+            }
+            ExprKind::Let { expr, pat } => self.declare_bindings(
+                visibility_scope,
+                pat.span,
+                &pat,
+                ArmHasGuard(false),
+                Some((None, expr.span())),
+            ),
+            _ => visibility_scope,
+        }
+    }
+
+    /// Lowers the condition of an `if` or `while` whose condition is a `let`
+    /// chain (see `ExprKind::If`), or an `if let` guard. Returns the block to
+    /// continue in when every clause of the chain holds, and the block to
+    /// continue in otherwise.
+    ///
+    /// Each clause is lowered in turn, branching to the `else` block as soon as
+    /// one fails, rather than nesting a `match` per `let`. Unless
+    /// `declare_bindings` is false, as for guards (see `declare_guard_bindings`),
+    /// the bindings of the chain are declared in `self.source_scope`, which is
+    /// left pointing at the scope of the last of them: callers must reset it
+    /// before lowering the `else` branch.
+    crate fn lower_let_chain(
+        &mut self,
+        mut block: BasicBlock,
+        cond: ExprRef<'tcx>,
+        declare_bindings: bool,
+    ) -> (BasicBlock, BasicBlock) {
+        let cond = self.hir.mirror(cond);
+        let source_info = self.source_info(cond.span);
+        match cond.kind {
+            ExprKind::Scope { region_scope, lint_level: _, value } => {
+                // FIXME: unlike `in_scope`, we don't create source scopes for the
+                // lint levels of the parts of the chain.
+                let region_scope = (region_scope, source_info);
+                self.push_scope(region_scope);
+                let (then_block, else_block) = ensure_sufficient_stack(|| {
+                    self.lower_let_chain(block, value, declare_bindings)
+                });
+                let else_exit = self.cfg.start_new_block();
+                self.exit_top_scope(else_block, else_exit, source_info);
+                let then_exit = unpack!(self.pop_scope(region_scope, then_block));
+                (then_exit, else_exit)
+            }
+            ExprKind::LogicalOp { op: LogicalOp::And, lhs, rhs } => {
+                let (lhs_then, lhs_else) = self.lower_let_chain(block, lhs, declare_bindings);
+                let (rhs_then, rhs_else) = self.lower_let_chain(lhs_then, rhs, declare_bindings);
+                let else_block = self.cfg.start_new_block();
+                self.cfg.goto(lhs_else, source_info, else_block);
+                self.cfg.goto(rhs_else, source_info, else_block);
+                (rhs_then, else_block)
+            }
+            ExprKind::Let { expr, pat } => {
+                self.lower_let_expr(block, expr, &pat, declare_bindings)
+            }
+            _ => {
+                // A boolean operand, lowered within the scope of its `DropTemps`:
+                // leaving that scope on either branch drops its temporaries.
+                let cond = unpack!(block = self.as_local_operand(block, cond));
+                let then_block = self.cfg.start_new_block();
+                let else_block = self.cfg.start_new_block();
+                let term = TerminatorKind::if_(self.hir.tcx(), cond, then_block, else_block);
+                self.cfg.terminate(block, source_info, term);
+                (then_block, else_block)
+            }
+        }
+    }
+
+    /// Lowers a single `let` clause of a `let` chain like a `match` with the
+    /// arms `pat => then_block` and `_ => else_block`.
+    fn lower_let_expr(
+        &mut self,
+        mut block: BasicBlock,
+        expr: ExprRef<'tcx>,
+        pat: &Pat<'tcx>,
+        declare_bindings: bool,
+    ) -> (BasicBlock, BasicBlock) {
+        let expr_span = expr.span();
+        let scrutinee_place = unpack!(block = self.lower_scrutinee(block, expr, expr_span));
+        let wildcard = Pat::wildcard_from_ty(pat.ty);
+        let mut pat_candidate = Candidate::new(scrutinee_place, pat, false);
+        let mut otherwise_candidate = Candidate::new(scrutinee_place, &wildcard, false);
+        let fake_borrow_temps = self.lower_match_tree(
+            block,
+            expr_span,
+            false,
+            &mut [&mut pat_candidate, &mut otherwise_candidate],
+        );
+        let else_block = otherwise_candidate.pre_binding_block.unwrap();
+
+        let scope = if declare_bindings {
+            self.declare_bindings(
+                None,
+                pat.span,
+                pat,
+                ArmHasGuard(false),
+                Some((Some(&scrutinee_place), expr_span)),
+            )
+        } else {
+            None
+        };
+        let then_block = self.bind_pattern(
+            self.source_info(pat.span),
+            pat_candidate,
+            None,
+            &fake_borrow_temps,
+            expr_span,
+            None,
+        );
+        if let Some(source_scope) = scope {
+            self.source_scope = source_scope;
+        }
+
+        (then_block, else_block)
+    }
+
     /// Binds the variables and ascribes types for a given `match` arm or
     /// `let` binding.
     ///
@@ -466,26 +606,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         // let PATTERN = ... might not even exist until we do the assignment.
         // so we set it here instead.
         if set_match_place {
-            let mut candidate_ref = &candidate;
-            while let Some(next) = {
-                for binding in &candidate_ref.bindings {
-                    let local = self.var_local_id(binding.var_id, OutsideGuard);
-
-                    if let Some(box LocalInfo::User(ClearCrossCrate::Set(BindingForm::Var(
-                        VarBindingForm { opt_match_place: Some((ref mut match_place, _)), .. },
-                    )))) = self.local_decls[local].local_info
-                    {
-                        *match_place = Some(initializer);
-                    } else {
-                        bug!("Let binding to non-user variable.")
-                    }
-                }
-                // All of the subcandidates should bind the same locals, so we
-                // only visit the first one.
-                candidate_ref.subcandidates.get(0)
-            } {
-                candidate_ref = next;
-            }
+            self.set_match_place(&candidate, initializer);
         }
 
         self.bind_pattern(
@@ -499,6 +620,71 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         .unit()
     }
 
+    /// Lowers the initializer and pattern of a `let PATTERN = INIT else { ELSE };` statement,
+    /// whose bindings have already been declared, like a `match` with the arms `PATTERN => {}`
+    /// and `_ => ELSE`. The `else` block diverges, which type checking ensures.
+    pub(super) fn ast_let_else(
+        &mut self,
+        mut block: BasicBlock,
+        pattern: Pat<'tcx>,
+        initializer: ExprRef<'tcx>,
+        else_block: ExprRef<'tcx>,
+    ) -> BlockAnd<()> {
+        let initializer_span = initializer.span();
+        let place = unpack!(block = self.lower_scrutinee(block, initializer, initializer_span));
+        let wildcard = Pat::wildcard_from_ty(pattern.ty);
+        let mut candidate = Candidate::new(place, &pattern, false);
+        let mut otherwise_candidate = Candidate::new(place, &wildcard, false);
+        let fake_borrow_temps = self.lower_match_tree(
+            block,
+            initializer_span,
+            false,
+            &mut [&mut candidate, &mut otherwise_candidate],
+        );
+        self.set_match_place(&candidate, place);
+
+        let mut else_entry = otherwise_candidate.pre_binding_block.unwrap();
+        let else_block = self.hir.mirror(else_block);
+        let else_source_info = self.source_info(else_block.span);
+        let else_temp = self.temp(else_block.ty, else_block.span);
+        unpack!(else_entry = self.into(else_temp, else_entry, else_block));
+        self.cfg.terminate(else_entry, else_source_info, TerminatorKind::Unreachable);
+
+        self.bind_pattern(
+            self.source_info(pattern.span),
+            candidate,
+            None,
+            &fake_borrow_temps,
+            initializer_span,
+            None,
+        )
+        .unit()
+    }
+
+    /// Records `match_place` as the place matched by the `let` bindings of `candidate`.
+    fn set_match_place(&mut self, candidate: &Candidate<'_, 'tcx>, match_place: Place<'tcx>) {
+        let mut candidate_ref = candidate;
+        while let Some(next) = {
+            for binding in &candidate_ref.bindings {
+                let local = self.var_local_id(binding.var_id, OutsideGuard);
+
+                if let Some(box LocalInfo::User(ClearCrossCrate::Set(BindingForm::Var(
+                    VarBindingForm { opt_match_place: Some((ref mut opt_place, _)), .. },
+                )))) = self.local_decls[local].local_info
+                {
+                    *opt_place = Some(match_place);
+                } else {
+                    bug!("Let binding to non-user variable.")
+                }
+            }
+            // All of the subcandidates should bind the same locals, so we
+            // only visit the first one.
+            candidate_ref.subcandidates.get(0)
+        } {
+            candidate_ref = next;
+        }
+    }
+
     /// Declares the bindings of the given patterns and returns the visibility
     /// scope for the bindings in these patterns, if such a scope had to be
     /// created. NOTE: Declaring the bindings should always be done in their
@@ -1725,13 +1911,16 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
 
             // the block to branch to if the guard fails; if there is no
             // guard, this block is simply unreachable
-            let guard = match guard {
-                Guard::If(e) => self.hir.mirror(e.clone()),
+            let (guard_span, (post_guard_block, otherwise_post_guard_block)) = match guard {
+                Guard::If(e) => {
+                    let e = self.hir.mirror(e.clone());
+                    let source_info = self.source_info(e.span);
+                    (e.span, self.test_bool(block, e, source_info))
+                }
+                Guard::IfLet(e) => (e.span(), self.lower_let_chain(block, e.clone(), false)),
             };
-            let source_info = self.source_info(guard.span);
-            let guard_end = self.source_info(tcx.sess.source_map().end_point(guard.span));
-            let (post_guard_block, otherwise_post_guard_block) =
-                self.test_bool(block, guard, source_info);
+            let source_info = self.source_info(guard_span);
+            let guard_end = self.source_info(tcx.sess.source_map().end_point(guard_span));
             let guard_frame = self.guard_context.pop().unwrap();
             debug!("Exiting guard building context with locals: {:?}", guard_frame);
 
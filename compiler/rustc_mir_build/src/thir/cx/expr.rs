@@ -538,6 +538,18 @@ fn make_mirror_unadjusted<'a, 'tcx>(
             },
             Err(err) => bug!("invalid loop id for continue: {}", err),
         },
+        hir::ExprKind::Match(
+            ref cond,
+            [then_arm, else_arm],
+            hir::MatchSource::IfDesugar { .. } | hir::MatchSource::WhileDesugar,
+        ) if cond.is_let_chain() => ExprKind::If {
+            cond: cond.to_ref(),
+            then: then_arm.body.to_ref(),
+            otherwise: else_arm.body.to_ref(),
+        },
+        hir::ExprKind::Let(ref pat, ref expr) => {
+            ExprKind::Let { expr: expr.to_ref(), pat: cx.pattern_from_hir(pat) }
+        }
         hir::ExprKind::Match(ref discr, ref arms, _) => ExprKind::Match {
             scrutinee: discr.to_ref(),
             arms: arms.iter().map(|a| convert_arm(cx, a)).collect(),
@@ -778,6 +790,7 @@ fn convert_arm<'tcx>(cx: &mut Cx<'_, 'tcx>, arm: &'tcx hir::Arm<'tcx>) -> Arm<'t
     Arm {
         pattern: cx.pattern_from_hir(&arm.pat),
         guard: match arm.guard {
+            Some(hir::Guard::If(ref e)) if e.is_let_chain() => Some(Guard::IfLet(e.to_ref())),
             Some(hir::Guard::If(ref e)) => Some(Guard::If(e.to_ref())),
             _ => None,
         },
@@ -90,6 +90,9 @@ crate enum StmtKind<'tcx> {
         /// let pat: ty = <INIT> ...
         initializer: Option<ExprRef<'tcx>>,
 
+        /// let pat: ty = init else { <ELSE> };
+        else_block: Option<ExprRef<'tcx>>,
+
         /// the lint level for this let-statement
         lint_level: LintLevel,
     },
@@ -188,6 +191,18 @@ crate enum ExprKind<'tcx> {
         scrutinee: ExprRef<'tcx>,
         arms: Vec<Arm<'tcx>>,
     },
+    /// An `if` or `while` condition whose condition is a `let` chain. `cond` is
+    /// built from `Let`s, `LogicalOp::And`s and boolean operands.
+    If {
+        cond: ExprRef<'tcx>,
+        then: ExprRef<'tcx>,
+        otherwise: ExprRef<'tcx>,
+    },
+    /// A `let` clause in a `let` chain, only valid within the `cond` of an `If`.
+    Let {
+        expr: ExprRef<'tcx>,
+        pat: Pat<'tcx>,
+    },
     Block {
         body: &'tcx hir::Block<'tcx>,
     },
@@ -338,6 +353,8 @@ crate struct Arm<'tcx> {
 #[derive(Clone, Debug)]
 crate enum Guard<'tcx> {
     If(ExprRef<'tcx>),
+    /// An `if let` guard, whose condition is a `let` chain.
+    IfLet(ExprRef<'tcx>),
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -54,6 +54,28 @@ impl<'tcx> Visitor<'tcx> for MatchVisitor<'_, 'tcx> {
     }
 
     fn visit_expr(&mut self, ex: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Match(ref scrut, ref arms, source) = ex.kind {
+            // The condition of an `if` or `while` is only lowered without `DropTemps`
+            // when it is a `let` chain.
+            let is_let_chain = matches!(
+                source,
+                hir::MatchSource::IfDesugar { .. } | hir::MatchSource::WhileDesugar
+            ) && matches!(scrut.kind, hir::ExprKind::Binary(..));
+            if is_let_chain {
+                let mut chain = vec![];
+                collect_let_chain(scrut, &mut chain);
+                for operand in &chain {
+                    intravisit::walk_expr(self, operand);
+                }
+                self.check_let_chain(&chain);
+                for arm in arms.iter() {
+                    self.visit_arm(arm);
+                }
+                self.check_match(scrut, arms, source);
+                return;
+            }
+        }
+
         intravisit::walk_expr(self, ex);
 
         if let hir::ExprKind::Match(ref scrut, ref arms, source) = ex.kind {
@@ -64,6 +86,17 @@ impl<'tcx> Visitor<'tcx> for MatchVisitor<'_, 'tcx> {
     fn visit_local(&mut self, loc: &'tcx hir::Local<'tcx>) {
         intravisit::walk_local(self, loc);
 
+        if let (Some(init), Some(_)) = (loc.init, loc.els) {
+            // The `else` block handles the values the pattern doesn't match.
+            if self.check_let(&loc.pat, init) {
+                let lint = IRREFUTABLE_LET_PATTERNS;
+                self.tcx.struct_span_lint_hir(lint, loc.hir_id, loc.span, |lint| {
+                    lint.build("irrefutable let-else pattern").emit()
+                });
+            }
+            return;
+        }
+
         let (msg, sp) = match loc.source {
             hir::LocalSource::Normal => ("local binding", Some(loc.span)),
             hir::LocalSource::ForLoopDesugar => ("`for` loop binding", None),
@@ -192,6 +225,57 @@ impl<'tcx> MatchVisitor<'_, 'tcx> {
         check_exhaustive(&mut cx, scrut_ty, scrut.span, &matrix, scrut.hir_id, is_empty_match);
     }
 
+    /// Checks the `let` clauses of a `let` chain. Only a run of irrefutable `let`s
+    /// at the start or at the end of the chain is linted: those could be moved
+    /// before the chain or into the body. An irrefutable `let` between refutable
+    /// clauses binds something the rest of the chain needs, so it is left alone.
+    fn check_let_chain(&mut self, chain: &[&'tcx hir::Expr<'tcx>]) {
+        let irrefutable: Vec<bool> = chain
+            .iter()
+            .map(|operand| match operand.kind {
+                hir::ExprKind::Let(ref pat, ref scrut) => self.check_let(pat, scrut),
+                _ => false,
+            })
+            .collect();
+
+        let leading = irrefutable.iter().take_while(|&&irrefutable| irrefutable).count();
+        let trailing = if leading == chain.len() {
+            0
+        } else {
+            irrefutable.iter().rev().take_while(|&&irrefutable| irrefutable).count()
+        };
+
+        let leading_lets = chain[..leading].iter().map(|e| (e, "before the chain"));
+        let trailing_lets = chain[chain.len() - trailing..].iter().map(|e| (e, "into the body"));
+        for (operand, place) in leading_lets.chain(trailing_lets) {
+            if let hir::ExprKind::Let(ref pat, _) = operand.kind {
+                let lint = IRREFUTABLE_LET_PATTERNS;
+                self.tcx.struct_span_lint_hir(lint, pat.hir_id, pat.span, |lint| {
+                    lint.build("irrefutable `let` pattern in a `let` chain")
+                        .note(&format!("this `let` always matches, so it could be moved {}", place))
+                        .emit()
+                });
+            }
+        }
+    }
+
+    /// Checks a single `let` clause of a `let` chain, returning whether its pattern
+    /// is irrefutable.
+    fn check_let(&mut self, pat: &'tcx Pat<'tcx>, scrut: &hir::Expr<'_>) -> bool {
+        self.check_patterns(pat);
+
+        let mut cx = self.new_cx(scrut.hir_id);
+
+        let mut have_errors = false;
+        let (pattern, pattern_ty) = self.lower_pattern(&mut cx, pat, &mut have_errors);
+        if have_errors {
+            return false;
+        }
+
+        let pats: Matrix<'_, '_> = vec![PatStack::from_pattern(pattern)].into_iter().collect();
+        check_not_useful(&mut cx, pattern_ty, &pats, pat.hir_id).is_ok()
+    }
+
     fn check_irrefutable(&self, pat: &'tcx Pat<'tcx>, origin: &str, sp: Option<Span>) {
         let mut cx = self.new_cx(pat.hir_id);
 
@@ -250,6 +334,17 @@ impl<'tcx> MatchVisitor<'_, 'tcx> {
     }
 }
 
+/// Flattens the `&&`-joined operands of a `let` chain, in evaluation order.
+fn collect_let_chain<'tcx>(ex: &'tcx hir::Expr<'tcx>, chain: &mut Vec<&'tcx hir::Expr<'tcx>>) {
+    match ex.kind {
+        hir::ExprKind::Binary(op, ref lhs, ref rhs) if op.node == hir::BinOpKind::And => {
+            collect_let_chain(lhs, chain);
+            collect_let_chain(rhs, chain);
+        }
+        _ => chain.push(ex),
+    }
+}
+
 /// A path pattern was interpreted as a constant, not a new variable.
 /// This caused an irrefutable match failure in e.g. `let`.
 fn const_not_var(
@@ -1967,8 +1967,9 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parses a `try {...}` expression (`try` token already eaten).
+    /// Parses a `try {...}` or `try -> Ty {...}` expression (`try` token already eaten).
     fn parse_try_block(&mut self, span_lo: Span, mut attrs: AttrVec) -> PResult<'a, P<Expr>> {
+        let ty = if self.eat(&token::RArrow) { Some(self.parse_ty_no_plus()?) } else { None };
         let (iattrs, body) = self.parse_inner_attrs_and_block()?;
         attrs.extend(iattrs);
         if self.eat_keyword(kw::Catch) {
@@ -1982,7 +1983,7 @@ impl<'a> Parser<'a> {
         } else {
             let span = span_lo.to(body.span);
             self.sess.gated_spans.gate(sym::try_blocks, span);
-            Ok(self.mk_expr(span, ExprKind::TryBlock(body), attrs))
+            Ok(self.mk_expr(span, ExprKind::TryBlock(body, ty), attrs))
         }
     }
 
@@ -1995,7 +1996,9 @@ impl<'a> Parser<'a> {
 
     fn is_try_block(&self) -> bool {
         self.token.is_keyword(kw::Try)
-            && self.look_ahead(1, |t| *t == token::OpenDelim(token::Brace))
+            && self.look_ahead(1, |t| {
+                *t == token::OpenDelim(token::Brace) || *t == token::RArrow
+            })
             && self.token.uninterpolated_span().rust_2018()
     }
 
@@ -58,12 +58,23 @@ impl<'a> Parser<'a> {
 
         self.sess.gated_spans.gate(sym::min_const_generics, const_span.to(self.prev_token.span));
 
+        // Parse optional const generics default value, e.g. `const N: usize = 64`.
+        let default = if self.eat(&token::Eq) {
+            let default_start = self.token.span;
+            let default = self.parse_const_arg()?;
+            let span = default_start.to(self.prev_token.span);
+            self.sess.gated_spans.gate(sym::const_generics_defaults, span);
+            Some(default)
+        } else {
+            None
+        };
+
         Ok(GenericParam {
             ident,
             id: ast::DUMMY_NODE_ID,
             attrs: preceding_attrs.into(),
             bounds: Vec::new(),
-            kind: GenericParamKind::Const { ty, kw_span: const_span },
+            kind: GenericParamKind::Const { ty, kw_span: const_span, default },
             is_placeholder: false,
         })
     }
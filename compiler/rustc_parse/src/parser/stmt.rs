@@ -218,8 +218,54 @@ impl<'a> Parser<'a> {
                 return Err(err);
             }
         };
+        let els = if self.eat_keyword(kw::Else) {
+            let else_span = self.prev_token.span;
+            let els = self.parse_block()?;
+            self.sess.gated_spans.gate(sym::let_else, lo.to(self.prev_token.span));
+            match init {
+                Some(ref init) => {
+                    self.check_let_else_init(init);
+                    Some(els)
+                }
+                None => {
+                    self.struct_span_err(else_span, "`let...else` statement without an initializer")
+                        .span_label(pat.span, "expected `= <expr>` after this pattern")
+                        .emit();
+                    None
+                }
+            }
+        } else {
+            None
+        };
         let hi = if self.token == token::Semi { self.token.span } else { self.prev_token.span };
-        Ok(P(ast::Local { ty, pat, init, id: DUMMY_NODE_ID, span: lo.to(hi), attrs }))
+        Ok(P(ast::Local { ty, pat, init, els, id: DUMMY_NODE_ID, span: lo.to(hi), attrs }))
+    }
+
+    /// Checks the initializer of a `let...else` statement. It may neither end in a `}`, as in
+    /// `let x = if a { b } else { c } else { return };`, nor be a lazy boolean expression, as in
+    /// `let true = a && b else { return };`, since both read as if the `else` belonged elsewhere.
+    fn check_let_else_init(&self, init: &Expr) {
+        let msg = match init.kind {
+            ExprKind::Binary(op, ..) if op.node.lazy() => format!(
+                "a `{}` expression cannot be directly assigned in `let...else`",
+                op.node.to_string()
+            ),
+            _ if !classify::expr_requires_semi_to_be_stmt(init) => {
+                "right curly brace `}` before `else` in a `let...else` statement not allowed"
+                    .to_string()
+            }
+            _ => return,
+        };
+        self.struct_span_err(init.span, &msg)
+            .multipart_suggestion(
+                "wrap the expression in parentheses",
+                vec![
+                    (init.span.shrink_to_lo(), "(".to_string()),
+                    (init.span.shrink_to_hi(), ")".to_string()),
+                ],
+                Applicability::MachineApplicable,
+            )
+            .emit();
     }
 
     /// Parses the RHS of a local variable declaration (e.g., '= 14;').
@@ -352,6 +352,9 @@ impl<'tcx> Visitor<'tcx> for IrMaps<'tcx> {
 
     fn visit_local(&mut self, local: &'tcx hir::Local<'tcx>) {
         self.add_from_pat(&local.pat);
+        if local.els.is_some() {
+            self.add_live_node_for_node(local.hir_id, ExprNode(local.span));
+        }
         intravisit::walk_local(self, local);
     }
 
@@ -407,6 +410,11 @@ impl<'tcx> Visitor<'tcx> for IrMaps<'tcx> {
                 intravisit::walk_expr(self, expr);
             }
 
+            hir::ExprKind::Let(ref pat, _) => {
+                self.add_from_pat(pat);
+                intravisit::walk_expr(self, expr);
+            }
+
             // live nodes required for interesting control flow:
             hir::ExprKind::Match(..) | hir::ExprKind::Loop(..) => {
                 self.add_live_node_for_node(expr.hir_id, ExprNode(expr.span));
@@ -940,6 +948,16 @@ impl<'a, 'tcx> Liveness<'a, 'tcx> {
                 // initialization, which is mildly more complex than checking
                 // once at the func header but otherwise equivalent.
 
+                // The `else` block of a `let...else` is entered if the pattern doesn't match,
+                // like a second arm of a `match` on the initializer.
+                let mut succ = succ;
+                if let Some(els) = local.els {
+                    let else_ln = self.propagate_through_block(els, succ);
+                    let ln = self.live_node(local.hir_id, local.span);
+                    self.init_from_succ(ln, succ);
+                    self.merge_from_succ(ln, else_ln, false);
+                    succ = ln;
+                }
                 let succ = self.propagate_through_opt_expr(local.init.as_deref(), succ);
                 self.define_bindings_in_pat(&local.pat, succ)
             }
@@ -1029,6 +1047,13 @@ impl<'a, 'tcx> Liveness<'a, 'tcx> {
                 self.propagate_through_expr(&e, ln)
             }
 
+            hir::ExprKind::Let(ref pat, ref init) => {
+                // The bindings are defined once `init` has been evaluated and
+                // matched; the rest of the `let` chain sees them in `succ`.
+                let succ = self.define_bindings_in_pat(pat, succ);
+                self.propagate_through_expr(init, succ)
+            }
+
             hir::ExprKind::Ret(ref o_e) => {
                 // Ignore succ and subst exit_ln.
                 self.propagate_through_opt_expr(o_e.as_ref().map(|e| &**e), self.exit_ln)
@@ -1449,6 +1474,10 @@ fn check_expr<'tcx>(this: &mut Liveness<'_, 'tcx>, expr: &'tcx Expr<'tcx>) {
             }
         }
 
+        hir::ExprKind::Let(ref pat, _) => {
+            this.check_unused_vars_in_pat(pat, None, |_, _, _, _| {});
+        }
+
         hir::ExprKind::LlvmInlineAsm(ref asm) => {
             for input in asm.inputs_exprs {
                 this.visit_expr(input);
@@ -237,8 +237,12 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
                 ref r,
             ) => {
                 // For shortcircuiting operators, mark the RHS as a terminating
-                // scope since it only executes conditionally.
-                terminating(r.hir_id.local_id);
+                // scope since it only executes conditionally. The exception is
+                // the RHS of a `let` chain: the temporaries of a `let` must live
+                // as long as its bindings, which are used by the rest of the chain.
+                if !r.is_let_chain() {
+                    terminating(r.hir_id.local_id);
+                }
             }
 
             hir::ExprKind::Loop(ref body, _, _) => {
@@ -339,6 +343,20 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
             let body = visitor.tcx.hir().body(body);
             visitor.visit_body(body);
         }
+        hir::ExprKind::Match(ref cond, arms, hir::MatchSource::IfDesugar { .. })
+        | hir::ExprKind::Match(ref cond, arms, hir::MatchSource::WhileDesugar)
+            if cond.is_let_chain() =>
+        {
+            // The bindings of a `let` chain are used by the `then` arm, so they
+            // are scoped to the whole `if` rather than to the enclosing block.
+            let expr_cx = visitor.cx;
+            visitor.cx.var_parent = visitor.cx.parent;
+            visitor.visit_expr(cond);
+            visitor.cx = expr_cx;
+            for arm in arms {
+                visitor.visit_arm(arm);
+            }
+        }
         hir::ExprKind::AssignOp(_, ref left_expr, ref right_expr) => {
             debug!(
                 "resolve_expr - enabling pessimistic_yield, was previously {}",
@@ -798,6 +816,7 @@ impl<'tcx> Visitor<'tcx> for RegionResolutionVisitor<'tcx> {
     }
     fn visit_local(&mut self, l: &'tcx Local<'tcx>) {
         resolve_local(self, Some(&l.pat), l.init.as_deref());
+        walk_list!(self, visit_block, &l.els);
     }
 }
 
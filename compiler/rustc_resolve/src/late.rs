@@ -604,7 +604,7 @@ impl<'a: 'ast, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
                     // Allow all following defaults to refer to this type parameter.
                     default_ban_rib.bindings.remove(&Ident::with_dummy_span(param.ident.name));
                 }
-                GenericParamKind::Const { ref ty, kw_span: _ } => {
+                GenericParamKind::Const { ref ty, kw_span: _, ref default } => {
                     for bound in &param.bounds {
                         self.visit_param_bound(bound);
                     }
@@ -613,6 +613,16 @@ impl<'a: 'ast, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
                     self.visit_ty(ty);
                     self.ribs[TypeNS].pop().unwrap();
                     self.ribs[ValueNS].pop().unwrap();
+
+                    if let Some(ref expr) = default {
+                        // Const defaults are checked just like type defaults: they may not
+                        // refer to any generic parameters, nor to `Self` in ADT generics.
+                        self.ribs[TypeNS].push(default_ban_rib);
+                        self.with_rib(ValueNS, ForwardTyParamBanRibKind, |this| {
+                            this.visit_anon_const(expr)
+                        });
+                        default_ban_rib = self.ribs[TypeNS].pop().unwrap();
+                    }
                 }
             }
         }
@@ -632,17 +642,39 @@ impl<'a: 'ast, 'ast> Visitor<'ast> for LateResolutionVisitor<'a, '_, 'ast> {
                 // resolution in the value namespace succeeds, we have an generic const argument on
                 // our hands.
                 if let TyKind::Path(ref qself, ref path) = ty.kind {
-                    // We cannot disambiguate multi-segment paths right now as that requires type
-                    // checking.
-                    if path.segments.len() == 1 && path.segments[0].args.is_none() {
+                    // We cannot disambiguate multi-segment paths which do not fully resolve,
+                    // e.g. `Self::N` or `T::N`, right now as that requires type checking.
+                    // Paths through modules, like `consts::N`, are fine however.
+                    let is_simple_path = match &path.segments[..] {
+                        [segment] => segment.args.is_none(),
+                        segments => qself.is_none() && segments.iter().all(|s| s.args.is_none()),
+                    };
+                    if is_simple_path {
                         let mut check_ns = |ns| {
-                            self.resolve_ident_in_lexical_scope(
-                                path.segments[0].ident,
-                                ns,
-                                None,
+                            if let [segment] = &path.segments[..] {
+                                return self
+                                    .resolve_ident_in_lexical_scope(
+                                        segment.ident,
+                                        ns,
+                                        None,
+                                        path.span,
+                                    )
+                                    .is_some();
+                            }
+                            match self.resolve_path(
+                                &Segment::from_path(path),
+                                Some(ns),
+                                false,
                                 path.span,
-                            )
-                            .is_some()
+                                CrateLint::No,
+                            ) {
+                                PathResult::NonModule(partial_res) => {
+                                    partial_res.unresolved_segments() == 0
+                                        && partial_res.base_res() != Res::Err
+                                }
+                                PathResult::Module(..) => ns == TypeNS,
+                                _ => false,
+                            }
                         };
                         if !check_ns(TypeNS) && check_ns(ValueNS) {
                             // This must be equivalent to `visit_anon_const`, but we cannot call it
@@ -1377,6 +1409,9 @@ impl<'a: 'ast, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
         // Resolve the initializer.
         walk_list!(self, visit_expr, &local.init);
 
+        // Resolve the `else` block, in which the bindings of the pattern are not in scope.
+        walk_list!(self, visit_block, &local.els);
+
         // Resolve the pattern.
         self.resolve_pattern_top(&local.pat, PatternSource::Let);
     }
@@ -2322,6 +2357,11 @@ impl<'a: 'ast, 'b, 'ast> LateResolutionVisitor<'a, 'b, 'ast> {
             ExprKind::Async(..) | ExprKind::Closure(..) => {
                 self.with_label_rib(ClosureOrAsyncRibKind, |this| visit::walk_expr(this, expr));
             }
+            // Inline constants inherit the generics of the enclosing item, so unlike
+            // other anonymous constants they may use generic parameters freely.
+            ExprKind::ConstBlock(ref ct) => {
+                self.with_constant_rib(true, |this| visit::walk_anon_const(this, ct));
+            }
             _ => {
                 visit::walk_expr(self, expr);
             }
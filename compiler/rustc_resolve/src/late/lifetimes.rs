@@ -723,6 +723,15 @@ impl<'a, 'tcx> Visitor<'tcx> for LifetimeContext<'a, 'tcx> {
                 );
                 self.missing_named_lifetime_spots.pop();
             }
+            Type(bounds, default) if self.impl_trait_in_trait_fn(trait_item.hir_id).is_some() => {
+                // A provided method defines the associated type as its opaque return type, see
+                // the impl item case below.
+                let args = match default.map(|ty| &ty.kind) {
+                    Some(hir::TyKind::OpaqueDef(_, args)) => args,
+                    _ => &[],
+                };
+                self.visit_impl_trait_in_trait_assoc_ty(trait_item.hir_id, bounds, args);
+            }
             Type(bounds, ref ty) => {
                 self.missing_named_lifetime_spots.push((&trait_item.generics).into());
                 let generics = &trait_item.generics;
@@ -785,6 +794,15 @@ impl<'a, 'tcx> Visitor<'tcx> for LifetimeContext<'a, 'tcx> {
                 );
                 self.missing_named_lifetime_spots.pop();
             }
+            TyAlias(ty) if self.impl_trait_in_trait_fn(impl_item.hir_id).is_some() => {
+                // Only resolve the lifetimes applied to the opaque type, its bounds are resolved
+                // in the scope of the method.
+                let args = match ty.kind {
+                    hir::TyKind::OpaqueDef(_, args) => args,
+                    _ => &[],
+                };
+                self.visit_impl_trait_in_trait_assoc_ty(impl_item.hir_id, &[], args);
+            }
             TyAlias(ref ty) => {
                 let generics = &impl_item.generics;
                 self.missing_named_lifetime_spots.push(generics.into());
@@ -1751,6 +1769,78 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
         self.next_early_index_helper(true)
     }
 
+    /// Returns the method whose `impl Trait` return type the associated type `hir_id` desugars
+    /// to, if any.
+    fn impl_trait_in_trait_fn(&self, hir_id: hir::HirId) -> Option<DefId> {
+        let def_id = self.tcx.hir().local_def_id(hir_id);
+        self.tcx.associated_item(def_id.to_def_id()).impl_trait_fn
+    }
+
+    /// Visits the anonymous associated type `hir_id` that an `impl Trait` return type in a trait
+    /// or trait impl desugars to. Like the opaque type it stands for, it is generic over the
+    /// lifetimes captured by the `impl Trait`, which may be elided or fresh ones, so its
+    /// parameters are not checked and elided lifetimes in its bounds resolve to its `'_`
+    /// parameter.
+    fn visit_impl_trait_in_trait_assoc_ty(
+        &mut self,
+        hir_id: hir::HirId,
+        bounds: &'tcx [hir::GenericBound<'tcx>],
+        opaque_ty_args: &'tcx [hir::GenericArg<'tcx>],
+    ) {
+        let generics = self.tcx.hir().get_generics(hir_id.owner.to_def_id()).unwrap();
+
+        // The parameters of the associated type come after those of the method, see
+        // `generics_of`. The method comes first in the trait or impl, so it has been visited
+        // already and its late-bound lifetimes are known.
+        let fn_def_id = self.impl_trait_in_trait_fn(hir_id).unwrap();
+        let fn_generics = self.tcx.hir().get_generics(fn_def_id).unwrap();
+        let fn_early_params = fn_generics
+            .params
+            .iter()
+            .filter(|param| match param.kind {
+                GenericParamKind::Lifetime { .. } => !self.map.late_bound.contains(&param.hir_id),
+                GenericParamKind::Type { .. } | GenericParamKind::Const { .. } => true,
+            })
+            .count();
+        let mut index = self.next_early_index() + fn_early_params as u32;
+        let mut elision = None;
+        let mut lifetimes = FxHashMap::default();
+        for param in generics.params {
+            let (name, reg) = Region::early(&self.tcx.hir(), &mut index, param);
+            if let Region::EarlyBound(_, def_id, _) = reg {
+                self.lifetime_uses.insert(def_id, LifetimeUseSet::Many);
+            }
+            if name.ident().name == kw::UnderscoreLifetime {
+                if let hir::ParamName::Plain(_) = name {
+                    elision = Some(reg);
+                }
+            }
+            lifetimes.insert(name, reg);
+        }
+
+        let scope = Scope::Binder {
+            lifetimes,
+            next_early_index: index,
+            s: self.scope,
+            track_lifetime_uses: true,
+            opaque_type_parent: true,
+        };
+        self.with(scope, |_old_scope, this| {
+            let elide = elision.map_or(Elide::Forbid, Elide::Exact);
+            let scope = Scope::Elision { elide, s: this.scope };
+            this.with(scope, |_old_scope, this| {
+                for bound in bounds {
+                    this.visit_param_bound(bound);
+                }
+                for arg in opaque_ty_args {
+                    if let hir::GenericArg::Lifetime(lifetime) = arg {
+                        this.visit_lifetime(lifetime);
+                    }
+                }
+            });
+        });
+    }
+
     /// Returns the next index one would use for an `impl Trait` that
     /// is being converted into an opaque type alias `impl Trait`. This will be the
     /// next early index from the enclosing item, for the most
@@ -1974,7 +2064,8 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
                             GenericParamDefKind::Type { object_lifetime_default, .. } => {
                                 Some(object_lifetime_default)
                             }
-                            GenericParamDefKind::Lifetime | GenericParamDefKind::Const => None,
+                            GenericParamDefKind::Lifetime
+                            | GenericParamDefKind::Const { .. } => None,
                         })
                         .collect()
                 })
@@ -614,6 +614,7 @@ impl Default for Options {
             optimize: OptLevel::No,
             debuginfo: DebugInfo::None,
             lint_opts: Vec::new(),
+            lint_config: Vec::new(),
             lint_cap: None,
             describe_lints: false,
             output_types: OutputTypes(BTreeMap::new()),
@@ -640,6 +641,7 @@ impl Default for Options {
             remap_path_prefix: Vec::new(),
             edition: DEFAULT_EDITION,
             json_artifact_notifications: false,
+            json_unused_externs: false,
             pretty: None,
         }
     }
@@ -690,6 +692,10 @@ impl DebuggingOptions {
             report_delayed_bugs: self.report_delayed_bugs,
             macro_backtrace: self.macro_backtrace,
             deduplicate_diagnostics: self.deduplicate_diagnostics,
+            deterministic_diagnostic_order: self
+                .deterministic_diagnostics
+                .unwrap_or(cfg!(parallel_compiler) && self.threads != 1),
+            group_macro_diagnostics: self.group_macro_diagnostics,
         }
     }
 }
@@ -1080,6 +1086,12 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
             "Remap source names in all output (compiler messages and output files)",
             "FROM=TO",
         ),
+        opt::opt(
+            "",
+            "lint-config",
+            "Read lint levels from a TOML file, overridden by the -A/-W/-D/-F flags",
+            "PATH",
+        ),
     ]);
     opts
 }
@@ -1123,6 +1135,61 @@ pub fn get_cmd_lint_options(
     (lint_opts, describe_lints, lint_cap)
 }
 
+/// Parses the lint configuration file passed with `--lint-config`.
+///
+/// The levels are read from the `[lints]` table, where each key is a lint or lint group
+/// name. Tool lints are read from a nested table named after the tool, e.g.
+/// `[lints.clippy]`.
+pub fn parse_lint_config(
+    matches: &getopts::Matches,
+    error_format: ErrorOutputType,
+) -> Vec<(String, lint::Level)> {
+    let path = match matches.opt_str("lint-config") {
+        Some(path) => PathBuf::from(path),
+        None => return Vec::new(),
+    };
+    let error = |msg: &str| -> ! {
+        early_error(
+            error_format,
+            &format!("failed to read lint configuration file `{}`: {}", path.display(), msg),
+        )
+    };
+
+    let contents = std::fs::read_to_string(&path).unwrap_or_else(|e| error(&e.to_string()));
+    let config: toml::Value = contents.parse().unwrap_or_else(|e| error(&format!("{}", e)));
+    let lints = match config.get("lints") {
+        Some(toml::Value::Table(lints)) => lints,
+        Some(_) => error("`lints` must be a table"),
+        None => return Vec::new(),
+    };
+
+    let parse_level = |lint_name: &str, value: &toml::Value| match value.as_str() {
+        Some(level) => lint::Level::from_str(level).unwrap_or_else(|| {
+            error(&format!("unknown lint level `{}` for `{}`", level, lint_name))
+        }),
+        None => error(&format!("the level of `{}` must be a string", lint_name)),
+    };
+
+    let mut lint_config = vec![];
+    for (name, value) in lints {
+        match value {
+            toml::Value::Table(tool_lints) => {
+                for (lint_name, value) in tool_lints {
+                    let lint_name = format!("{}::{}", name, lint_name.replace("-", "_"));
+                    let level = parse_level(&lint_name, value);
+                    lint_config.push((lint_name, level));
+                }
+            }
+            _ => {
+                let lint_name = name.replace("-", "_");
+                let level = parse_level(&lint_name, value);
+                lint_config.push((lint_name, level));
+            }
+        }
+    }
+    lint_config
+}
+
 /// Parses the `--color` flag.
 pub fn parse_color(matches: &getopts::Matches) -> ColorConfig {
     match matches.opt_str("color").as_ref().map(|s| &s[..]) {
@@ -1145,13 +1212,15 @@ pub fn parse_color(matches: &getopts::Matches) -> ColorConfig {
 
 /// Parse the `--json` flag.
 ///
-/// The first value returned is how to render JSON diagnostics, and the second
-/// is whether or not artifact notifications are enabled.
-pub fn parse_json(matches: &getopts::Matches) -> (HumanReadableErrorType, bool) {
+/// The first value returned is how to render JSON diagnostics, the second is
+/// whether or not artifact notifications are enabled, and the third whether or
+/// not the unused `--extern` crates are reported.
+pub fn parse_json(matches: &getopts::Matches) -> (HumanReadableErrorType, bool, bool) {
     let mut json_rendered: fn(ColorConfig) -> HumanReadableErrorType =
         HumanReadableErrorType::Default;
     let mut json_color = ColorConfig::Never;
     let mut json_artifact_notifications = false;
+    let mut json_unused_externs = false;
     for option in matches.opt_strs("json") {
         // For now conservatively forbid `--color` with `--json` since `--json`
         // won't actually be emitting any colors and anything colorized is
@@ -1168,6 +1237,7 @@ pub fn parse_json(matches: &getopts::Matches) -> (HumanReadableErrorType, bool)
                 "diagnostic-short" => json_rendered = HumanReadableErrorType::Short,
                 "diagnostic-rendered-ansi" => json_color = ColorConfig::Always,
                 "artifacts" => json_artifact_notifications = true,
+                "unused-externs" => json_unused_externs = true,
                 s => early_error(
                     ErrorOutputType::default(),
                     &format!("unknown `--json` option `{}`", s),
@@ -1175,7 +1245,7 @@ pub fn parse_json(matches: &getopts::Matches) -> (HumanReadableErrorType, bool)
             }
         }
     }
-    (json_rendered(json_color), json_artifact_notifications)
+    (json_rendered(json_color), json_artifact_notifications, json_unused_externs)
 }
 
 /// Parses the `--error-format` flag.
@@ -1265,6 +1335,7 @@ fn check_debug_option_stability(
     debugging_opts: &DebuggingOptions,
     error_format: ErrorOutputType,
     json_rendered: HumanReadableErrorType,
+    json_unused_externs: bool,
 ) {
     if !debugging_opts.unstable_options {
         if let ErrorOutputType::Json { pretty: true, json_rendered } = error_format {
@@ -1281,6 +1352,12 @@ fn check_debug_option_stability(
                 "`--error-format=human-annotate-rs` is unstable",
             );
         }
+        if json_unused_externs {
+            early_error(
+                ErrorOutputType::Json { pretty: false, json_rendered },
+                "`--json=unused-externs` is unstable",
+            );
+        }
     }
 }
 
@@ -1697,7 +1774,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
 
     let edition = parse_crate_edition(matches);
 
-    let (json_rendered, json_artifact_notifications) = parse_json(matches);
+    let (json_rendered, json_artifact_notifications, json_unused_externs) = parse_json(matches);
 
     let error_format = parse_error_format(matches, color, json_rendered);
 
@@ -1706,9 +1783,10 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         .unwrap_or_else(|e| early_error(error_format, &e[..]));
 
     let (lint_opts, describe_lints, lint_cap) = get_cmd_lint_options(matches, error_format);
+    let lint_config = parse_lint_config(matches, error_format);
 
     let mut debugging_opts = build_debugging_options(matches, error_format);
-    check_debug_option_stability(&debugging_opts, error_format, json_rendered);
+    check_debug_option_stability(&debugging_opts, error_format, json_rendered, json_unused_externs);
 
     let output_types = parse_output_types(&debugging_opts, matches, error_format);
 
@@ -1819,6 +1897,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         optimize: opt_level,
         debuginfo,
         lint_opts,
+        lint_config,
         lint_cap,
         describe_lints,
         output_types,
@@ -1845,6 +1924,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         remap_path_prefix,
         edition,
         json_artifact_notifications,
+        json_unused_externs,
         pretty,
     }
 }
@@ -1,6 +1,8 @@
 pub use self::Level::*;
 use rustc_ast::node_id::{NodeId, NodeMap};
+use rustc_ast::Attribute;
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher, ToStableHashKey};
+pub use rustc_errors::LintExpectationId;
 use rustc_errors::{pluralize, Applicability, DiagnosticBuilder};
 use rustc_span::edition::Edition;
 use rustc_span::{sym, symbol::Ident, MultiSpan, Span, Symbol};
@@ -11,18 +13,29 @@ pub mod builtin;
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
 pub enum Level {
     Allow,
+    /// Like `Allow`, but reports `unfulfilled_lint_expectations` if the lint
+    /// is never emitted in this scope (RFC 2383).
+    Expect(LintExpectationId),
     Warn,
     Deny,
     Forbid,
 }
 
-rustc_data_structures::impl_stable_hash_via_hash!(Level);
+impl<HCX: rustc_hir::HashStableContext> HashStable<HCX> for Level {
+    fn hash_stable(&self, hcx: &mut HCX, hasher: &mut StableHasher) {
+        std::mem::discriminant(self).hash_stable(hcx, hasher);
+        if let Level::Expect(id) = self {
+            id.hash_stable(hcx, hasher);
+        }
+    }
+}
 
 impl Level {
     /// Converts a level to a lower-case string.
     pub fn as_str(self) -> &'static str {
         match self {
             Level::Allow => "allow",
+            Level::Expect(_) => "expect",
             Level::Warn => "warn",
             Level::Deny => "deny",
             Level::Forbid => "forbid",
@@ -50,6 +63,18 @@ impl Level {
             _ => None,
         }
     }
+
+    /// Converts a lint attribute to a level. The expectation of an `#[expect]`
+    /// attribute refers to its first lint.
+    pub fn from_attr(attr: &Attribute) -> Option<Level> {
+        match attr.name_or_empty() {
+            sym::expect => Some(Level::Expect(LintExpectationId::Unstable {
+                attr_id: attr.id,
+                lint_index: 0,
+            })),
+            name => Level::from_symbol(name),
+        }
+    }
 }
 
 /// Specification of a single lint.
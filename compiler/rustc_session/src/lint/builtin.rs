@@ -539,6 +539,35 @@ declare_lint! {
     "detects attributes that were not used by the compiler"
 }
 
+declare_lint! {
+    /// The `unfulfilled_lint_expectations` lint detects `#[expect]`
+    /// attributes whose expected lint was never emitted.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[expect(unused_variables)]
+    /// fn main() {
+    ///     let x = 1;
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// An `#[expect]` attribute suppresses the lint like `#[allow]` does, but
+    /// also records that the lint is expected to fire in its scope. If the
+    /// code changes so that the lint no longer fires, the attribute is stale
+    /// and should be removed.
+    pub UNFULFILLED_LINT_EXPECTATIONS,
+    Warn,
+    "detects `#[expect]` attributes whose lint was never emitted"
+}
+
 declare_lint! {
     /// The `unreachable_code` lint detects unreachable code paths.
     ///
@@ -2647,6 +2676,48 @@ declare_lint! {
     };
 }
 
+declare_lint! {
+    /// The `must_not_suspend` lint detects values of types marked with
+    /// `#[must_not_suspend]` that are held across a suspend point, such as an
+    /// `.await`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(must_not_suspend)]
+    /// #![warn(must_not_suspend)]
+    ///
+    /// #[must_not_suspend = "the connection must be released before waiting"]
+    /// struct Connection;
+    ///
+    /// async fn other() {}
+    ///
+    /// async fn query() {
+    ///     let connection = Connection;
+    ///     other().await;
+    ///     drop(connection);
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Some values, such as lock guards, should not live across suspend
+    /// points: they may cause deadlocks when the future or generator is
+    /// suspended while holding them, or make it impossible to send it to
+    /// another thread. Such a value can usually be moved into a block which
+    /// ends before the suspend point.
+    ///
+    /// This lint is "allow" by default because holding such a value is often
+    /// intended, for example a `MutexGuard` held in a future which is only
+    /// polled on a single thread.
+    pub MUST_NOT_SUSPEND,
+    Allow,
+    "use of a `#[must_not_suspend]` value across a suspend point",
+    @feature_gate = sym::must_not_suspend;
+}
+
 declare_tool_lint! {
     pub rustc::INEFFECTIVE_UNSTABLE_TRAIT_IMPL,
     Deny,
@@ -2732,6 +2803,8 @@ declare_lint_pass! {
         CENUM_IMPL_DROP_CAST,
         CONST_EVALUATABLE_UNCHECKED,
         INEFFECTIVE_UNSTABLE_TRAIT_IMPL,
+        UNFULFILLED_LINT_EXPECTATIONS,
+        MUST_NOT_SUSPEND,
     ]
 }
 
@@ -8,6 +8,7 @@ use crate::utils::NativeLibKind;
 use rustc_target::spec::{CodeModel, LinkerFlavor, MergeFunctions, PanicStrategy};
 use rustc_target::spec::{RelocModel, RelroLevel, TargetTriple, TlsModel};
 
+use rustc_data_structures::profiling::SummaryFormat;
 use rustc_feature::UnstableFeatures;
 use rustc_span::edition::Edition;
 use rustc_span::SourceFileHashAlgorithm;
@@ -89,6 +90,7 @@ top_level_options!(
         debug_assertions: bool [TRACKED],
         debuginfo: DebugInfo [TRACKED],
         lint_opts: Vec<(String, lint::Level)> [TRACKED],
+        lint_config: Vec<(String, lint::Level)> [TRACKED],
         lint_cap: Option<lint::Level> [TRACKED],
         describe_lints: bool [UNTRACKED],
         output_types: OutputTypes [TRACKED],
@@ -145,6 +147,10 @@ top_level_options!(
         // by the compiler.
         json_artifact_notifications: bool [TRACKED],
 
+        // `true` if we're emitting a JSON blob listing the `--extern` crates
+        // which were not used by the crate.
+        json_unused_externs: bool [UNTRACKED],
+
         pretty: Option<PpMode> [UNTRACKED],
     }
 );
@@ -267,6 +273,7 @@ macro_rules! options {
             "either a boolean (`yes`, `no`, `on`, `off`, etc), or the path to the linker plugin";
         pub const parse_switch_with_opt_path: &str =
             "an optional path to the profiling data output directory";
+        pub const parse_self_profile_summary: &str = "either no value, `text`, or `json`";
         pub const parse_merge_functions: &str = "one of: `disabled`, `trampolines`, or `aliases`";
         pub const parse_symbol_mangling_version: &str = "either `legacy` or `v0` (RFC 2603)";
         pub const parse_src_file_hash: &str = "either `md5` or `sha1`";
@@ -687,6 +694,15 @@ macro_rules! options {
             true
         }
 
+        fn parse_self_profile_summary(slot: &mut Option<SummaryFormat>, v: Option<&str>) -> bool {
+            *slot = match v {
+                None | Some("text") => Some(SummaryFormat::Text),
+                Some("json") => Some(SummaryFormat::Json),
+                _ => return false,
+            };
+            true
+        }
+
         fn parse_src_file_hash(slot: &mut Option<SourceFileHashAlgorithm>, v: Option<&str>) -> bool {
             match v.and_then(|s| SourceFileHashAlgorithm::from_str(s).ok()) {
                 Some(hash_kind) => *slot = Some(hash_kind),
@@ -864,6 +880,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     dep_tasks: bool = (false, parse_bool, [UNTRACKED],
         "print tasks that execute and the color their dep node gets (requires debug build) \
         (default: no)"),
+    deterministic_diagnostics: Option<bool> = (None, parse_opt_bool, [UNTRACKED],
+        "emit diagnostics sorted by their location, independently of the order in which \
+        they were reported (default: yes with `-Z threads` greater than 1)"),
     dont_buffer_diagnostics: bool = (false, parse_bool, [UNTRACKED],
         "emit diagnostics rather than buffering (breaks NLL error downgrading, sorting) \
         (default: no)"),
@@ -893,8 +912,14 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         all `statement`s (including terminators), only `terminator` spans, or \
         computed `block` spans (one span encompassing a block's terminator and \
         all statements)."),
+    emit_future_incompat_report: bool = (false, parse_bool, [UNTRACKED],
+        "emit a JSON report of the future-incompatible lints that fired, even if they were \
+        allowed or capped (default: no)"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
+    explain_obligations: bool = (false, parse_bool, [UNTRACKED],
+        "explain unsatisfied trait bounds with the tree of obligations and the candidates \
+        considered for each of them (default: no)"),
     fewer_names: bool = (false, parse_bool, [TRACKED],
         "reduce memory use by retaining fewer names within compilation artifacts (LLVM-IR) \
         (default: no)"),
@@ -909,6 +934,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     graphviz_font: String = ("Courier, monospace".to_string(), parse_string, [UNTRACKED],
         "use the given `fontname` in graphviz output; can be overridden by setting \
         environment variable `RUSTC_GRAPHVIZ_FONT` (default: `Courier, monospace`)"),
+    group_macro_diagnostics: bool = (false, parse_bool, [UNTRACKED],
+        "report the diagnostics with the same code and message that point into the same macro \
+        once, listing the invocations of the macro (default: no)"),
     hir_stats: bool = (false, parse_bool, [UNTRACKED],
         "print some statistics about AST and HIR (default: no)"),
     human_readable_cgu_names: bool = (false, parse_bool, [TRACKED],
@@ -924,6 +952,12 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "verify incr. comp. hashes of green query instances (default: no)"),
     inline_in_all_cgus: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "control whether `#[inline]` functions are in all CGUs"),
+    inline_mir: Option<bool> = (None, parse_opt_bool, [TRACKED],
+        "enable MIR inlining (default: yes with `-Z mir-opt-level=2` or higher, no otherwise)"),
+    inline_mir_hint_threshold: usize = (100, parse_uint, [TRACKED],
+        "inlining threshold for functions with inline hint (default: 100)"),
+    inline_mir_threshold: usize = (50, parse_uint, [TRACKED],
+        "a default MIR inlining threshold (default: 50)"),
     input_stats: bool = (false, parse_bool, [UNTRACKED],
         "gather statistics about the input (default: no)"),
     insert_sideeffect: bool = (false, parse_bool, [TRACKED],
@@ -999,6 +1033,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "enable polonius-based borrow-checker (default: no)"),
     polymorphize: bool = (false, parse_bool, [TRACKED],
           "perform polymorphization analysis"),
+    polymorphize_stats: bool = (false, parse_bool, [UNTRACKED],
+        "report the instances that were merged by polymorphization (default: no)"),
     pre_link_arg: (/* redirected to pre_link_args */) = ((), parse_string_push, [UNTRACKED],
         "a single extra argument to prepend the linker invocation (can be used several times)"),
     pre_link_args: Vec<String> = (Vec::new(), parse_list, [UNTRACKED],
@@ -1058,6 +1094,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, query-keys, function-args, args, llvm"),
+    self_profile_summary: Option<SummaryFormat> = (None, parse_self_profile_summary, [UNTRACKED],
+        "print a summary of the most expensive queries, activities, items and codegen units \
+        at the end of the compilation, as `text` (default) or `json`"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     show_span: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -1098,6 +1137,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "choose the TLS model to use (`rustc --print tls-models` for details)"),
     trace_macros: bool = (false, parse_bool, [UNTRACKED],
         "for every macro invocation, print its name and arguments (default: no)"),
+    translate_lang: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "language identifier of the translation of diagnostics to use, read from \
+        `$sysroot/share/locale/<lang>` (default: English)"),
     treat_err_as_bug: Option<usize> = (None, parse_treat_err_as_bug, [TRACKED],
         "treat error number `val` that occurs as bug"),
     trim_diagnostic_paths: bool = (true, parse_bool, [UNTRACKED],
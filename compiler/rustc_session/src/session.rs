@@ -297,6 +297,21 @@ impl Session {
     pub fn finish_diagnostics(&self, registry: &Registry) {
         self.check_miri_unleashed_features();
         self.diagnostic().print_error_count(registry);
+        self.emit_future_breakage();
+    }
+
+    /// Emits the report of the future-incompatible lints that fired in this crate, whatever
+    /// their level, so that build tools can tell about dependencies that will break.
+    fn emit_future_breakage(&self) {
+        if !self.opts.debugging_opts.emit_future_incompat_report {
+            return;
+        }
+
+        let diags = self.diagnostic().take_future_breakage_diagnostics();
+        if diags.is_empty() {
+            return;
+        }
+        self.diagnostic().emit_future_breakage_report(diags);
     }
 
     pub fn local_crate_disambiguator(&self) -> CrateDisambiguator {
@@ -323,6 +338,23 @@ impl Session {
         self.const_eval_limit.get().copied().unwrap()
     }
 
+    pub fn struct_span_allow<S: Into<MultiSpan>>(&self, sp: S, msg: &str) -> DiagnosticBuilder<'_> {
+        self.diagnostic().struct_span_allow(sp, msg)
+    }
+    pub fn struct_allow(&self, msg: &str) -> DiagnosticBuilder<'_> {
+        self.diagnostic().struct_allow(msg)
+    }
+    pub fn struct_span_expect<S: Into<MultiSpan>>(
+        &self,
+        sp: S,
+        msg: &str,
+        id: lint::LintExpectationId,
+    ) -> DiagnosticBuilder<'_> {
+        self.diagnostic().struct_span_expect(sp, msg, id)
+    }
+    pub fn struct_expect(&self, msg: &str, id: lint::LintExpectationId) -> DiagnosticBuilder<'_> {
+        self.diagnostic().struct_expect(msg, id)
+    }
     pub fn struct_span_warn<S: Into<MultiSpan>>(&self, sp: S, msg: &str) -> DiagnosticBuilder<'_> {
         self.diagnostic().struct_span_warn(sp, msg)
     }
@@ -1236,8 +1268,9 @@ pub fn build_session(
     // normal diagnostic warnings, since the warning lint can also be denied and changed
     // later via the source code.
     let warnings_allow = sopts
-        .lint_opts
+        .lint_config
         .iter()
+        .chain(&sopts.lint_opts)
         .filter(|&&(ref key, _)| *key == "warnings")
         .map(|&(_, ref level)| *level == lint::Allow)
         .last()
@@ -1271,20 +1304,41 @@ pub fn build_session(
     ));
     let emitter = default_emitter(&sopts, registry, source_map.clone(), write_dest);
 
-    let span_diagnostic = rustc_errors::Handler::with_emitter_and_flags(
+    let mut span_diagnostic = rustc_errors::Handler::with_emitter_and_flags(
         emitter,
         sopts.debugging_opts.diagnostic_handler_flags(can_emit_warnings),
     );
 
-    let self_profiler = if let SwitchWithOptPath::Enabled(ref d) = sopts.debugging_opts.self_profile
-    {
-        let directory =
-            if let Some(ref directory) = d { directory } else { std::path::Path::new(".") };
+    let sysroot = match &sopts.maybe_sysroot {
+        Some(sysroot) => sysroot.clone(),
+        None => filesearch::get_or_default_sysroot(),
+    };
+    if let Some(ref locale) = sopts.debugging_opts.translate_lang {
+        match rustc_errors::MessageCatalog::load_locale(&sysroot, locale) {
+            Ok(catalog) => span_diagnostic.set_locale_catalog(catalog),
+            Err(e) => early_warn(
+                sopts.error_format,
+                &format!("failed to load the translations for `{}`: {}", locale, e),
+            ),
+        }
+    }
 
+    let self_profile_summary = sopts.debugging_opts.self_profile_summary;
+    let self_profile_dir = match sopts.debugging_opts.self_profile {
+        SwitchWithOptPath::Enabled(ref d) => {
+            Some(Some(d.as_deref().unwrap_or_else(|| std::path::Path::new("."))))
+        }
+        // The summary is aggregated from the profiler's events, so it needs a profiler too,
+        // which then writes its event data to a temporary directory.
+        SwitchWithOptPath::Disabled if self_profile_summary.is_some() => Some(None),
+        SwitchWithOptPath::Disabled => None,
+    };
+    let self_profiler = if let Some(directory) = self_profile_dir {
         let profiler = SelfProfiler::new(
             directory,
             sopts.crate_name.as_ref().map(|s| &s[..]),
             &sopts.debugging_opts.self_profile_events,
+            self_profile_summary,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1298,10 +1352,6 @@ pub fn build_session(
     };
 
     let parse_sess = ParseSess::with_span_handler(span_diagnostic, source_map);
-    let sysroot = match &sopts.maybe_sysroot {
-        Some(sysroot) => sysroot.clone(),
-        None => filesearch::get_or_default_sysroot(),
-    };
 
     let host_triple = config::host_triple();
     let target_triple = sopts.target_triple.triple();
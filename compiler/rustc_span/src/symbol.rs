@@ -275,7 +275,16 @@ symbols! {
         assume,
         assume_init,
         async_await,
+        async_call,
+        async_call_mut,
+        async_call_once,
         async_closure,
+        async_fn,
+        async_fn_in_trait,
+        async_fn_mut,
+        async_fn_once,
+        async_fn_once_output,
+        async_fn_traits,
         atomics,
         att_syntax,
         attr,
@@ -360,6 +369,7 @@ symbols! {
         const_fn_transmute,
         const_fn_union,
         const_generics,
+        const_generics_defaults,
         const_if_match,
         const_impl_trait,
         const_in_array_repeat_expressions,
@@ -477,6 +487,7 @@ symbols! {
         existential_type,
         exp2f32,
         exp2f64,
+        expect,
         expected,
         expf32,
         expf64,
@@ -619,6 +630,7 @@ symbols! {
         lazy_normalization_consts,
         le,
         let_chains,
+        let_else,
         lhs,
         lib,
         libc,
@@ -695,6 +707,7 @@ symbols! {
         mul,
         mul_assign,
         mul_with_overflow,
+        must_not_suspend,
         must_use,
         mut_ptr,
         mut_slice_ptr,
@@ -870,6 +883,7 @@ symbols! {
         repr_transparent,
         result,
         result_type,
+        return_position_impl_trait_in_trait,
         rhs,
         rintf32,
         rintf64,
@@ -0,0 +1,243 @@
+//! Explanation of the unsatisfied trait bounds with `-Z explain-obligations`.
+//!
+//! The explanation is a tree, starting from the obligation that required the one that
+//! failed in the first place. Each obligation lists the candidates assembled to prove it,
+//! like selection does, and why each of them was rejected. The candidate that required the
+//! next obligation of the chain lists it as its child, down to the obligation that failed.
+
+use super::{Obligation, ObligationCause, ObligationCauseCode, PredicateObligation};
+use crate::infer::InferCtxt;
+use crate::traits::select::{ExplainedCandidate, SelectionCandidate, SelectionContext};
+use rustc_errors::{DiagnosticBuilder, ExplanationNode};
+use rustc_middle::ty::{self, ToPredicate, WithConstness};
+use std::mem;
+
+/// An obligation of the chain leading to the one that failed, with the cause code that
+/// tells how it was derived from the next one.
+crate struct RequiredObligation<'a, 'tcx> {
+    trait_ref: ty::PolyTraitRef<'tcx>,
+    code: &'a ObligationCauseCode<'tcx>,
+    node: ExplanationNode,
+}
+
+crate trait InferCtxtExt<'tcx> {
+    fn note_obligation_explanation(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        obligation: &PredicateObligation<'tcx>,
+    );
+
+    /*private*/
+    fn explain_trait_obligation(
+        &self,
+        param_env: ty::ParamEnv<'tcx>,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        required: Option<RequiredObligation<'_, 'tcx>>,
+    ) -> ExplanationNode;
+
+    /*private*/
+    fn describe_candidate(
+        &self,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        candidate: &ExplainedCandidate<'tcx>,
+    ) -> String;
+
+    /*private*/
+    fn requires_obligation(
+        &self,
+        candidate: &ExplainedCandidate<'tcx>,
+        required: &RequiredObligation<'_, 'tcx>,
+    ) -> bool;
+}
+
+impl<'a, 'tcx> InferCtxtExt<'tcx> for InferCtxt<'a, 'tcx> {
+    fn note_obligation_explanation(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        obligation: &PredicateObligation<'tcx>,
+    ) {
+        if !self.tcx.sess.opts.debugging_opts.explain_obligations {
+            return;
+        }
+        let trait_ref = match obligation.predicate.to_opt_poly_trait_ref() {
+            Some(trait_ref) => trait_ref,
+            None => return,
+        };
+
+        // The chain of obligations, from the one that failed to the one that required it
+        // in the first place, without the truncation of the "required because of" notes.
+        let mut chain = vec![(self.resolve_vars_if_possible(&trait_ref), &obligation.cause.code)];
+        let mut code = &obligation.cause.code;
+        while let ObligationCauseCode::ImplDerivedObligation(data)
+        | ObligationCauseCode::BuiltinDerivedObligation(data)
+        | ObligationCauseCode::DerivedObligation(data) = code
+        {
+            chain.push((self.resolve_vars_if_possible(&data.parent_trait_ref), &data.parent_code));
+            code = &data.parent_code;
+        }
+
+        let mut required = None;
+        for (trait_ref, code) in chain {
+            let node = self.explain_trait_obligation(obligation.param_env, trait_ref, required);
+            required = Some(RequiredObligation { trait_ref, code, node });
+        }
+        let tree = required.unwrap().node;
+        err.note(&format!("the obligations that were tried are:\n{}", tree.render()));
+        self.tcx.sess.diagnostic().emit_explanation("obligations", &tree);
+    }
+
+    fn explain_trait_obligation(
+        &self,
+        param_env: ty::ParamEnv<'tcx>,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        mut required: Option<RequiredObligation<'_, 'tcx>>,
+    ) -> ExplanationNode {
+        let tcx = self.tcx;
+        let mut node =
+            ExplanationNode::new(format!("`{}`", trait_ref.without_const().to_predicate(tcx)));
+
+        let obligation = Obligation::new(
+            ObligationCause::dummy(),
+            param_env,
+            trait_ref.to_poly_trait_predicate(),
+        );
+        let mut selcx = SelectionContext::new(self);
+        match selcx.explain_candidates(&obligation) {
+            Ok((candidates, ambiguous)) => {
+                for candidate in &candidates {
+                    let description = self.describe_candidate(trait_ref, candidate);
+                    let mut child = ExplanationNode::new(description);
+                    // The next obligation of the chain is a nested obligation of the
+                    // candidate that required it.
+                    if required.as_ref().map_or(false, |required| {
+                        self.requires_obligation(candidate, required)
+                    }) {
+                        child.children.push(required.take().unwrap().node);
+                    }
+                    node.children.push(child);
+                }
+                if ambiguous {
+                    node.children.push(ExplanationNode::new(
+                        "the candidates are ambiguous, as the type isn't known yet".to_string(),
+                    ));
+                } else if candidates.is_empty() {
+                    node.children
+                        .push(ExplanationNode::new("no candidates were found".to_string()));
+                }
+            }
+            Err(error) => node.children.push(ExplanationNode::new(format!(
+                "the candidates couldn't be assembled: {:?}",
+                error,
+            ))),
+        }
+
+        // The candidate that required the next obligation of the chain wasn't found again,
+        // e.g. because it depends on inference results of the failed selection.
+        node.children.extend(required.map(|required| required.node));
+        node
+    }
+
+    fn describe_candidate(
+        &self,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        candidate: &ExplainedCandidate<'tcx>,
+    ) -> String {
+        let tcx = self.tcx;
+        let self_ty = trait_ref.skip_binder().self_ty();
+        let description = match candidate.candidate {
+            SelectionCandidate::ImplCandidate(impl_def_id) => {
+                let impl_trait_ref = tcx.impl_trait_ref(impl_def_id).unwrap();
+                let negative = tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Negative;
+                format!(
+                    "`impl {}{} for {}`",
+                    if negative { "!" } else { "" },
+                    impl_trait_ref.print_only_trait_path(),
+                    impl_trait_ref.self_ty(),
+                )
+            }
+            SelectionCandidate::ParamCandidate(bound) => {
+                format!("where-clause `{}`", bound.without_const().to_predicate(tcx))
+            }
+            SelectionCandidate::AutoImplCandidate(trait_def_id) => format!(
+                "the automatic implementation of the auto trait `{}`",
+                tcx.def_path_str(trait_def_id),
+            ),
+            SelectionCandidate::ProjectionCandidate(idx) => match *self_ty.kind() {
+                ty::Projection(ty::ProjectionTy { item_def_id: def_id, .. })
+                | ty::Opaque(def_id, _) => {
+                    format!("the bound `{}` of `{}`", tcx.item_bounds(def_id)[idx], self_ty)
+                }
+                _ => format!("a bound of `{}`", self_ty),
+            },
+            SelectionCandidate::ClosureCandidate => {
+                format!("the implementation for the closure `{}`", self_ty)
+            }
+            SelectionCandidate::GeneratorCandidate => {
+                format!("the implementation for the generator `{}`", self_ty)
+            }
+            SelectionCandidate::FnPointerCandidate => {
+                format!("the implementation for the function pointer `{}`", self_ty)
+            }
+            SelectionCandidate::DiscriminantKindCandidate => {
+                "the builtin implementation of `DiscriminantKind`".to_string()
+            }
+            SelectionCandidate::TraitAliasCandidate(alias_def_id) => {
+                format!("the trait alias `{}`", tcx.def_path_str(alias_def_id))
+            }
+            SelectionCandidate::ObjectCandidate(_) => {
+                format!("the implementation for the trait object `{}`", self_ty)
+            }
+            SelectionCandidate::BuiltinObjectCandidate => {
+                format!("the builtin implementation for the trait object `{}`", self_ty)
+            }
+            SelectionCandidate::BuiltinUnsizeCandidate => {
+                format!("the builtin unsizing implementation for `{}`", self_ty)
+            }
+            SelectionCandidate::BuiltinCandidate { .. } => {
+                format!("the builtin implementation for `{}`", self_ty)
+            }
+        };
+
+        // Selection rejects negative impls after evaluating them.
+        if let SelectionCandidate::ImplCandidate(impl_def_id) = candidate.candidate {
+            if tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Negative {
+                return format!("{}: rejected, it is a negative implementation", description);
+            }
+        }
+        if candidate.evaluation.may_apply() {
+            return format!("{}: may apply", description);
+        }
+        let unsatisfied = match &candidate.nested {
+            Some(nested) => nested
+                .iter()
+                .filter(|(_, may_hold)| !may_hold)
+                .map(|(obligation, _)| format!("`{}`", obligation.predicate))
+                .collect::<Vec<_>>(),
+            None => {
+                return format!("{}: rejected, it doesn't match the obligation", description);
+            }
+        };
+        match &unsatisfied[..] {
+            [] => format!("{}: rejected", description),
+            [predicate] => format!("{}: rejected, {} is not satisfied", description, predicate),
+            _ => format!("{}: rejected, {} are not satisfied", description, unsatisfied.join(", ")),
+        }
+    }
+
+    fn requires_obligation(
+        &self,
+        candidate: &ExplainedCandidate<'tcx>,
+        required: &RequiredObligation<'_, 'tcx>,
+    ) -> bool {
+        let tcx = self.tcx;
+        let required_trait_ref = tcx.erase_regions(&required.trait_ref);
+        candidate.nested.iter().flatten().any(|(obligation, _)| {
+            // The nested obligation must have been derived the same way, e.g. from an
+            // `impl` rather than from a builtin implementation.
+            mem::discriminant(&obligation.cause.code) == mem::discriminant(required.code)
+                && obligation.predicate.to_opt_poly_trait_ref().map_or(false, |trait_ref| {
+                    tcx.erase_regions(&trait_ref) == required_trait_ref
+                })
+        })
+    }
+}
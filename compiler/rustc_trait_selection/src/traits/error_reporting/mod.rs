@@ -1,3 +1,4 @@
+mod explain;
 pub mod on_unimplemented;
 pub mod suggestions;
 
@@ -31,6 +32,7 @@ use std::fmt;
 
 use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
 use crate::traits::query::normalize::AtExt as _;
+use explain::InferCtxtExt as _;
 use on_unimplemented::InferCtxtExt as _;
 use suggestions::InferCtxtExt as _;
 
@@ -1707,6 +1709,7 @@ impl<'a, 'tcx> InferCtxtPrivExt<'tcx> for InferCtxt<'a, 'tcx> {
             );
             self.suggest_unsized_bound_if_applicable(err, obligation);
         }
+        self.note_obligation_explanation(err, obligation);
     }
 
     fn suggest_unsized_bound_if_applicable(
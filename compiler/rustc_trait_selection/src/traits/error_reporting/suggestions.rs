@@ -1627,6 +1627,21 @@ impl<'a, 'tcx> InferCtxtExt<'tcx> for InferCtxt<'a, 'tcx> {
                             typeck_results.generator_interior_types
                         );
                         explain_yield(interior_span, yield_span, scope_span);
+
+                        // Tell why values of types marked `#[must_not_suspend = "reason"]`
+                        // shouldn't be held across an await or a yield, when the crate opted in.
+                        let must_not_suspend = self.tcx.features().must_not_suspend;
+                        if let (true, ty::Adt(def, _)) = (must_not_suspend, target_ty.kind()) {
+                            let reason = self
+                                .tcx
+                                .get_attrs(def.did)
+                                .iter()
+                                .find(|attr| self.tcx.sess.check_name(attr, sym::must_not_suspend))
+                                .and_then(|attr| attr.value_str());
+                            if let Some(reason) = reason {
+                                err.note(&reason.as_str());
+                            }
+                        }
                     }
 
                     if let Some(expr_id) = expr {
@@ -258,7 +258,7 @@ fn bounds_reference_self(tcx: TyCtxt<'_>, trait_def_id: DefId) -> SmallVec<[Span
     let trait_ref = ty::Binder::dummy(ty::TraitRef::identity(tcx, trait_def_id));
     tcx.associated_items(trait_def_id)
         .in_definition_order()
-        .filter(|item| item.kind == ty::AssocKind::Type)
+        .filter(|item| item.kind == ty::AssocKind::Type && item.impl_trait_fn.is_none())
         .flat_map(|item| tcx.explicit_item_bounds(item.def_id))
         .map(|(predicate, sp)| (predicate.subst_supertrait(tcx, &trait_ref), *sp))
         .filter_map(|predicate| predicate_references_self(tcx, predicate))
@@ -370,7 +370,8 @@ fn object_safety_violation_for_method(
                 .fn_decl()
                 .and_then(|decl| decl.inputs.get(0))
                 .map_or(method.ident.span, |arg| arg.span),
-            (MethodViolationCode::ReferencesSelfOutput, Some(node)) => {
+            (MethodViolationCode::ReferencesSelfOutput, Some(node))
+            | (MethodViolationCode::ReferencesImplTraitInTrait, Some(node)) => {
                 node.fn_decl().map_or(method.ident.span, |decl| decl.output.span())
             }
             _ => method.ident.span,
@@ -415,6 +416,19 @@ fn virtual_call_violation_for_method<'tcx>(
         ));
     }
 
+    // The return type of an `async fn` or an `impl Trait` return type is an anonymous associated
+    // type, which trait objects cannot specify.
+    if tcx
+        .associated_items(trait_def_id)
+        .in_definition_order()
+        .any(|item| item.impl_trait_fn == Some(method.def_id))
+    {
+        return Some(match tcx.asyncness(method.def_id) {
+            hir::IsAsync::Async => MethodViolationCode::AsyncFn,
+            hir::IsAsync::NotAsync => MethodViolationCode::ReferencesImplTraitInTrait,
+        });
+    }
+
     for (i, input_ty) in sig.skip_binder().inputs()[1..].iter().enumerate() {
         if contains_illegal_self_type_reference(tcx, trait_def_id, input_ty) {
             return Some(MethodViolationCode::ReferencesSelfInput(i));
@@ -555,7 +569,9 @@ fn object_ty_for_trait<'tcx>(
                 .in_definition_order()
                 .map(move |item| (super_trait_ref, item))
         })
-        .filter(|(_, item)| item.kind == ty::AssocKind::Type)
+        // The anonymous associated types of `impl Trait` return types are only used by methods
+        // that are not callable on trait objects.
+        .filter(|(_, item)| item.kind == ty::AssocKind::Type && item.impl_trait_fn.is_none())
         .collect::<Vec<_>>();
 
     // existential predicates need to be in a specific order
@@ -453,12 +453,13 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             }
         }
 
+        // The anonymous associated types of `impl Trait` return types are not part of the
+        // object type, see `object_ty_for_trait`.
         let assoc_types: Vec<_> = tcx
             .associated_items(trait_predicate.def_id())
             .in_definition_order()
-            .filter_map(
-                |item| if item.kind == ty::AssocKind::Type { Some(item.def_id) } else { None },
-            )
+            .filter(|item| item.kind == ty::AssocKind::Type && item.impl_trait_fn.is_none())
+            .map(|item| item.def_id)
             .collect();
 
         for assoc_type in assoc_types {
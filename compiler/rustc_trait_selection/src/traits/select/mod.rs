@@ -198,6 +198,18 @@ struct EvaluatedCandidate<'tcx> {
     evaluation: EvaluationResult,
 }
 
+/// A candidate assembled to prove an obligation, with the outcome of its confirmation. This
+/// is used by `-Z explain-obligations` to tell why each candidate was rejected.
+#[derive(Debug)]
+crate struct ExplainedCandidate<'tcx> {
+    crate candidate: SelectionCandidate<'tcx>,
+    crate evaluation: EvaluationResult,
+    /// The obligations required by the candidate, with whether each of them may hold, or
+    /// `None` if the candidate couldn't be confirmed. They are resolved as far as possible,
+    /// but may still refer to the inference variables of the probe they were created in.
+    crate nested: Option<Vec<(PredicateObligation<'tcx>, bool)>>,
+}
+
 /// When does the builtin impl for `T: Trait` apply?
 enum BuiltinImplConditions<'tcx> {
     /// The impl is conditional on `T1, T2, ...: Trait`.
@@ -403,6 +415,40 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         })
     }
 
+    /// Assembles the candidates to prove `obligation`, like selection does, and confirms each
+    /// of them in a probe, without winnowing them. Returns whether the candidate set is
+    /// ambiguous, as no candidates are assembled when the self type isn't known yet.
+    crate fn explain_candidates(
+        &mut self,
+        obligation: &TraitObligation<'tcx>,
+    ) -> Result<(Vec<ExplainedCandidate<'tcx>>, bool), SelectionError<'tcx>> {
+        let pec = &ProvisionalEvaluationCache::default();
+        let stack = self.push_stack(TraitObligationStackList::empty(pec), obligation);
+        let candidate_set = self.infcx.probe(|_| self.assemble_candidates(&stack))?;
+
+        let mut candidates = Vec::with_capacity(candidate_set.vec.len());
+        for candidate in candidate_set.vec {
+            let evaluation =
+                self.evaluate_candidate(&stack, &candidate).unwrap_or(EvaluatedToRecur);
+            let nested = self.infcx.probe(|_| {
+                let selection = self.confirm_candidate(obligation, candidate.clone()).ok()?;
+                let nested = selection
+                    .nested_obligations()
+                    .into_iter()
+                    .map(|nested| {
+                        let may_hold = self
+                            .evaluate_root_obligation(&nested)
+                            .map_or(true, |evaluation| evaluation.may_apply());
+                        (self.infcx.resolve_vars_if_possible(&nested), may_hold)
+                    })
+                    .collect();
+                Some(nested)
+            });
+            candidates.push(ExplainedCandidate { candidate, evaluation, nested });
+        }
+        Ok((candidates, candidate_set.ambiguous))
+    }
+
     /// Evaluates the predicates in `predicates` recursively. Note that
     /// this applies projections in the predicates, and therefore
     /// is run within an inference probe.
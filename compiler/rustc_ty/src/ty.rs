@@ -3,7 +3,7 @@ use rustc_data_structures::svh::Svh;
 use rustc_hir as hir;
 use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, LOCAL_CRATE};
 use rustc_middle::hir::map as hir_map;
-use rustc_middle::ty::subst::Subst;
+use rustc_middle::ty::subst::{InternalSubsts, Subst};
 use rustc_middle::ty::{
     self, Binder, Predicate, PredicateAtom, PredicateKind, ToPredicate, Ty, TyCtxt, WithConstness,
 };
@@ -97,6 +97,9 @@ fn associated_item_from_trait_item_ref(
         def_id: def_id.to_def_id(),
         container: ty::TraitContainer(parent_def_id.to_def_id()),
         fn_has_self_parameter: has_self,
+        impl_trait_fn: trait_item_ref
+            .impl_trait_fn
+            .map(|id| tcx.hir().local_def_id(id.hir_id).to_def_id()),
     }
 }
 
@@ -120,6 +123,9 @@ fn associated_item_from_impl_item_ref(
         def_id: def_id.to_def_id(),
         container: ty::ImplContainer(parent_def_id.to_def_id()),
         fn_has_self_parameter: has_self,
+        impl_trait_fn: impl_item_ref
+            .impl_trait_fn
+            .map(|id| tcx.hir().local_def_id(id.hir_id).to_def_id()),
     }
 }
 
@@ -242,6 +248,10 @@ fn param_env(tcx: TyCtxt<'_>, def_id: DefId) -> ty::ParamEnv<'_> {
     let ty::InstantiatedPredicates { mut predicates, .. } =
         tcx.predicates_of(def_id).instantiate_identity(tcx);
 
+    // A provided trait method defines the anonymous associated type of its `impl Trait` return
+    // type, so its body may rely on the default of that type.
+    predicates.extend(impl_trait_in_trait_default(tcx, def_id));
+
     // Finally, we have to normalize the bounds in the environment, in
     // case they contain any associated type projections. This process
     // can yield errors if the put in illegal associated types, like
@@ -272,6 +282,42 @@ fn param_env(tcx: TyCtxt<'_>, def_id: DefId) -> ty::ParamEnv<'_> {
     traits::normalize_param_env_or_error(tcx, def_id, unnormalized_env, cause)
 }
 
+/// If `def_id` is a provided trait method returning `impl Trait`, returns
+/// `for<'a..> <Self as Trait>::foo::{opaque}<'a..> == T`, where `T` is the default of the
+/// anonymous associated type, i.e. the opaque return type of the method.
+fn impl_trait_in_trait_default<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+) -> Option<Predicate<'tcx>> {
+    let item = tcx.opt_associated_item(def_id)?;
+    let trait_def_id = match item.container {
+        ty::TraitContainer(trait_def_id) if item.defaultness.has_value() => trait_def_id,
+        _ => return None,
+    };
+    let assoc_ty = tcx
+        .associated_items(trait_def_id)
+        .in_definition_order()
+        .find(|assoc_ty| assoc_ty.impl_trait_fn == Some(def_id))?;
+    if !assoc_ty.defaultness.has_value() {
+        return None;
+    }
+
+    // The associated type is generic over the lifetimes captured by the `impl Trait`, which
+    // may be late-bound in the method, so the predicate is bound over all of them.
+    let generics = tcx.generics_of(assoc_ty.def_id);
+    let substs = InternalSubsts::for_item(tcx, assoc_ty.def_id, |param, _| {
+        match (param.index as usize).checked_sub(generics.parent_count) {
+            Some(index) => {
+                tcx.mk_region(ty::ReLateBound(ty::INNERMOST, ty::BrAnon(index as u32))).into()
+            }
+            None => tcx.mk_param_from_def(param),
+        }
+    });
+    let projection_ty = ty::ProjectionTy { substs, item_def_id: assoc_ty.def_id };
+    let ty = tcx.type_of(assoc_ty.def_id).subst(tcx, substs);
+    Some(Binder::bind(ty::ProjectionPredicate { projection_ty, ty }).to_predicate(tcx))
+}
+
 /// Elaborate the environment.
 ///
 /// Collect a list of `Predicate`'s used for building the `ParamEnv`. Adds `TypeWellFormedFromEnv`'s
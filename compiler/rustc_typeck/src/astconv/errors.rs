@@ -174,9 +174,8 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
         let all_candidate_names: Vec<_> = all_candidates()
             .map(|r| self.tcx().associated_items(r.def_id()).in_definition_order())
             .flatten()
-            .filter_map(
-                |item| if item.kind == ty::AssocKind::Type { Some(item.ident.name) } else { None },
-            )
+            .filter(|item| item.kind == ty::AssocKind::Type && item.impl_trait_fn.is_none())
+            .map(|item| item.ident.name)
             .collect();
 
         if let (Some(suggested_name), true) = (
@@ -337,6 +337,13 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
             false
         };
 
+        // The anonymous associated type of an `impl Trait` return type in a trait is nested in its
+        // method, whose parameters are always the ones in scope, see `generics_of`.
+        let impl_trait_fn_generics = tcx
+            .opt_associated_item(def_id)
+            .and_then(|item| item.impl_trait_fn)
+            .map(|fn_def_id| tcx.generics_of(fn_def_id));
+
         let mut missing_type_params = vec![];
         let mut inferred_params = vec![];
         let substs = Self::create_substs_for_generic_args(
@@ -382,7 +389,7 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                         self.ast_ty_to_ty(&ty).into()
                     }
                 }
-                (GenericParamDefKind::Const, GenericArg::Const(ct)) => {
+                (GenericParamDefKind::Const { .. }, GenericArg::Const(ct)) => {
                     ty::Const::from_opt_const_arg_anon_const(
                         tcx,
                         ty::WithOptConstParam {
@@ -396,6 +403,12 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
             },
             // Provide substitutions for parameters for which arguments are inferred.
             |substs, param, infer_args| {
+                if let Some(fn_generics) = impl_trait_fn_generics {
+                    let index = param.index as usize;
+                    if fn_generics.parent_count <= index && index < fn_generics.count() {
+                        return tcx.mk_param_from_def(param);
+                    }
+                }
                 match param.kind {
                     GenericParamDefKind::Lifetime => tcx.lifetimes.re_static.into(),
                     GenericParamDefKind::Type { has_default, .. } => {
@@ -432,10 +445,14 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                             tcx.ty_error().into()
                         }
                     }
-                    GenericParamDefKind::Const => {
+                    GenericParamDefKind::Const { has_default } => {
                         let ty = tcx.at(span).type_of(param.def_id);
-                        // FIXME(const_generics:defaults)
-                        if infer_args {
+                        if !infer_args && has_default {
+                            // No const parameter provided, but a default exists.
+                            tcx.const_param_default(param.def_id)
+                                .subst_spanned(tcx, substs.unwrap(), Some(span))
+                                .into()
+                        } else if infer_args {
                             // No const parameters were provided, we can infer all.
                             self.ct_infer(ty, Some(param), span).into()
                         } else {
@@ -1102,7 +1119,10 @@ impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
                         associated_types.entry(span).or_default().extend(
                             tcx.associated_items(pred.def_id())
                                 .in_definition_order()
-                                .filter(|item| item.kind == ty::AssocKind::Type)
+                                .filter(|item| {
+                                    item.kind == ty::AssocKind::Type
+                                        && item.impl_trait_fn.is_none()
+                                })
                                 .map(|item| item.def_id),
                         );
                     }
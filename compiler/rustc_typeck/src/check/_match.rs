@@ -45,6 +45,10 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             self.demand_scrutinee_type(arms, scrut)
         };
 
+        if let TryDesugar = match_src {
+            self.record_try_block_question_mark(expr, scrut, arms);
+        }
+
         // If there are no arms, that is a diverging match; a special case.
         if arms.is_empty() {
             self.diverges.set(self.diverges.get() | Diverges::always(expr.span));
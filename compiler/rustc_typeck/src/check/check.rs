@@ -968,7 +968,17 @@ pub(super) fn check_impl_items_against_trait<'tcx>(
                 .unwrap_or(false);
 
             if !is_implemented && tcx.impl_defaultness(impl_id).is_final() {
-                if !trait_item.defaultness.has_value() {
+                if let Some(trait_fn) = trait_item.impl_trait_fn {
+                    // The anonymous associated type of an `impl Trait` return type is only
+                    // missing if the method does not return `impl Trait` either.
+                    let fn_ident = tcx.associated_item(trait_fn).ident;
+                    let impl_fn = ancestors
+                        .leaf_def(tcx, fn_ident, ty::AssocKind::Fn)
+                        .filter(|node_item| !node_item.defining_node.is_from_trait());
+                    if let Some(impl_fn) = impl_fn {
+                        report_missing_impl_trait_in_trait(tcx, trait_item, &impl_fn.item);
+                    }
+                } else if !trait_item.defaultness.has_value() {
                     missing_items.push(*trait_item);
                 }
             }
@@ -980,6 +990,27 @@ pub(super) fn check_impl_items_against_trait<'tcx>(
     }
 }
 
+/// Reports an implementation of a trait method returning `impl Trait` (or of an `async fn`)
+/// that returns some other type.
+fn report_missing_impl_trait_in_trait(
+    tcx: TyCtxt<'_>,
+    trait_ty: &ty::AssocItem,
+    impl_fn: &ty::AssocItem,
+) {
+    let impl_fn_span = tcx.sess.source_map().guess_head_span(tcx.def_span(impl_fn.def_id));
+    let mut err = tcx.sess.struct_span_err(
+        impl_fn_span,
+        &format!(
+            "method `{}` must return `impl Trait` to match its trait declaration",
+            impl_fn.ident
+        ),
+    );
+    if let Some(trait_ty_span) = tcx.hir().span_if_local(trait_ty.def_id) {
+        err.span_label(trait_ty_span, "return type declared here");
+    }
+    err.emit();
+}
+
 /// Checks whether a type can be represented in memory. In particular, it
 /// identifies types that contain themselves without indirection through a
 /// pointer, which would mean their size is unbounded.
@@ -13,6 +13,7 @@ use rustc_middle::ty::fold::TypeFoldable;
 use rustc_middle::ty::subst::InternalSubsts;
 use rustc_middle::ty::{self, Ty};
 use rustc_span::source_map::Span;
+use rustc_span::DUMMY_SP;
 use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::traits::error_reporting::ArgKind;
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt as _;
@@ -167,9 +168,8 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     let pb = pb.with_self_ty(self.tcx, self.tcx.types.trait_object_dummy_self);
                     self.deduce_sig_from_projection(None, pb)
                 });
-                let kind = object_type
-                    .principal_def_id()
-                    .and_then(|did| self.tcx.fn_trait_kind_from_lang_item(did));
+                let kind =
+                    object_type.principal_def_id().and_then(|did| self.closure_kind_of_trait(did));
                 (sig, kind)
             }
             ty::Infer(ty::TyVar(vid)) => self.deduce_expectations_from_obligations(vid),
@@ -213,15 +213,27 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         // many viable options, so pick the most restrictive.
         let expected_kind = self
             .obligations_for_self_ty(expected_vid)
-            .filter_map(|(tr, _)| self.tcx.fn_trait_kind_from_lang_item(tr.def_id()))
+            .filter_map(|(tr, _)| self.closure_kind_of_trait(tr.def_id()))
             .fold(None, |best, cur| Some(best.map_or(cur, |best| cmp::min(best, cur))));
 
         (expected_sig, expected_kind)
     }
 
+    /// Returns the closure kind required by a `Fn`-family or `AsyncFn`-family trait. As the
+    /// `AsyncFn` traits are implemented for closures returning futures through the `Fn` trait
+    /// of the same kind, both deduce the same closure kind.
+    fn closure_kind_of_trait(&self, trait_def_id: DefId) -> Option<ty::ClosureKind> {
+        self.tcx
+            .fn_trait_kind_from_lang_item(trait_def_id)
+            .or_else(|| self.tcx.async_fn_trait_kind_from_lang_item(trait_def_id))
+    }
+
     /// Given a projection like "<F as Fn(X)>::Result == Y", we can deduce
     /// everything we need to know about a closure or generator.
     ///
+    /// A projection like "<F as AsyncFn(X)>::Output == Y" gives us the inputs
+    /// of a closure, but only the output type of the future it returns.
+    ///
     /// The `cause_span` should be the span that caused us to
     /// have this expected signature, or `None` if we can't readily
     /// know that.
@@ -239,8 +251,10 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         let is_fn = tcx.fn_trait_kind_from_lang_item(trait_ref.def_id()).is_some();
         let gen_trait = tcx.require_lang_item(LangItem::Generator, cause_span);
         let is_gen = gen_trait == trait_ref.def_id();
-        if !is_fn && !is_gen {
-            debug!("deduce_sig_from_projection: not fn or generator");
+        let is_async_fn =
+            tcx.lang_items().async_fn_once_output() == Some(projection.projection_def_id());
+        if !is_fn && !is_gen && !is_async_fn {
+            debug!("deduce_sig_from_projection: not fn, async fn or generator");
             return None;
         }
 
@@ -255,7 +269,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             }
         }
 
-        let input_tys = if is_fn {
+        let input_tys = if is_fn || is_async_fn {
             let arg_param_ty = trait_ref.skip_binder().substs.type_at(1);
             let arg_param_ty = self.resolve_vars_if_possible(&arg_param_ty);
             debug!("deduce_sig_from_projection: arg_param_ty={:?}", arg_param_ty);
@@ -270,8 +284,17 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             return None;
         };
 
-        let ret_param_ty = projection.skip_binder().ty;
-        let ret_param_ty = self.resolve_vars_if_possible(&ret_param_ty);
+        let ret_param_ty = if is_async_fn {
+            // The closure returns some future resolving to the projected type,
+            // which is only known once the closure body was type checked.
+            self.next_ty_var(TypeVariableOrigin {
+                kind: TypeVariableOriginKind::TypeInference,
+                span: cause_span.unwrap_or(DUMMY_SP),
+            })
+        } else {
+            let ret_param_ty = projection.skip_binder().ty;
+            self.resolve_vars_if_possible(&ret_param_ty)
+        };
         debug!("deduce_sig_from_projection: ret_param_ty={:?}", ret_param_ty);
 
         let sig = self.tcx.mk_fn_sig(
@@ -362,6 +362,35 @@ fn check_region_bounds_on_impl_item<'tcx>(
     // are zero. Since I don't quite know how to phrase things at
     // the moment, give a kind of vague error message.
     if trait_params != impl_params {
+        if let Some(impl_fn) = impl_m.impl_trait_fn {
+            // The anonymous associated type of an `impl Trait` return type has a lifetime
+            // parameter for every lifetime it captures, which users never write themselves.
+            let mut err = struct_span_err!(
+                tcx.sess,
+                span,
+                E0195,
+                "return type of method `{}` captures different lifetimes than in the trait \
+                 declaration",
+                tcx.item_name(impl_fn),
+            );
+            err.span_label(
+                span,
+                format!("captures {} lifetime{}", impl_params, pluralize!(impl_params)),
+            );
+            if let Some(trait_span) = tcx.hir().span_if_local(trait_m.def_id) {
+                err.span_label(
+                    trait_span,
+                    format!("captures {} lifetime{}", trait_params, pluralize!(trait_params)),
+                );
+            }
+            err.note(
+                "`impl Trait` captures the lifetimes appearing in its bounds, and the return type \
+                 of an `async fn` captures all lifetimes of its arguments",
+            );
+            err.emit();
+            return Err(ErrorReported);
+        }
+
         let item_kind = assoc_item_kind_str(impl_m);
         let def_span = tcx.sess.source_map().guess_head_span(span);
         let span = tcx.hir().get_generics(impl_m.def_id).map(|g| g.span).unwrap_or(def_span);
@@ -794,11 +823,11 @@ fn compare_synthetic_generics<'tcx>(
     let trait_m_generics = tcx.generics_of(trait_m.def_id);
     let impl_m_type_params = impl_m_generics.params.iter().filter_map(|param| match param.kind {
         GenericParamDefKind::Type { synthetic, .. } => Some((param.def_id, synthetic)),
-        GenericParamDefKind::Lifetime | GenericParamDefKind::Const => None,
+        GenericParamDefKind::Lifetime | GenericParamDefKind::Const { .. } => None,
     });
     let trait_m_type_params = trait_m_generics.params.iter().filter_map(|param| match param.kind {
         GenericParamDefKind::Type { synthetic, .. } => Some((param.def_id, synthetic)),
-        GenericParamDefKind::Lifetime | GenericParamDefKind::Const => None,
+        GenericParamDefKind::Lifetime | GenericParamDefKind::Const { .. } => None,
     });
     for ((impl_def_id, impl_synthetic), (trait_def_id, trait_synthetic)) in
         impl_m_type_params.zip(trait_m_type_params)
@@ -6,6 +6,7 @@ use crate::astconv::AstConv as _;
 use crate::check::cast;
 use crate::check::coercion::CoerceMany;
 use crate::check::fatally_break_rust;
+use crate::check::gather_locals::GatherLocalsVisitor;
 use crate::check::method::{probe, MethodError, SelfSource};
 use crate::check::report_unexpected_variant_res;
 use crate::check::BreakableCtxt;
@@ -30,6 +31,7 @@ use rustc_errors::{pluralize, struct_span_err, Applicability, DiagnosticBuilder,
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::Visitor;
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{ExprKind, QPath};
 use rustc_infer::infer;
@@ -285,8 +287,11 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                 ty
             }
             ExprKind::DropTemps(ref e) => self.check_expr_with_expectation(e, expected),
+            ExprKind::Let(ref pat, ref init) => self.check_expr_let(pat, init),
             ExprKind::Array(ref args) => self.check_expr_array(args, expected, expr),
-            ExprKind::ConstBlock(ref anon_const) => self.to_const(anon_const).ty,
+            ExprKind::ConstBlock(ref anon_const) => {
+                self.check_expr_const_block(anon_const, expected)
+            }
             ExprKind::Repeat(ref element, ref count) => {
                 self.check_expr_repeat(element, count, expected, expr)
             }
@@ -822,6 +827,18 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Checks a `let` clause of a `let` chain as a single-armed `match` on `init`.
+    fn check_expr_let(&self, pat: &'tcx hir::Pat<'tcx>, init: &'tcx hir::Expr<'tcx>) -> Ty<'tcx> {
+        // As in `demand_scrutinee_type`, explicit `ref mut` bindings need a mutable place.
+        let init_ty = if let Some(m) = pat.contains_explicit_ref_binding() {
+            self.check_expr_with_needs(init, Needs::maybe_mut_place(m))
+        } else {
+            self.check_expr(init)
+        };
+        self.check_pat_top(pat, init_ty, Some(init.span), true);
+        self.tcx.types.bool
+    }
+
     fn check_expr_loop(
         &self,
         body: &'tcx hir::Block<'tcx>,
@@ -1025,6 +1042,27 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         self.tcx.mk_array(element_ty, args.len() as u64)
     }
 
+    /// Inline constants are type checked as part of their enclosing body, like closures,
+    /// so that their types may be inferred from their uses, e.g. the element type of
+    /// `[const { Vec::new() }; N]`. See `typeck_with_fallback`.
+    fn check_expr_const_block(
+        &self,
+        anon_const: &'tcx hir::AnonConst,
+        expected: Expectation<'tcx>,
+    ) -> Ty<'tcx> {
+        let body = self.tcx.hir().body(anon_const.body);
+
+        // Create a new function context, as the body of the inline constant
+        // can't refer to the locals or the return type of the enclosing body.
+        let fcx = FnCtxt::new(self, self.param_env, body.value.hir_id);
+        GatherLocalsVisitor::new(&fcx, anon_const.hir_id).visit_body(body);
+
+        let ty = fcx.check_expr_with_expectation(&body.value, expected);
+        fcx.require_type_is_sized(ty, body.value.span, traits::ConstSized);
+        fcx.write_ty(anon_const.hir_id, ty);
+        ty
+    }
+
     fn check_expr_repeat(
         &self,
         element: &'tcx hir::Expr<'tcx>,
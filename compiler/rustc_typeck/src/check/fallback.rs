@@ -0,0 +1,223 @@
+use crate::check::{FallbackMode, FnCtxt};
+use rustc_errors::{struct_span_err, DiagnosticBuilder};
+use rustc_hir as hir;
+use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span};
+use std::iter;
+
+impl<'tcx> FnCtxt<'_, 'tcx> {
+    /// Performs type inference fallback, replacing the inference variables which
+    /// remain unconstrained once all type checking constraints were added.
+    pub(super) fn type_inference_fallback(&self) {
+        // All type checking constraints were added, try to fallback unsolved variables.
+        self.select_obligations_where_possible(false, |_| {});
+        let mut fallback_has_occurred = false;
+
+        // Guess the output types of `try` blocks first: this may constrain other
+        // variables, e.g. integer variables flowing into the error of a `Result`,
+        // which would otherwise be replaced with their defaults below.
+        if self.fallback_try_blocks() {
+            self.select_obligations_where_possible(false, |_| {});
+        }
+
+        // We do fallback in two passes, to try to generate
+        // better error messages.
+        // The first time, we do *not* replace opaque types.
+        for ty in &self.unsolved_variables() {
+            fallback_has_occurred |= self.fallback_if_possible(ty, FallbackMode::NoOpaque);
+        }
+        // We now see if we can make progress. This might
+        // cause us to unify inference variables for opaque types,
+        // since we may have unified some other type variables
+        // during the first phase of fallback.
+        // This means that we only replace inference variables with their underlying
+        // opaque types as a last resort.
+        //
+        // In code like this:
+        //
+        // ```rust
+        // type MyType = impl Copy;
+        // fn produce() -> MyType { true }
+        // fn bad_produce() -> MyType { panic!() }
+        // ```
+        //
+        // we want to unify the opaque inference variable in `bad_produce`
+        // with the diverging fallback for `panic!` (e.g. `()` or `!`).
+        // This will produce a nice error message about conflicting concrete
+        // types for `MyType`.
+        //
+        // If we had tried to fallback the opaque inference variable to `MyType`,
+        // we will generate a confusing type-check error that does not explicitly
+        // refer to opaque types.
+        self.select_obligations_where_possible(fallback_has_occurred, |_| {});
+
+        // We now run fallback again, but this time we allow it to replace
+        // unconstrained opaque type variables, in addition to performing
+        // other kinds of fallback.
+        for ty in &self.unsolved_variables() {
+            fallback_has_occurred |= self.fallback_if_possible(ty, FallbackMode::All);
+        }
+
+        // See if we can make any more progress.
+        self.select_obligations_where_possible(fallback_has_occurred, |_| {});
+    }
+
+    /// Records the operand of a `?` propagating its error to an enclosing `try` block,
+    /// which is used to guide inference of the block's output type if nothing else
+    /// constrains it. Only the first `?` of each `try` block is recorded.
+    ///
+    /// `<expr>?` is desugared into
+    /// `match Try::into_result(<expr>) { Err(err) => break 'catch .., Ok(val) => val }`,
+    /// where `'catch` is the `try` block, see `lower_expr_try` in `rustc_ast_lowering`.
+    pub(super) fn record_try_block_question_mark(
+        &self,
+        expr: &'tcx hir::Expr<'tcx>,
+        scrut: &'tcx hir::Expr<'tcx>,
+        arms: &'tcx [hir::Arm<'tcx>],
+    ) {
+        let operand = match scrut.kind {
+            hir::ExprKind::Call(_, [operand]) => operand,
+            _ => return,
+        };
+        let block_id = arms.iter().find_map(|arm| match arm.body.kind {
+            hir::ExprKind::Break(hir::Destination { target_id: Ok(target_id), .. }, _) => {
+                Some(target_id)
+            }
+            _ => None,
+        });
+        if let Some(block_id) = block_id {
+            let mut question_marks = self.try_block_question_marks.borrow_mut();
+            if question_marks.iter().all(|&(id, ..)| id != block_id) {
+                question_marks.push((block_id, self.node_ty(operand.hir_id), expr.span));
+            }
+        }
+    }
+
+    /// Returns the first `?` of the `try` block whose output type is the unresolved type `ty`:
+    /// the `HirId` of the block, the type of the operand of the `?` and the span of the `?`
+    /// expression. Returns `None` if `ty` isn't the output type of such a `try` block.
+    fn try_block_question_mark(&self, ty: Ty<'tcx>) -> Option<(hir::HirId, Ty<'tcx>, Span)> {
+        let vid = match *ty.kind() {
+            ty::Infer(ty::TyVar(vid)) => self.root_var(vid),
+            _ => return None,
+        };
+        self.try_block_question_marks.borrow().iter().copied().find(|&(block_id, ..)| {
+            let block_ty = self.typeck_results.borrow().node_types().get(block_id).copied();
+            match block_ty.map(|ty| *self.resolve_vars_if_possible(ty).kind()) {
+                Some(ty::Infer(ty::TyVar(block_vid))) => self.root_var(block_vid) == vid,
+                _ => false,
+            }
+        })
+    }
+
+    /// Guesses the output type of a `try` block from the type of the operand of its first `?`.
+    ///
+    /// If the operand is a `Result<T, E>` or an `Option<T>`, the block is assumed to produce
+    /// the same kind of value, with its output `T` left to be inferred from the tail expression
+    /// of the block. E.g., a `try` block containing `x?` with `x: Result<u8, E>` becomes a
+    /// `Result<_, E>`. Other `Try` types don't tell which of their parameters is the output,
+    /// so there is no guess for them.
+    fn try_block_output_guess(&self, operand_ty: Ty<'tcx>, span: Span) -> Option<Ty<'tcx>> {
+        match *self.resolve_vars_if_possible(operand_ty).kind() {
+            ty::Adt(def, substs)
+                if self.tcx.is_diagnostic_item(sym::result_type, def.did)
+                    || self.tcx.is_diagnostic_item(sym::option_type, def.did) =>
+            {
+                let origin =
+                    TypeVariableOrigin { kind: TypeVariableOriginKind::TypeInference, span };
+                let output = self.next_ty_var(origin);
+                let substs =
+                    self.tcx.mk_substs(iter::once(output.into()).chain(substs.iter().skip(1)));
+                Some(self.tcx.mk_adt(def, substs))
+            }
+            _ => None,
+        }
+    }
+
+    /// Builds the error at `span` asking for the output type of a `try` block to be annotated,
+    /// pointing at the span `question_mark` of its first `?` and suggesting `guess` as the
+    /// annotation if there is one.
+    fn struct_try_block_annotation_err(
+        &self,
+        span: Span,
+        question_mark: Span,
+        guess: Option<Ty<'tcx>>,
+    ) -> DiagnosticBuilder<'tcx> {
+        let mut err =
+            struct_span_err!(self.tcx.sess, span, E0282, "type annotations needed for `try` block");
+        err.span_label(
+            self.tcx.sess.source_map().end_point(question_mark),
+            "cannot infer the type of the `try` block from this `?`",
+        );
+        let annotation = match guess {
+            Some(guess) => guess.to_string(),
+            None => "Result<_, _>".to_string(),
+        };
+        err.help(&format!(
+            "consider annotating the output type of the `try` block, e.g. `try -> {} {{ .. }}`",
+            annotation,
+        ));
+        err
+    }
+
+    /// Tries to constrain the unresolved output type `ty` of a `try` block, using the first `?`
+    /// in that block as described in `try_block_output_guess`; returns `false` if `ty` isn't
+    /// the output type of such a `try` block. If there is nothing to guess from, an error
+    /// asking for an annotation of the block is emitted instead.
+    fn try_block_fallback(&self, ty: Ty<'tcx>) -> bool {
+        let (block_id, operand_ty, span) = match self.try_block_question_mark(ty) {
+            Some(question_mark) => question_mark,
+            None => return false,
+        };
+
+        let guess = match self.try_block_output_guess(operand_ty, span) {
+            Some(guess) => guess,
+            None => {
+                let operand_ty = self.resolve_vars_if_possible(operand_ty);
+                if !operand_ty.references_error() && !self.is_tainted_by_errors() {
+                    let block_span = self.tcx.hir().span(block_id);
+                    self.struct_try_block_annotation_err(block_span, span, None).emit();
+                }
+                self.tcx.ty_error()
+            }
+        };
+        self.demand_eqtype(span, guess, ty);
+        true
+    }
+
+    /// Reports that the unresolved output type `ty` of a `try` block must be known at `sp`,
+    /// i.e. before `try_block_fallback` could guess it, pointing at the `?` it would be guessed
+    /// from. Returns `false`, without reporting anything, if `ty` isn't the output type of a
+    /// `try` block.
+    pub(super) fn report_unresolved_try_block(&self, sp: Span, ty: Ty<'tcx>) -> bool {
+        let (_, operand_ty, span) = match self.try_block_question_mark(ty) {
+            Some(question_mark) => question_mark,
+            None => return false,
+        };
+
+        let guess = self.try_block_output_guess(operand_ty, span);
+        let mut err = self.struct_try_block_annotation_err(sp, span, guess);
+        err.span_label(sp, "type must be known at this point");
+        err.emit();
+        true
+    }
+
+    /// Applies `try_block_fallback` to the output types of all `try` blocks
+    /// which are still unconstrained, returning whether any of them were.
+    fn fallback_try_blocks(&self) -> bool {
+        let block_ids: Vec<_> =
+            self.try_block_question_marks.borrow().iter().map(|&(block_id, ..)| block_id).collect();
+        let mut fallback_has_occurred = false;
+        for block_id in block_ids {
+            let block_ty = self.typeck_results.borrow().node_types().get(block_id).copied();
+            if let Some(block_ty) = block_ty {
+                let block_ty = self.resolve_vars_if_possible(block_ty);
+                if block_ty.is_ty_var() {
+                    fallback_has_occurred |= self.try_block_fallback(block_ty);
+                }
+            }
+        }
+        fallback_has_occurred
+    }
+}
@@ -1285,7 +1285,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     (GenericParamDefKind::Type { .. }, GenericArg::Type(ty)) => {
                         self.to_ty(ty).into()
                     }
-                    (GenericParamDefKind::Const, GenericArg::Const(ct)) => {
+                    (GenericParamDefKind::Const { .. }, GenericArg::Const(ct)) => {
                         self.const_arg_to_const(&ct.value, param.def_id).into()
                     }
                     _ => unreachable!(),
@@ -1315,10 +1315,15 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                                 self.var_for_def(span, param)
                             }
                         }
-                        GenericParamDefKind::Const => {
-                            // FIXME(const_generics:defaults)
-                            // No const parameters were provided, we have to infer them.
-                            self.var_for_def(span, param)
+                        GenericParamDefKind::Const { has_default } => {
+                            if !infer_args && has_default {
+                                tcx.const_param_default(param.def_id)
+                                    .subst_spanned(tcx, substs.unwrap(), Some(span))
+                                    .into()
+                            } else {
+                                // No const parameters were provided, we have to infer them.
+                                self.var_for_def(span, param)
+                            }
                         }
                     }
                 },
@@ -1395,7 +1400,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         if !ty.is_ty_var() {
             ty
         } else {
-            if !self.is_tainted_by_errors() {
+            if !self.is_tainted_by_errors() && !self.report_unresolved_try_block(sp, ty) {
                 self.emit_inference_failure_err((**self).body_id, sp, ty.into(), E0282)
                     .note("type must be known at this point")
                     .emit();
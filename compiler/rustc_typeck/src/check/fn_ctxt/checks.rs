@@ -536,6 +536,31 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         self.check_pat_top(&local.pat, ty, ty_span, origin_expr);
         let pat_ty = self.node_ty(local.pat.hir_id);
         self.overwrite_local_ty_if_err(local, ty, pat_ty);
+
+        if let Some(els) = local.els {
+            self.check_let_else_block(els);
+        }
+    }
+
+    /// Checks the `else` block of a `let...else` statement, which must diverge. Whether the
+    /// statement itself diverges only depends on its initializer.
+    fn check_let_else_block(&self, els: &'tcx hir::Block<'tcx>) {
+        let prev_diverges = self.diverges.replace(Diverges::Maybe);
+        let els_ty = self.check_block_with_expected(els, NoExpectation);
+        if !self.diverges.get().is_always() && !els_ty.references_error() {
+            let span = els.expr.map_or(els.span, |e| e.span);
+            struct_span_err!(
+                self.tcx.sess,
+                span,
+                E0308,
+                "`else` clause of `let...else` does not diverge"
+            )
+            .span_label(span, format!("expected `!`, found `{}`", els_ty))
+            .help("try adding a diverging expression, such as `return` or `panic!(..)`")
+            .help("or use `match` instead of `let...else`")
+            .emit();
+        }
+        self.diverges.set(prev_diverges);
     }
 
     pub fn check_stmt(&self, stmt: &'tcx hir::Stmt<'tcx>) {
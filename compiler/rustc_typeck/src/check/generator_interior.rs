@@ -12,7 +12,10 @@ use rustc_hir::hir_id::HirIdSet;
 use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_hir::{Arm, Expr, ExprKind, Guard, HirId, Pat, PatKind};
 use rustc_middle::middle::region::{self, YieldData};
-use rustc_middle::ty::{self, Ty};
+use rustc_errors::pluralize;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::lint::builtin::MUST_NOT_SUSPEND;
+use rustc_span::symbol::sym;
 use rustc_span::Span;
 use smallvec::SmallVec;
 
@@ -30,12 +33,15 @@ struct InteriorVisitor<'a, 'tcx> {
     /// that they may succeed the said yield point in the post-order.
     guard_bindings: SmallVec<[SmallVec<[HirId; 4]>; 1]>,
     guard_bindings_set: HirIdSet,
+    /// The expressions and bindings for which `must_not_suspend` was already emitted.
+    linted_values: HirIdSet,
 }
 
 impl<'a, 'tcx> InteriorVisitor<'a, 'tcx> {
     fn record(
         &mut self,
         ty: Ty<'tcx>,
+        hir_id: HirId,
         scope: Option<region::Scope>,
         expr: Option<&'tcx Expr<'tcx>>,
         source_span: Span,
@@ -103,6 +109,21 @@ impl<'a, 'tcx> InteriorVisitor<'a, 'tcx> {
                     .span_note(yield_data.span, &*note)
                     .emit();
             } else {
+                // Values without a scope are part of the generator from its start, and are
+                // not held across a particular suspend point.
+                if scope.is_some() && !self.linted_values.contains(&hir_id) {
+                    let data = SuspendCheckData {
+                        source_span,
+                        yield_span: yield_data.span,
+                        descr_pre: "",
+                        descr_post: "",
+                        plural_len: 1,
+                    };
+                    if check_must_not_suspend_ty(self.fcx, ty, hir_id, data) {
+                        self.linted_values.insert(hir_id);
+                    }
+                }
+
                 // Insert the type into the ordered set.
                 let scope_span = scope.map(|s| s.span(self.fcx.tcx, self.region_scope_tree));
                 self.types.insert(ty::GeneratorInteriorTypeCause {
@@ -151,6 +172,7 @@ pub fn resolve_interior<'a, 'tcx>(
         prev_unresolved_span: None,
         guard_bindings: <_>::default(),
         guard_bindings_set: <_>::default(),
+        linted_values: <_>::default(),
     };
     intravisit::walk_body(&mut visitor, body);
 
@@ -264,7 +286,7 @@ impl<'a, 'tcx> Visitor<'tcx> for InteriorVisitor<'a, 'tcx> {
         if let PatKind::Binding(..) = pat.kind {
             let scope = self.region_scope_tree.var_scope(pat.hir_id.local_id);
             let ty = self.fcx.typeck_results.borrow().pat_ty(pat);
-            self.record(ty, Some(scope), None, pat.span, false);
+            self.record(ty, pat.hir_id, Some(scope), None, pat.span, false);
         }
     }
 
@@ -316,7 +338,14 @@ impl<'a, 'tcx> Visitor<'tcx> for InteriorVisitor<'a, 'tcx> {
         // If there are adjustments, then record the final type --
         // this is the actual value that is being produced.
         if let Some(adjusted_ty) = self.fcx.typeck_results.borrow().expr_ty_adjusted_opt(expr) {
-            self.record(adjusted_ty, scope, Some(expr), expr.span, guard_borrowing_from_pattern);
+            self.record(
+                adjusted_ty,
+                expr.hir_id,
+                scope,
+                Some(expr),
+                expr.span,
+                guard_borrowing_from_pattern,
+            );
         }
 
         // Also record the unadjusted type (which is the only type if
@@ -344,7 +373,14 @@ impl<'a, 'tcx> Visitor<'tcx> for InteriorVisitor<'a, 'tcx> {
         // The type table might not have information for this expression
         // if it is in a malformed scope. (#66387)
         if let Some(ty) = self.fcx.typeck_results.borrow().expr_ty_opt(expr) {
-            self.record(ty, scope, Some(expr), expr.span, guard_borrowing_from_pattern);
+            self.record(
+                ty,
+                expr.hir_id,
+                scope,
+                Some(expr),
+                expr.span,
+                guard_borrowing_from_pattern,
+            );
         } else {
             self.fcx.tcx.sess.delay_span_bug(expr.span, "no type for node");
         }
@@ -371,3 +407,119 @@ impl<'a, 'tcx> Visitor<'tcx> for ArmPatCollector<'a> {
         }
     }
 }
+
+/// A value held across a suspend point, being checked for types marked `#[must_not_suspend]`.
+#[derive(Clone, Copy)]
+struct SuspendCheckData<'a> {
+    source_span: Span,
+    yield_span: Span,
+    descr_pre: &'a str,
+    descr_post: &'a str,
+    plural_len: usize,
+}
+
+// Returns whether a `must_not_suspend` lint has been emitted for the value of type `ty`.
+fn check_must_not_suspend_ty<'tcx>(
+    fcx: &FnCtxt<'_, 'tcx>,
+    ty: Ty<'tcx>,
+    hir_id: HirId,
+    data: SuspendCheckData<'_>,
+) -> bool {
+    let plural_suffix = pluralize!(data.plural_len);
+
+    match *ty.kind() {
+        ty::Adt(..) if ty.is_box() => {
+            let descr_pre = &format!("{}boxed ", data.descr_pre);
+            let data = SuspendCheckData { descr_pre, ..data };
+            check_must_not_suspend_ty(fcx, ty.boxed_ty(), hir_id, data)
+        }
+        ty::Adt(def, _) => check_must_not_suspend_def(fcx.tcx, def.did, hir_id, data),
+        ty::Opaque(def, _) => {
+            let mut has_emitted = false;
+            for &(predicate, _) in fcx.tcx.explicit_item_bounds(def) {
+                // We only look at the `DefId`, so it is safe to skip the binder here.
+                if let ty::PredicateAtom::Trait(ref poly_trait_predicate, _) =
+                    predicate.skip_binders()
+                {
+                    let def_id = poly_trait_predicate.trait_ref.def_id;
+                    let descr_pre = &format!("{}implementer{} of ", data.descr_pre, plural_suffix);
+                    let data = SuspendCheckData { descr_pre, ..data };
+                    if check_must_not_suspend_def(fcx.tcx, def_id, hir_id, data) {
+                        has_emitted = true;
+                        break;
+                    }
+                }
+            }
+            has_emitted
+        }
+        ty::Dynamic(binder, _) => {
+            let mut has_emitted = false;
+            for predicate in binder.skip_binder().iter() {
+                if let ty::ExistentialPredicate::Trait(ref trait_ref) = predicate {
+                    let def_id = trait_ref.def_id;
+                    let descr_post = &format!(" trait object{}{}", plural_suffix, data.descr_post);
+                    let data = SuspendCheckData { descr_post, ..data };
+                    if check_must_not_suspend_def(fcx.tcx, def_id, hir_id, data) {
+                        has_emitted = true;
+                        break;
+                    }
+                }
+            }
+            has_emitted
+        }
+        ty::Tuple(ref tys) => {
+            let mut has_emitted = false;
+            for (i, ty) in tys.iter().map(|k| k.expect_ty()).enumerate() {
+                let descr_post = &format!(" in tuple element {}", i);
+                let data = SuspendCheckData { descr_post, ..data };
+                if check_must_not_suspend_ty(fcx, ty, hir_id, data) {
+                    has_emitted = true;
+                }
+            }
+            has_emitted
+        }
+        ty::Array(ty, len) => match len.try_eval_usize(fcx.tcx, fcx.param_env) {
+            // Empty arrays don't hold any value across the suspend point.
+            Some(n) if n != 0 => {
+                let descr_pre = &format!("{}array{} of ", data.descr_pre, plural_suffix);
+                let data = SuspendCheckData { descr_pre, plural_len: n as usize + 1, ..data };
+                check_must_not_suspend_ty(fcx, ty, hir_id, data)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn check_must_not_suspend_def(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    hir_id: HirId,
+    data: SuspendCheckData<'_>,
+) -> bool {
+    for attr in tcx.get_attrs(def_id).iter() {
+        if tcx.sess.check_name(attr, sym::must_not_suspend) {
+            tcx.struct_span_lint_hir(MUST_NOT_SUSPEND, hir_id, data.source_span, |lint| {
+                let msg = format!(
+                    "{}`{}`{} held across a suspend point, but should not be",
+                    data.descr_pre,
+                    tcx.def_path_str(def_id),
+                    data.descr_post,
+                );
+                let mut err = lint.build(&msg);
+                err.span_label(data.yield_span, "the value is held across this suspend point");
+                // check for #[must_not_suspend = "..."]
+                if let Some(note) = attr.value_str() {
+                    err.note(&note.as_str());
+                }
+                err.help(
+                    "consider using a block (`{ ... }`) to shrink the value's scope, ending \
+                     before the suspend point",
+                );
+                err.emit();
+            });
+            return true;
+        }
+    }
+    false
+}
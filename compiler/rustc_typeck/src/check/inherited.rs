@@ -67,6 +67,11 @@ pub struct Inherited<'a, 'tcx> {
     /// opaque type.
     pub(super) opaque_types_vars: RefCell<FxHashMap<Ty<'tcx>, Ty<'tcx>>>,
 
+    /// The first `?` propagating its error to each `try` block, as the `HirId` of the
+    /// block, the type of the operand of the `?` and the span of the `?` expression.
+    /// Used to guide inference of the output types of `try` blocks, see `try_block_fallback`.
+    pub(super) try_block_question_marks: RefCell<Vec<(hir::HirId, Ty<'tcx>, Span)>>,
+
     pub(super) body_id: Option<hir::BodyId>,
 }
 
@@ -125,6 +130,7 @@ impl Inherited<'a, 'tcx> {
             deferred_generator_interiors: RefCell::new(Vec::new()),
             opaque_types: RefCell::new(Default::default()),
             opaque_types_vars: RefCell::new(Default::default()),
+            try_block_question_marks: RefCell::new(Vec::new()),
             body_id,
         }
     }
@@ -922,8 +922,19 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         let module_did = self.tcx.parent_module(self.body_id);
         let module_id = self.tcx.hir().local_def_id_to_hir_id(module_did);
         let krate = self.tcx.hir().krate();
-        let (span, found_use) = UsePlacementFinder::check(self.tcx, krate, module_id);
-        if let Some(span) = span {
+        let (span, found_use, use_spans) = UsePlacementFinder::check(self.tcx, krate, module_id);
+        if let (Some(span), [did]) = (span, &candidates[..]) {
+            // With a single candidate, the suggestion can be applied automatically, so
+            // place it among the imports of the same crate, in order, if there are any.
+            let path = with_crate_prefix(|| self.tcx.def_path_str(*did));
+            let (span, sugg) = match self.use_placement_in_group(&use_spans, &path) {
+                Some(placement) => placement,
+                // Produce an additional newline to separate the new use statement
+                // from the directly following item.
+                None => (span, format!("use {};\n{}", path, if found_use { "" } else { "\n" })),
+            };
+            err.span_suggestion(span, &msg, sugg, Applicability::MachineApplicable);
+        } else if let Some(span) = span {
             let path_strings = candidates.iter().map(|did| {
                 // Produce an additional newline to separate the new use statement
                 // from the directly following item.
@@ -959,6 +970,34 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Finds where to insert `use {path};` so that it is sorted among the `use` statements
+    /// importing from the same crate as `path`, which are often grouped together. Returns the
+    /// span to insert at and the text to insert, or `None` if there are no such statements.
+    fn use_placement_in_group(&self, use_spans: &[Span], path: &str) -> Option<(Span, String)> {
+        let sm = self.tcx.sess.source_map();
+        let root = |path: &str| path.split("::").next().unwrap_or("").to_string();
+        let path_root = root(path);
+        let mut group = use_spans.iter().filter_map(|&span| {
+            let snippet = sm.span_to_snippet(span).ok()?;
+            // Skip re-exports and the items lowered from `use` trees, which don't start
+            // a statement.
+            let imported = snippet.strip_prefix("use ")?.trim_end_matches(';').trim();
+            if root(imported) == path_root { Some((span, imported.to_string())) } else { None }
+        });
+        let (mut last, imported) = group.next()?;
+        let indent = " ".repeat(sm.span_to_margin(last).unwrap_or(0));
+        if path < imported.as_str() {
+            return Some((last.shrink_to_lo(), format!("use {};\n{}", path, indent)));
+        }
+        for (span, imported) in group {
+            if path < imported.as_str() {
+                return Some((span.shrink_to_lo(), format!("use {};\n{}", path, indent)));
+            }
+            last = span;
+        }
+        Some((last.shrink_to_hi(), format!("\n{}use {};", indent, path)))
+    }
+
     fn suggest_valid_traits(
         &self,
         err: &mut DiagnosticBuilder<'_>,
@@ -1113,6 +1152,15 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                     name = item_name,
                 )
             };
+            // A single candidate can be added automatically, but when there are several we
+            // can't know which one is wanted. A bound on a generic trait is suggested without
+            // its generic arguments, which have to be filled in by hand.
+            let applicability = match &candidates[..] {
+                [trait_info] if self.tcx.generics_of(trait_info.def_id).count() == 1 => {
+                    Applicability::MachineApplicable
+                }
+                _ => Applicability::MaybeIncorrect,
+            };
             // Obtain the span for `param` and use it for a structured suggestion.
             let mut suggested = false;
             if let (Some(ref param), Some(ref table)) =
@@ -1151,18 +1199,37 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                             } else {
                                 sp
                             };
+                            // When `T` is only bounded in the where-clause, like in
+                            // `fn foo<T>(x: T) where T: Foo`, add the bound there.
+                            let where_bound = if impl_trait || has_bounds.is_some() {
+                                None
+                            } else {
+                                self.where_clause_bound_span(id, def_id.to_def_id())
+                            };
                             let trait_def_ids: FxHashSet<DefId> = param
                                 .bounds
                                 .iter()
                                 .filter_map(|bound| Some(bound.trait_ref()?.trait_def_id()?))
                                 .collect();
-                            if !candidates.iter().any(|t| trait_def_ids.contains(&t.def_id)) {
+                            let msg = message(format!(
+                                "restrict type parameter `{}` with",
+                                param.name.ident(),
+                            ));
+                            if candidates.iter().any(|t| trait_def_ids.contains(&t.def_id)) {
+                                // The parameter is already bounded by one of the traits.
+                            } else if let Some(bound_span) = where_bound {
+                                err.span_suggestions(
+                                    bound_span.shrink_to_hi(),
+                                    &msg,
+                                    candidates.iter().map(|t| {
+                                        format!(" + {}", self.tcx.def_path_str(t.def_id))
+                                    }),
+                                    applicability,
+                                );
+                            } else {
                                 err.span_suggestions(
                                     sp,
-                                    &message(format!(
-                                        "restrict type parameter `{}` with",
-                                        param.name.ident(),
-                                    )),
+                                    &msg,
                                     candidates.iter().map(|t| {
                                         format!(
                                             "{}{} {}{}",
@@ -1172,7 +1239,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                                             if has_bounds.is_some() { " + " } else { "" },
                                         )
                                     }),
-                                    Applicability::MaybeIncorrect,
+                                    applicability,
                                 );
                             }
                             suggested = true;
@@ -1193,7 +1260,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                                 candidates.iter().map(|t| {
                                     format!("{} {}", sep, self.tcx.def_path_str(t.def_id),)
                                 }),
-                                Applicability::MaybeIncorrect,
+                                applicability,
                             );
                             suggested = true;
                         }
@@ -1239,6 +1306,29 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// Returns the span of the last bound of the where-clause predicate bounding the type
+    /// parameter `param_def_id`, declared by the generic parameter `param_id`, if any.
+    fn where_clause_bound_span(&self, param_id: hir::HirId, param_def_id: DefId) -> Option<Span> {
+        let hir = self.tcx.hir();
+        let owner = hir.local_def_id(hir.get_parent_item(param_id));
+        let generics = hir.get_generics(owner.to_def_id())?;
+        generics.where_clause.predicates.iter().rev().find_map(|predicate| match predicate {
+            hir::WherePredicate::BoundPredicate(predicate)
+                if predicate.bound_generic_params.is_empty() =>
+            {
+                match predicate.bounded_ty.kind {
+                    hir::TyKind::Path(hir::QPath::Resolved(None, path))
+                        if path.res == Res::Def(DefKind::TyParam, param_def_id) =>
+                    {
+                        predicate.bounds.last().map(|bound| bound.span())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
     /// Checks whether there is a local type somewhere in the chain of
     /// autoderefs of `rcvr_ty`.
     fn type_derefs_to_local(&self, span: Span, rcvr_ty: Ty<'tcx>, source: SelfSource<'_>) -> bool {
@@ -1383,6 +1473,8 @@ struct UsePlacementFinder<'tcx> {
     target_module: hir::HirId,
     span: Option<Span>,
     found_use: bool,
+    /// The spans of the `use` items of the target module, in order.
+    use_spans: Vec<Span>,
     tcx: TyCtxt<'tcx>,
 }
 
@@ -1391,10 +1483,16 @@ impl UsePlacementFinder<'tcx> {
         tcx: TyCtxt<'tcx>,
         krate: &'tcx hir::Crate<'tcx>,
         target_module: hir::HirId,
-    ) -> (Option<Span>, bool) {
-        let mut finder = UsePlacementFinder { target_module, span: None, found_use: false, tcx };
+    ) -> (Option<Span>, bool, Vec<Span>) {
+        let mut finder = UsePlacementFinder {
+            target_module,
+            span: None,
+            found_use: false,
+            use_spans: vec![],
+            tcx,
+        };
         intravisit::walk_crate(&mut finder, krate);
-        (finder.span, finder.found_use)
+        (finder.span, finder.found_use, finder.use_spans)
     }
 }
 
@@ -1415,14 +1513,17 @@ impl intravisit::Visitor<'tcx> for UsePlacementFinder<'tcx> {
                     // Don't suggest placing a `use` before the prelude
                     // import or other generated ones.
                     if !item.span.from_expansion() {
-                        self.span = Some(item.span.shrink_to_lo());
-                        self.found_use = true;
-                        return;
+                        if !self.found_use {
+                            self.span = Some(item.span.shrink_to_lo());
+                            self.found_use = true;
+                        }
+                        self.use_spans.push(item.span);
                     }
                 }
                 // Don't place `use` before `extern crate`...
                 hir::ItemKind::ExternCrate(_) => {}
                 // ...but do place them before the first other item.
+                _ if self.found_use => {}
                 _ => {
                     if self.span.map_or(true, |span| item.span < span) {
                         if !item.span.from_expansion() {
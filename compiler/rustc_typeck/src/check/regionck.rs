@@ -419,6 +419,34 @@ impl<'a, 'tcx> Visitor<'tcx> for RegionCtxt<'a, 'tcx> {
                 intravisit::walk_expr(self, expr);
             }
 
+            hir::ExprKind::Let(ref pat, ref init) => {
+                // see `visit_arm` and `visit_local`
+                self.constrain_bindings_in_pat(pat);
+                let init_cmt = ignore_err!(self.with_mc(|mc| mc.cat_expr(init)));
+                self.link_pattern(init_cmt, pat);
+
+                intravisit::walk_expr(self, expr);
+            }
+
+            hir::ExprKind::ConstBlock(ref anon_const) => {
+                // The body of an inline constant is type checked with the enclosing
+                // body, but it is region checked like a standalone expression.
+                let old_body_id = self.body_id;
+                let old_body_owner = self.body_owner;
+                let env_snapshot = self.outlives_environment.push_snapshot_pre_closure();
+
+                let body = self.tcx.hir().body(anon_const.body);
+                self.body_id = body.value.hir_id;
+                self.body_owner = self.tcx.hir().body_owner_def_id(body.id());
+                self.outlives_environment.save_implied_bounds(self.body_id);
+                self.visit_body(body);
+                self.visit_region_obligations(self.body_id);
+
+                self.outlives_environment.pop_snapshot_post_closure(env_snapshot);
+                self.body_id = old_body_id;
+                self.body_owner = old_body_owner;
+            }
+
             _ => intravisit::walk_expr(self, expr),
         }
     }
@@ -267,6 +267,12 @@ impl<'cx, 'tcx> Visitor<'tcx> for WritebackCx<'cx, 'tcx> {
 
                 self.visit_body(body);
             }
+            hir::ExprKind::ConstBlock(ref anon_const) => {
+                self.visit_node_id(e.span, anon_const.hir_id);
+
+                let body = self.fcx.tcx.hir().body(anon_const.body);
+                self.visit_body(body);
+            }
             hir::ExprKind::Struct(_, fields, _) => {
                 for field in fields {
                     self.visit_field_id(field.hir_id);
@@ -69,6 +69,7 @@ pub fn provide(providers: &mut Providers) {
     *providers = Providers {
         opt_const_param_of: type_of::opt_const_param_of,
         type_of: type_of::type_of,
+        const_param_default: type_of::const_param_default,
         item_bounds: item_bounds::item_bounds,
         explicit_item_bounds: item_bounds::explicit_item_bounds,
         generics_of,
@@ -223,10 +224,12 @@ impl Visitor<'tcx> for CollectItemTypesVisitor<'tcx> {
                     self.tcx.ensure().type_of(def_id);
                 }
                 hir::GenericParamKind::Type { .. } => {}
-                hir::GenericParamKind::Const { .. } => {
+                hir::GenericParamKind::Const { ref default, .. } => {
                     let def_id = self.tcx.hir().local_def_id(param.hir_id);
                     self.tcx.ensure().type_of(def_id);
-                    // FIXME(const_generics:defaults)
+                    if default.is_some() {
+                        self.tcx.ensure().const_param_default(def_id);
+                    }
                 }
             }
         }
@@ -1215,6 +1218,14 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
 
     let node = tcx.hir().get(hir_id);
     let parent_def_id = match node {
+        // The anonymous associated type of an `impl Trait` return type in a trait can use the
+        // generic parameters of its method, so it is nested in the method.
+        Node::ImplItem(ImplItem { kind: ImplItemKind::TyAlias(..), .. })
+        | Node::TraitItem(TraitItem { kind: TraitItemKind::Type(..), .. })
+            if tcx.associated_item(def_id).impl_trait_fn.is_some() =>
+        {
+            tcx.associated_item(def_id).impl_trait_fn
+        }
         Node::ImplItem(_)
         | Node::TraitItem(_)
         | Node::Variant(_)
@@ -1266,7 +1277,11 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
                     // expressions' count (i.e. `N` in `[x; N]`), and explicit
                     // `enum` discriminants (i.e. `D` in `enum Foo { Bar = D }`),
                     // as they shouldn't be able to cause query cycle errors.
+                    //
+                    // Inline constants (i.e. `const { .. }`) are type checked
+                    // with their enclosing body, so they always inherit its generics.
                     Node::Expr(&Expr { kind: ExprKind::Repeat(_, ref constant), .. })
+                    | Node::Expr(&Expr { kind: ExprKind::ConstBlock(ref constant), .. })
                     | Node::Variant(Variant { disr_expr: Some(ref constant), .. })
                         if constant.hir_id == hir_id =>
                     {
@@ -1421,13 +1436,21 @@ fn generics_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::Generics {
             i += 1;
             Some(param_def)
         }
-        GenericParamKind::Const { .. } => {
+        GenericParamKind::Const { ref default, .. } => {
+            if !allow_defaults && default.is_some() {
+                tcx.sess.span_err(
+                    param.span,
+                    "defaults for const parameters are only allowed in \
+                     `struct`, `enum`, `type`, or `trait` definitions",
+                );
+            }
+
             let param_def = ty::GenericParamDef {
                 index: type_start + i as u32,
                 name: param.name.ident().name,
                 def_id: tcx.hir().local_def_id(param.hir_id).to_def_id(),
                 pure_wrt_drop: param.pure_wrt_drop,
-                kind: ty::GenericParamDefKind::Const,
+                kind: ty::GenericParamDefKind::Const { has_default: default.is_some() },
             };
             i += 1;
             Some(param_def)
@@ -2,7 +2,8 @@ use super::ItemCtxt;
 use crate::astconv::{AstConv, SizedByDefault};
 use rustc_hir as hir;
 use rustc_infer::traits::util;
-use rustc_middle::ty::subst::InternalSubsts;
+use rustc_middle::ty::fold::{BottomUpFolder, TypeFoldable};
+use rustc_middle::ty::subst::{InternalSubsts, Subst};
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::def_id::DefId;
 use rustc_span::Span;
@@ -80,12 +81,51 @@ fn opaque_type_bounds<'tcx>(
     tcx.arena.alloc_slice(&bounds)
 }
 
+/// The anonymous associated type of the `impl Trait` return type of a provided trait method has
+/// the bounds of its default, the opaque return type, in terms of the associated type itself.
+fn impl_trait_in_trait_default_bounds<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    assoc_item_def_id: DefId,
+) -> &'tcx [(ty::Predicate<'tcx>, Span)] {
+    let default = tcx.type_of(assoc_item_def_id);
+    let (opaque_def_id, substs) = match *default.kind() {
+        ty::Opaque(def_id, substs) => (def_id, substs),
+        _ => return &[],
+    };
+    let item_ty = tcx.mk_projection(
+        assoc_item_def_id,
+        InternalSubsts::identity_for_item(tcx, assoc_item_def_id),
+    );
+    let mut folder = BottomUpFolder {
+        tcx,
+        ty_op: |ty| if ty == default { item_ty } else { ty },
+        lt_op: |lt| lt,
+        ct_op: |ct| ct,
+    };
+    let bounds = tcx.arena.alloc_from_iter(
+        tcx.explicit_item_bounds(opaque_def_id)
+            .iter()
+            .map(|&(bound, span)| (bound.subst(tcx, substs).fold_with(&mut folder), span)),
+    );
+    debug!(
+        "impl_trait_in_trait_default_bounds({}) = {:?}",
+        tcx.def_path_str(assoc_item_def_id),
+        bounds
+    );
+    bounds
+}
+
 pub(super) fn explicit_item_bounds(
     tcx: TyCtxt<'_>,
     def_id: DefId,
 ) -> &'_ [(ty::Predicate<'_>, Span)] {
     let hir_id = tcx.hir().local_def_id_to_hir_id(def_id.expect_local());
     match tcx.hir().get(hir_id) {
+        hir::Node::TraitItem(hir::TraitItem {
+            kind: hir::TraitItemKind::Type(_, Some(_)), ..
+        }) if tcx.associated_item(def_id).impl_trait_fn.is_some() => {
+            impl_trait_in_trait_default_bounds(tcx, def_id)
+        }
         hir::Node::TraitItem(hir::TraitItem {
             kind: hir::TraitItemKind::Type(bounds, _),
             span,
@@ -56,7 +56,7 @@ pub(super) fn opt_const_param_of(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<
                 tcx.generics_of(type_dependent_def)
                     .params
                     .iter()
-                    .filter(|param| matches!(param.kind, ty::GenericParamDefKind::Const))
+                    .filter(|param| matches!(param.kind, ty::GenericParamDefKind::Const { .. }))
                     .nth(idx)
                     .map(|param| param.def_id)
             }
@@ -127,7 +127,7 @@ pub(super) fn opt_const_param_of(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<
                 generics
                     .params
                     .iter()
-                    .filter(|param| matches!(param.kind, ty::GenericParamDefKind::Const))
+                    .filter(|param| matches!(param.kind, ty::GenericParamDefKind::Const { .. }))
                     .nth(arg_index)
                     .map(|param| param.def_id)
             }
@@ -325,6 +325,12 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
                     .discr_type()
                     .to_ty(tcx),
 
+                Node::GenericParam(&GenericParam {
+                    hir_id: param_hir_id,
+                    kind: GenericParamKind::Const { default: Some(ct), .. },
+                    ..
+                }) if ct.hir_id == hir_id => tcx.type_of(tcx.hir().local_def_id(param_hir_id)),
+
                 x => tcx.ty_error_with_message(
                     DUMMY_SP,
                     &format!("unexpected const parent in type_of_def_id(): {:?}", x),
@@ -530,12 +536,40 @@ fn find_opaque_ty_constraints(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Ty<'_> {
         Some((_, ty)) => ty,
         None => {
             let span = tcx.def_span(def_id);
-            tcx.sess.span_err(span, "could not find defining uses");
+            let mut err = tcx.sess.struct_span_err(span, "could not find defining uses");
+            if scope == hir::CRATE_HIR_ID {
+                err.note("a defining use must appear somewhere within the crate root");
+            } else {
+                let scope_def_id = tcx.hir().local_def_id(scope).to_def_id();
+                err.note(&format!(
+                    "a defining use must appear within the {} `{}` or one of its children",
+                    tcx.def_kind(scope_def_id).descr(scope_def_id),
+                    tcx.def_path_str(scope_def_id),
+                ));
+            }
+            err.emit();
             tcx.ty_error()
         }
     }
 }
 
+/// Computes the default of a const generic parameter, e.g. `3` for `N` in
+/// `struct Foo<const N: usize = 3>`.
+pub(super) fn const_param_default(tcx: TyCtxt<'_>, def_id: DefId) -> &ty::Const<'_> {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(def_id.expect_local());
+    let default_def_id = match tcx.hir().get(hir_id) {
+        Node::GenericParam(hir::GenericParam {
+            kind: hir::GenericParamKind::Const { default: Some(ct), .. },
+            ..
+        }) => tcx.hir().local_def_id(ct.hir_id),
+        _ => span_bug!(
+            tcx.def_span(def_id),
+            "`const_param_default` expected a const parameter with a default"
+        ),
+    };
+    ty::Const::from_anon_const(tcx, default_def_id)
+}
+
 /// Retrieve the inferred concrete type for let position impl trait.
 ///
 /// This is different to other kinds of impl trait because:
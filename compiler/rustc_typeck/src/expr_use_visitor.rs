@@ -211,6 +211,12 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
                 }
             }
 
+            hir::ExprKind::Let(ref pat, ref init) => {
+                let init_place = return_if_err!(self.mc.cat_expr(&init));
+                self.borrow_expr(&init, ty::ImmBorrow);
+                self.walk_pat(&init_place, pat);
+            }
+
             hir::ExprKind::Array(ref exprs) => {
                 self.consume_exprs(exprs);
             }
@@ -349,9 +355,18 @@ impl<'a, 'tcx> ExprUseVisitor<'a, 'tcx> {
             // initializers are considered
             // "assigns", which is handled by
             // `walk_pat`:
-            self.walk_expr(&expr);
-            let init_place = return_if_err!(self.mc.cat_expr(&expr));
-            self.walk_irrefutable_pat(&init_place, &local.pat);
+            if let Some(els) = local.els {
+                // The pattern of a `let...else` is refutable, so the initializer is inspected
+                // like the scrutinee of a `match`.
+                let init_place = return_if_err!(self.mc.cat_expr(&expr));
+                self.borrow_expr(&expr, ty::ImmBorrow);
+                self.walk_pat(&init_place, &local.pat);
+                self.walk_block(els);
+            } else {
+                self.walk_expr(&expr);
+                let init_place = return_if_err!(self.mc.cat_expr(&expr));
+                self.walk_irrefutable_pat(&init_place, &local.pat);
+            }
         }
     }
 
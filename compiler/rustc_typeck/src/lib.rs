@@ -91,6 +91,7 @@ mod outlives;
 mod structured_errors;
 mod variance;
 
+use rustc_data_structures::sync::{par_iter, ParallelIterator};
 use rustc_errors::{struct_span_err, ErrorReported};
 use rustc_hir as hir;
 use rustc_hir::def_id::{LocalDefId, LOCAL_CRATE};
@@ -363,9 +364,9 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorReported> {
     // FIXME(matthewjasper) We shouldn't need to use `track_errors`.
     tcx.sess.track_errors(|| {
         tcx.sess.time("type_collecting", || {
-            for &module in tcx.hir().krate().modules.keys() {
+            par_iter(&tcx.hir().krate().modules).for_each(|(&module, _)| {
                 tcx.ensure().collect_mod_item_types(tcx.hir().local_def_id(module));
-            }
+            });
         });
     })?;
 
@@ -395,9 +396,9 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorReported> {
 
     // NOTE: This is copy/pasted in librustdoc/core.rs and should be kept in sync.
     tcx.sess.time("item_types_checking", || {
-        for &module in tcx.hir().krate().modules.keys() {
+        par_iter(&tcx.hir().krate().modules).for_each(|(&module, _)| {
             tcx.ensure().check_mod_item_types(tcx.hir().local_def_id(module));
-        }
+        });
     });
 
     tcx.sess.time("item_bodies_checking", || tcx.typeck_item_bodies(LOCAL_CRATE));
@@ -363,6 +363,7 @@ impl<'a, 'tcx> MemCategorizationContext<'a, 'tcx> {
             | hir::ExprKind::MethodCall(..)
             | hir::ExprKind::Cast(..)
             | hir::ExprKind::DropTemps(..)
+            | hir::ExprKind::Let(..)
             | hir::ExprKind::Array(..)
             | hir::ExprKind::Tup(..)
             | hir::ExprKind::Binary(..)
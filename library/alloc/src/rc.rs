@@ -277,6 +277,10 @@ struct RcBox<T: ?Sized> {
 ///
 /// [get_mut]: #method.get_mut
 #[cfg_attr(not(test), rustc_diagnostic_item = "Rc")]
+#[cfg_attr(
+    not(bootstrap),
+    must_not_suspend = "holding an `Rc` across suspend points prevents the future from being `Send`"
+)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Rc<T: ?Sized> {
     ptr: NonNull<RcBox<T>>,
@@ -0,0 +1,120 @@
+use crate::future::Future;
+
+/// An async-aware version of the [`Fn`] trait.
+///
+/// Closures and functions which return a [`Future`] and implement [`Fn`]
+/// implement `AsyncFn`, as do `async` closures which only read their captured
+/// variables.
+///
+/// The future returned by `async_call` cannot borrow from the closure itself:
+/// it owns the arguments of the call, and copies of the references the closure
+/// captured, so that the closure can be called again while it is pending.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_closure, async_fn_traits)]
+///
+/// use std::ops::AsyncFn;
+///
+/// async fn call_twice(f: impl AsyncFn(usize) -> usize) -> usize {
+///     f(1).await + f(2).await
+/// }
+///
+/// let greeting = String::from("hello");
+/// let greeting = &greeting;
+/// let _future = call_twice(async move |n| greeting.len() + n);
+/// ```
+#[lang = "async_fn"]
+#[unstable(feature = "async_fn_traits", issue = "62290")]
+#[rustc_paren_sugar]
+#[must_use = "async closures are lazy and do nothing unless called"]
+pub trait AsyncFn<Args>: AsyncFnMut<Args> {
+    /// Performs the call operation, returning the future of its result.
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    extern "rust-call" fn async_call(&self, args: Args) -> Self::CallFuture;
+}
+
+/// An async-aware version of the [`FnMut`] trait.
+///
+/// Closures and functions which return a [`Future`] and implement [`FnMut`]
+/// implement `AsyncFnMut`, as do all `async` closures implementing
+/// [`AsyncFn`].
+///
+/// The future returned by `async_call_mut` cannot borrow from the closure, so
+/// `async` closures which mutate their captured variables are not supported:
+/// a closure which mutates its state has to return a future which does not
+/// borrow that state, e.g. an `async move` block.
+#[lang = "async_fn_mut"]
+#[unstable(feature = "async_fn_traits", issue = "62290")]
+#[rustc_paren_sugar]
+#[must_use = "async closures are lazy and do nothing unless called"]
+pub trait AsyncFnMut<Args>: AsyncFnOnce<Args> {
+    /// Performs the call operation, returning the future of its result.
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    extern "rust-call" fn async_call_mut(&mut self, args: Args) -> Self::CallFuture;
+}
+
+/// An async-aware version of the [`FnOnce`] trait.
+///
+/// All `async` closures implement `AsyncFnOnce`, as do closures and functions
+/// which return a [`Future`] and implement [`FnOnce`].
+#[lang = "async_fn_once"]
+#[unstable(feature = "async_fn_traits", issue = "62290")]
+#[rustc_paren_sugar]
+#[must_use = "async closures are lazy and do nothing unless called"]
+pub trait AsyncFnOnce<Args> {
+    /// The type the future returned by the call resolves to.
+    #[lang = "async_fn_once_output"]
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    type Output;
+
+    /// The future returned by the call operation.
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    type CallFuture: Future<Output = Self::Output>;
+
+    /// Performs the call operation, returning the future of its result.
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    extern "rust-call" fn async_call_once(self, args: Args) -> Self::CallFuture;
+}
+
+mod impls {
+    use super::{AsyncFn, AsyncFnMut, AsyncFnOnce};
+    use crate::future::Future;
+
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    impl<A, F, Fut> AsyncFn<A> for F
+    where
+        F: Fn<A, Output = Fut>,
+        Fut: Future,
+    {
+        extern "rust-call" fn async_call(&self, args: A) -> Fut {
+            self.call(args)
+        }
+    }
+
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    impl<A, F, Fut> AsyncFnMut<A> for F
+    where
+        F: FnMut<A, Output = Fut>,
+        Fut: Future,
+    {
+        extern "rust-call" fn async_call_mut(&mut self, args: A) -> Fut {
+            self.call_mut(args)
+        }
+    }
+
+    #[unstable(feature = "async_fn_traits", issue = "62290")]
+    impl<A, F, Fut> AsyncFnOnce<A> for F
+    where
+        F: FnOnce<A, Output = Fut>,
+        Fut: Future,
+    {
+        type Output = Fut::Output;
+        type CallFuture = Fut;
+
+        extern "rust-call" fn async_call_once(self, args: A) -> Fut {
+            self.call_once(args)
+        }
+    }
+}
@@ -115,6 +115,7 @@ impl<'a, 'tcx> BlanketImplFinder<'a, 'tcx> {
                             .tcx
                             .associated_items(impl_def_id)
                             .in_definition_order()
+                            .filter(|item| item.impl_trait_fn.is_none())
                             .collect::<Vec<_>>()
                             .clean(self.cx),
                         polarity: None,
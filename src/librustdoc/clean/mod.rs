@@ -20,7 +20,7 @@ use rustc_infer::infer::region_constraints::{Constraint, RegionConstraintData};
 use rustc_middle::bug;
 use rustc_middle::middle::resolve_lifetime as rl;
 use rustc_middle::ty::fold::TypeFolder;
-use rustc_middle::ty::subst::{InternalSubsts, Subst};
+use rustc_middle::ty::subst::{InternalSubsts, Subst, SubstsRef};
 use rustc_middle::ty::{self, AdtKind, Lift, Ty, TyCtxt};
 use rustc_mir::const_eval::{is_const_fn, is_min_const_fn, is_unstable_const_fn};
 use rustc_span::hygiene::{AstPass, MacroKind};
@@ -638,7 +638,7 @@ impl Clean<GenericParamDef> for hir::GenericParam<'_> {
                     synthetic,
                 },
             ),
-            hir::GenericParamKind::Const { ref ty } => (
+            hir::GenericParamKind::Const { ref ty, .. } => (
                 self.name.ident().name.clean(cx),
                 GenericParamDefKind::Const {
                     did: cx.tcx.hir().local_def_id(self.hir_id).to_def_id(),
@@ -1511,6 +1511,10 @@ impl Clean<Type> for hir::Ty<'_> {
                 let mut res = Res::Err;
                 let ty = hir_ty_to_ty(cx.tcx, self);
                 if let ty::Projection(proj) = ty.kind() {
+                    // `Self::foo::{opaque}` is the desugared `impl Trait` return type of `foo`.
+                    if cx.tcx.associated_item(proj.item_def_id).impl_trait_fn.is_some() {
+                        return ty.clean(cx);
+                    }
                     res = Res::Def(DefKind::Trait, proj.trait_ref(cx.tcx).def_id);
                 }
                 let trait_path = hir::Path { span: self.span, res, segments: &[] };
@@ -1662,6 +1666,12 @@ impl<'tcx> Clean<Type> for Ty<'tcx> {
                 Tuple(t.iter().map(|t| t.expect_ty()).collect::<Vec<_>>().clean(cx))
             }
 
+            ty::Projection(ref data)
+                if cx.tcx.associated_item(data.item_def_id).impl_trait_fn.is_some() =>
+            {
+                let substs = cx.tcx.lift(data.substs).expect("Projection lift failed");
+                impl_trait_bounds(cx, data.item_def_id, substs)
+            }
             ty::Projection(ref data) => data.clean(cx),
 
             ty::Param(ref p) => {
@@ -1673,82 +1683,8 @@ impl<'tcx> Clean<Type> for Ty<'tcx> {
             }
 
             ty::Opaque(def_id, substs) => {
-                // Grab the "TraitA + TraitB" from `impl TraitA + TraitB`,
-                // by looking up the bounds associated with the def_id.
                 let substs = cx.tcx.lift(substs).expect("Opaque lift failed");
-                let bounds = cx
-                    .tcx
-                    .explicit_item_bounds(def_id)
-                    .iter()
-                    .map(|(bound, _)| bound.subst(cx.tcx, substs))
-                    .collect::<Vec<_>>();
-                let mut regions = vec![];
-                let mut has_sized = false;
-                let mut bounds = bounds
-                    .iter()
-                    .filter_map(|bound| {
-                        // Note: The substs of opaque types can contain unbound variables,
-                        // meaning that we have to use `ignore_quantifiers_with_unbound_vars` here.
-                        let trait_ref = match bound
-                            .bound_atom_with_opt_escaping(cx.tcx)
-                            .skip_binder()
-                        {
-                            ty::PredicateAtom::Trait(tr, _constness) => {
-                                ty::Binder::bind(tr.trait_ref)
-                            }
-                            ty::PredicateAtom::TypeOutlives(ty::OutlivesPredicate(_ty, reg)) => {
-                                if let Some(r) = reg.clean(cx) {
-                                    regions.push(GenericBound::Outlives(r));
-                                }
-                                return None;
-                            }
-                            _ => return None,
-                        };
-
-                        if let Some(sized) = cx.tcx.lang_items().sized_trait() {
-                            if trait_ref.def_id() == sized {
-                                has_sized = true;
-                                return None;
-                            }
-                        }
-
-                        let bounds: Vec<_> = bounds
-                            .iter()
-                            .filter_map(|bound| {
-                                if let ty::PredicateAtom::Projection(proj) =
-                                    bound.bound_atom_with_opt_escaping(cx.tcx).skip_binder()
-                                {
-                                    if proj.projection_ty.trait_ref(cx.tcx)
-                                        == trait_ref.skip_binder()
-                                    {
-                                        Some(TypeBinding {
-                                            name: cx
-                                                .tcx
-                                                .associated_item(proj.projection_ty.item_def_id)
-                                                .ident
-                                                .name
-                                                .clean(cx),
-                                            kind: TypeBindingKind::Equality {
-                                                ty: proj.ty.clean(cx),
-                                            },
-                                        })
-                                    } else {
-                                        None
-                                    }
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-
-                        Some((trait_ref, &bounds[..]).clean(cx))
-                    })
-                    .collect::<Vec<_>>();
-                bounds.extend(regions);
-                if !has_sized && !bounds.is_empty() {
-                    bounds.insert(0, GenericBound::maybe_sized(cx));
-                }
-                ImplTrait(bounds)
+                impl_trait_bounds(cx, def_id, substs)
             }
 
             ty::Closure(..) | ty::Generator(..) => Tuple(vec![]), // FIXME(pcwalton)
@@ -1762,6 +1698,77 @@ impl<'tcx> Clean<Type> for Ty<'tcx> {
     }
 }
 
+/// Cleans the bounds of the opaque type `def_id`, or of the anonymous associated type of an
+/// `impl Trait` return type in a trait, into an `impl Trait` type.
+fn impl_trait_bounds<'tcx>(cx: &DocContext<'tcx>, def_id: DefId, substs: SubstsRef<'tcx>) -> Type {
+    // Grab the "TraitA + TraitB" from `impl TraitA + TraitB`,
+    // by looking up the bounds associated with the def_id.
+    let bounds = cx
+        .tcx
+        .explicit_item_bounds(def_id)
+        .iter()
+        .map(|(bound, _)| bound.subst(cx.tcx, substs))
+        .collect::<Vec<_>>();
+    let mut regions = vec![];
+    let mut has_sized = false;
+    let mut bounds = bounds
+        .iter()
+        .filter_map(|bound| {
+            // Note: The substs of opaque types can contain unbound variables,
+            // meaning that we have to use `ignore_quantifiers_with_unbound_vars` here.
+            let trait_ref = match bound.bound_atom_with_opt_escaping(cx.tcx).skip_binder() {
+                ty::PredicateAtom::Trait(tr, _constness) => ty::Binder::bind(tr.trait_ref),
+                ty::PredicateAtom::TypeOutlives(ty::OutlivesPredicate(_ty, reg)) => {
+                    if let Some(r) = reg.clean(cx) {
+                        regions.push(GenericBound::Outlives(r));
+                    }
+                    return None;
+                }
+                _ => return None,
+            };
+
+            if let Some(sized) = cx.tcx.lang_items().sized_trait() {
+                if trait_ref.def_id() == sized {
+                    has_sized = true;
+                    return None;
+                }
+            }
+
+            let bounds: Vec<_> = bounds
+                .iter()
+                .filter_map(|bound| {
+                    if let ty::PredicateAtom::Projection(proj) =
+                        bound.bound_atom_with_opt_escaping(cx.tcx).skip_binder()
+                    {
+                        if proj.projection_ty.trait_ref(cx.tcx) == trait_ref.skip_binder() {
+                            Some(TypeBinding {
+                                name: cx
+                                    .tcx
+                                    .associated_item(proj.projection_ty.item_def_id)
+                                    .ident
+                                    .name
+                                    .clean(cx),
+                                kind: TypeBindingKind::Equality { ty: proj.ty.clean(cx) },
+                            })
+                        } else {
+                            None
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Some((trait_ref, &bounds[..]).clean(cx))
+        })
+        .collect::<Vec<_>>();
+    bounds.extend(regions);
+    if !has_sized && !bounds.is_empty() {
+        bounds.insert(0, GenericBound::maybe_sized(cx));
+    }
+    ImplTrait(bounds)
+}
+
 impl<'tcx> Clean<Constant> for ty::Const<'tcx> {
     fn clean(&self, cx: &DocContext<'_>) -> Constant {
         Constant {
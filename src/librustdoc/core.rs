@@ -1,6 +1,6 @@
 use rustc_attr as attr;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_data_structures::sync::{self, Lrc};
+use rustc_data_structures::sync::{self, par_iter, Lrc, ParallelIterator};
 use rustc_driver::abort_on_err;
 use rustc_errors::emitter::{Emitter, EmitterWriter};
 use rustc_errors::json::JsonEmitter;
@@ -505,9 +505,9 @@ fn run_global_ctxt(
 
     // NOTE: This is copy/pasted from typeck/lib.rs and should be kept in sync with those changes.
     tcx.sess.time("item_types_checking", || {
-        for &module in tcx.hir().krate().modules.keys() {
+        par_iter(&tcx.hir().krate().modules).for_each(|(&module, _)| {
             tcx.ensure().check_mod_item_types(tcx.hir().local_def_id(module));
-        }
+        });
     });
     tcx.sess.abort_if_errors();
     tcx.sess.time("missing_docs", || {
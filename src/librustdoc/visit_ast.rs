@@ -523,7 +523,13 @@ impl<'a, 'tcx> RustdocVisitor<'a, 'tcx> {
                 }
             }
             hir::ItemKind::Trait(is_auto, unsafety, ref generics, ref bounds, ref item_ids) => {
-                let items = item_ids.iter().map(|ti| self.cx.tcx.hir().trait_item(ti.id)).collect();
+                // The anonymous associated types of `impl Trait` return types are documented by
+                // their methods.
+                let items = item_ids
+                    .iter()
+                    .filter(|ti| ti.impl_trait_fn.is_none())
+                    .map(|ti| self.cx.tcx.hir().trait_item(ti.id))
+                    .collect();
                 let t = Trait {
                     is_auto,
                     unsafety,
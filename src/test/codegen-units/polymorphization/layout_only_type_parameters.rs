@@ -0,0 +1,43 @@
+// compile-flags:-Zpolymorphize=on -Zprint-mono-items=lazy -Copt-level=1 -Zinline-mir=no
+
+#![crate_type = "rlib"]
+#![feature(core_intrinsics)]
+
+// This test checks that instances of functions whose type parameters are only used for their
+// layout are shared between types with the same size and alignment.
+
+use std::intrinsics::{min_align_of, size_of};
+
+// Function only uses its type parameter for its size.
+pub fn size<T>() -> usize {
+    size_of::<T>()
+}
+
+//~ MONO_ITEM fn size::<[u32; 1]>
+//~ MONO_ITEM fn size::<[u8; 4]>
+
+// Function only uses its type parameter for its size and alignment.
+pub fn size_and_align<T>() -> (usize, usize) {
+    (size_of::<T>(), min_align_of::<T>())
+}
+
+//~ MONO_ITEM fn size_and_align::<[u16; 1]>
+
+// Function uses its type parameter in an argument.
+pub fn used_argument<T>(_: T) -> usize {
+    size_of::<T>()
+}
+
+//~ MONO_ITEM fn used_argument::<u32>
+//~ MONO_ITEM fn used_argument::<char>
+
+//~ MONO_ITEM fn user
+pub fn user() {
+    size::<u32>();
+    size::<char>();
+    size::<[u8; 4]>();
+    size_and_align::<u16>();
+    size_and_align::<i16>();
+    used_argument::<u32>(1);
+    used_argument::<char>('a');
+}
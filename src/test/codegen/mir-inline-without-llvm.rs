@@ -0,0 +1,31 @@
+// Checks that `-Zinline-mir` inlines small `#[inline]` callees on its own, without the
+// help of LLVM, and that it respects `#[inline(never)]`.
+//
+// compile-flags: -Copt-level=0 -Zinline-mir
+
+#![crate_type = "lib"]
+
+#[inline]
+fn square(x: u32) -> u32 {
+    x.wrapping_mul(x)
+}
+
+#[inline(never)]
+fn cube(x: u32) -> u32 {
+    x.wrapping_mul(x).wrapping_mul(x)
+}
+
+// CHECK-LABEL: @square_caller
+#[no_mangle]
+pub fn square_caller(x: u32) -> u32 {
+    // CHECK-NOT: call
+    // CHECK: mul i32
+    square(x)
+}
+
+// CHECK-LABEL: @cube_caller
+#[no_mangle]
+pub fn cube_caller(x: u32) -> u32 {
+    // CHECK: call {{.*}}cube
+    cube(x)
+}
@@ -40,7 +40,7 @@ fn square_fn() -> impl Fn(u32) -> u32 {
 
 fn main() {
    iterator(); //~ ERROR unused implementer of `Iterator` that must be used
-   future(); //~ ERROR unused implementer of `Future` that must be used
+   future(); //~ ERROR unused future that must be used
    square_fn_once(); //~ ERROR unused implementer of `FnOnce` that must be used
    square_fn_mut(); //~ ERROR unused implementer of `FnMut` that must be used
    square_fn(); //~ ERROR unused implementer of `Fn` that must be used
@@ -0,0 +1,34 @@
+// Lint expectations must stay fulfilled when the queries emitting the lints are loaded from
+// the incremental cache instead of being executed again.
+
+// revisions: cfail1 cfail2
+// build-pass
+
+#![feature(lint_reasons)]
+#![deny(unfulfilled_lint_expectations)]
+
+// Emitted by the late lint pass.
+#[expect(unused_variables)]
+fn unused_variable() {
+    let x = 1;
+}
+
+// Emitted by borrowck.
+#[expect(unused_mut)]
+fn unused_mut() {
+    let mut y = 1;
+    drop(y);
+}
+
+fn changed() -> u32 {
+    #[cfg(cfail1)]
+    return 1;
+    #[cfg(cfail2)]
+    return 2;
+}
+
+fn main() {
+    unused_variable();
+    unused_mut();
+    changed();
+}
@@ -0,0 +1,9 @@
+#[inline(always)]
+pub fn outer() -> u32 {
+    inner()
+}
+
+#[inline]
+fn inner() -> u32 {
+    42
+}
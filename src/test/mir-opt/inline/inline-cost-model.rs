@@ -0,0 +1,63 @@
+// Checks the decisions of the inliner's cost model: calls through function pointers and inline
+// assembly are more expensive than direct calls, and the uses of an argument which is a
+// constant at the call site are credited back.
+//
+// only-x86_64
+// compile-flags: -Zinline-mir-hint-threshold=30
+
+#![crate_type = "lib"]
+#![feature(asm)]
+
+// EMIT_MIR inline_cost_model.direct_call.Inline.diff
+pub fn direct_call() {
+    call_direct();
+}
+
+// EMIT_MIR inline_cost_model.indirect_call.Inline.diff
+pub fn indirect_call(f: fn()) {
+    call_indirect(f);
+}
+
+// EMIT_MIR inline_cost_model.inline_asm.Inline.diff
+pub fn inline_asm() {
+    call_inline_asm();
+}
+
+// EMIT_MIR inline_cost_model.constant_argument.Inline.diff
+pub fn constant_argument() {
+    use_argument(7);
+}
+
+// EMIT_MIR inline_cost_model.variable_argument.Inline.diff
+pub fn variable_argument(x: u32) {
+    use_argument(x);
+}
+
+#[inline]
+fn call_direct() {
+    g()
+}
+
+#[inline]
+fn call_indirect(f: fn()) {
+    f()
+}
+
+#[inline]
+fn call_inline_asm() {
+    unsafe {
+        asm!("");
+        asm!("");
+    }
+}
+
+#[inline]
+fn use_argument(x: u32) {
+    h(x, x, x)
+}
+
+#[inline(never)]
+fn g() {}
+
+#[inline(never)]
+fn h(_: u32, _: u32, _: u32) {}
@@ -0,0 +1,13 @@
+// Checks that the MIR encoded in the metadata of a crate is the MIR after inlining, so that
+// downstream crates benefit from the inlining done upstream. `inner` is too expensive to be
+// inlined into `main` with the threshold used here.
+//
+// aux-build:encoded_mir.rs
+// compile-flags: -Zinline-mir-hint-threshold=0
+
+extern crate encoded_mir;
+
+// EMIT_MIR inline_encoded_mir.main.Inline.diff
+fn main() {
+    encoded_mir::outer();
+}
@@ -0,0 +1,6 @@
+#![feature(no_core)]
+#![no_core]
+
+extern crate foo;
+
+pub const ANSWER: u32 = foo::ANSWER;
@@ -0,0 +1,4 @@
+#![feature(no_core)]
+#![no_core]
+
+pub const ANSWER: u32 = 42;
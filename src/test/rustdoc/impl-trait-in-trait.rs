@@ -0,0 +1,34 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+#![crate_name = "foo"]
+
+use std::fmt::Display;
+
+// @has foo/trait.Greet.html
+// @has - '//*[@class="rust trait"]' 'fn greeting(&self) -> impl Display;'
+// @has - '//*[@class="rust trait"]' \
+//     "fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a;"
+// @has - '//*[@class="rust trait"]' 'fn shout(&self) -> impl Display { ... }'
+// @!has - '//*[@class="rust trait"]' 'opaque'
+pub trait Greet {
+    fn greeting(&self) -> impl Display;
+    fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a;
+    fn shout(&self) -> impl Display {
+        self.greeting().to_string().to_uppercase()
+    }
+}
+
+pub struct English;
+
+// @has foo/struct.English.html
+// @has - '//h4[@id="method.greeting"]/code' 'fn greeting(&self) -> impl Display'
+// @!has - 'opaque'
+impl Greet for English {
+    fn greeting(&self) -> impl Display {
+        "hello"
+    }
+
+    fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        std::iter::empty()
+    }
+}
@@ -258,3 +258,24 @@ struct ErrorWithLifetime<'a> {
     span: Span,
     name: &'a str,
 }
+
+#[derive(SessionDiagnostic)]
+#[error = "E0123"]
+#[slug = "typeck-field-already-declared"]
+struct TranslatedError {
+    field_name: Ident,
+    #[message]
+    #[label]
+    span: Span,
+    #[label = "previous-decl-label"]
+    prev_span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0123"]
+struct LabelWithoutSlug {
+    #[message = "Some message"]
+    #[label]
+    //~^ ERROR `#[label]` requires the `slug` of the message to be specified
+    span: Span,
+}
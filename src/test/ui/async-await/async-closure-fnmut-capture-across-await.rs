@@ -0,0 +1,23 @@
+// edition:2018
+
+#![feature(async_closure, async_fn_traits)]
+
+use std::ops::AsyncFnMut;
+
+async fn yield_now() {}
+
+async fn call_twice(mut f: impl AsyncFnMut()) {
+    f().await;
+    f().await;
+}
+
+fn main() {
+    let mut count = 0;
+    // The future returned by an `async` closure can't borrow from the closure,
+    // so the closure can't mutate `count` through the future.
+    let _ = call_twice(async || { //~ ERROR captured variable cannot escape `FnMut` closure body
+        count += 1;
+        yield_now().await;
+        count += 1;
+    });
+}
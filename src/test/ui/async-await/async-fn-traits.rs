@@ -0,0 +1,87 @@
+// run-pass
+// edition:2018
+
+#![feature(async_closure, async_fn_traits)]
+
+use std::future::Future;
+use std::ops::{AsyncFn, AsyncFnMut};
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(std::ptr::null(), &VTABLE)
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Box::pin(fut);
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+async fn call_twice(f: impl AsyncFn(usize) -> usize) -> usize {
+    f(1).await + f(2).await
+}
+
+async fn call_mut_twice(mut f: impl AsyncFnMut() -> usize) -> usize {
+    f().await + f().await
+}
+
+async fn double(n: usize) -> usize {
+    n * 2
+}
+
+fn main() {
+    let greeting = String::from("hello");
+    let greeting_ref = &greeting;
+
+    // The parameter and the copied reference are moved into the returned future,
+    // so the closure can be called again while the future is pending.
+    let len_plus = async move |n: usize| {
+        YieldOnce(false).await;
+        greeting_ref.len() + n
+    };
+    assert_eq!(block_on(len_plus(1)), 6);
+    assert_eq!(block_on(call_twice(&len_plus)), 13);
+
+    // The parameter types are deduced from the `AsyncFn` bound.
+    assert_eq!(block_on(call_twice(async move |n| n * 2)), 6);
+
+    // Functions returning futures are async callable as well.
+    assert_eq!(block_on(call_twice(double)), 6);
+
+    drop(greeting);
+
+    // A closure mutating its state is async callable through `AsyncFnMut` if the
+    // future it returns doesn't borrow that state.
+    let mut count = 0;
+    let counter = move || {
+        count += 1;
+        let current = count;
+        async move { current }
+    };
+    assert_eq!(block_on(call_mut_twice(counter)), 3);
+}
@@ -0,0 +1,32 @@
+// check-pass
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Database<'conn> {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn count(&mut self) -> usize;
+}
+
+struct Memory<'conn>(&'conn [(&'static str, &'static str)]);
+
+impl<'conn> Database<'conn> for Memory<'conn> {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.0.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+    }
+
+    async fn count(&mut self) -> usize {
+        self.0.len()
+    }
+}
+
+async fn lookup<'conn, D: Database<'conn>>(db: &mut D) -> (Option<String>, usize) {
+    let value = db.get("key").await;
+    (value, db.count().await)
+}
+
+fn main() {
+    let data = [("key", "value")];
+    let _ = lookup(&mut Memory(&data));
+}
@@ -0,0 +1,41 @@
+// check-pass
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Foo {
+    async fn foo(&self) -> u32 {
+        self.bar().await + 1
+    }
+
+    async fn bar(&self) -> u32;
+}
+
+struct Default;
+struct Override;
+
+impl Foo for Default {
+    async fn bar(&self) -> u32 {
+        1
+    }
+}
+
+impl Foo for Override {
+    async fn foo(&self) -> u32 {
+        0
+    }
+
+    async fn bar(&self) -> u32 {
+        2
+    }
+}
+
+async fn sum<F: Foo>(f: &F) -> u32 {
+    f.foo().await + f.bar().await
+}
+
+fn main() {
+    let _ = sum(&Default);
+    let _ = sum(&Override);
+}
@@ -0,0 +1,15 @@
+// edition:2018
+
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features)]
+
+trait Foo {
+    async fn foo(&self) -> u32;
+}
+
+fn make_foo<T: Foo>(t: &T) -> &dyn Foo {
+    //~^ ERROR the trait `Foo` cannot be made into an object
+    t
+}
+
+fn main() {}
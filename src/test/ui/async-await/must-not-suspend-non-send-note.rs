@@ -0,0 +1,22 @@
+// edition:2018
+
+// Check that the reason given by `#[must_not_suspend]` is noted when a future is not `Send`,
+// in crates using the feature.
+
+#![feature(must_not_suspend)]
+
+use std::rc::Rc;
+
+fn is_send<T: Send>(t: T) { }
+
+async fn bar() {
+    let shared = Rc::new(0);
+    baz().await;
+}
+
+async fn baz() { }
+
+fn main() {
+    is_send(bar());
+    //~^ ERROR future cannot be sent between threads safely
+}
@@ -0,0 +1,9 @@
+#![feature(min_const_generics, const_generics_defaults)]
+
+pub struct Buf<T, const N: usize = 4>(pub [T; N]);
+
+impl<T, const N: usize> Buf<T, N> {
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
@@ -0,0 +1,59 @@
+// run-pass
+// aux-build:const_defaults.rs
+#![feature(min_const_generics, const_generics_defaults)]
+
+extern crate const_defaults;
+
+struct Buf<T: Copy + Default, const N: usize = 64> {
+    data: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> Buf<T, N> {
+    fn new() -> Self {
+        Buf { data: [T::default(); N], len: 0 }
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+        self.data[self.len] = value;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+struct Defaulted<T = u8, const N: usize = { 2 * 4 }>([T; N]);
+
+mod consts {
+    pub const CAP: usize = 3;
+}
+
+fn main() {
+    let buf: Buf<u32> = Buf::new();
+    assert_eq!(buf.capacity(), 64);
+
+    let mut small: Buf<u32, 2> = Buf::new();
+    assert_eq!(small.push(1), Ok(()));
+    assert_eq!(small.push(2), Ok(()));
+    assert_eq!(small.push(3), Err(3));
+
+    // Const arguments may be simple paths through modules without braces.
+    let cap: Buf<u8, consts::CAP> = Buf::new();
+    assert_eq!(cap.capacity(), 3);
+
+    let Defaulted(all_defaults): Defaulted = Defaulted([1; 8]);
+    assert_eq!(all_defaults.len(), 8);
+    let Defaulted(type_only): Defaulted<u16> = Defaulted([2; 8]);
+    assert_eq!(type_only.iter().sum::<u16>(), 16);
+    let Defaulted(both): Defaulted<u16, 1> = Defaulted([3]);
+    assert_eq!(both, [3]);
+
+    let external: const_defaults::Buf<i32> = const_defaults::Buf([0; 4]);
+    assert_eq!(external.capacity(), 4);
+}
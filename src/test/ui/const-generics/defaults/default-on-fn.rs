@@ -0,0 +1,6 @@
+#![feature(min_const_generics, const_generics_defaults)]
+
+fn foo<const N: usize = 3>() {}
+//~^ ERROR defaults for const parameters are only allowed in
+
+fn main() {}
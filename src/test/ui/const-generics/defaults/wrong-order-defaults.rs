@@ -0,0 +1,9 @@
+#![feature(min_const_generics, const_generics_defaults)]
+
+struct A<const N: usize = 3, const M: usize>;
+//~^ ERROR generic parameters with a default must be trailing
+
+struct B<T = u8, const N: usize>(T);
+//~^ ERROR type parameters with a default must be trailing
+
+fn main() {}
@@ -0,0 +1,11 @@
+// revisions: sorted unsorted
+//[sorted] compile-flags: -Z deterministic-diagnostics=yes
+//[unsorted] compile-flags: -Z deterministic-diagnostics=no
+
+// Name resolution errors are reported before type errors, but with
+// `-Z deterministic-diagnostics` all of them are emitted in source order.
+
+fn main() {
+    let _: u32 = "a"; //~ ERROR mismatched types
+    let _ = undefined_value; //~ ERROR cannot find value `undefined_value` in this scope
+}
@@ -0,0 +1,11 @@
+// edition:2018
+
+trait Foo {
+    async fn foo(&self); //~ ERROR functions in traits cannot be declared `async`
+}
+
+impl Foo for () {
+    async fn foo(&self) {} //~ ERROR functions in traits cannot be declared `async`
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#![feature(min_const_generics)]
+
+struct A<const N: usize = 3>;
+//~^ ERROR default values for const generic parameters are unstable
+
+fn main() {}
@@ -0,0 +1,6 @@
+// check that #[must_not_suspend] is feature-gated
+
+#[must_not_suspend = "it must be released first"] //~ ERROR the `#[must_not_suspend]` attribute is an experimental feature
+struct Guard;
+
+fn main() {}
@@ -0,0 +1,13 @@
+use std::fmt::Debug;
+
+trait Foo {
+    fn foo(&self) -> impl Debug;
+    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+}
+
+impl Foo for () {
+    fn foo(&self) -> impl Debug {}
+    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+trait Greet {
+    fn greeting(&self) -> impl Display;
+    fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a;
+}
+
+struct English(Vec<String>);
+struct Unit;
+
+impl Greet for English {
+    fn greeting(&self) -> impl Display {
+        "hello"
+    }
+
+    fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        self.0.iter().map(|name| name.as_str())
+    }
+}
+
+impl Greet for Unit {
+    fn greeting(&self) -> impl Display {
+        42
+    }
+
+    fn names<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        std::iter::empty()
+    }
+}
+
+fn greet_all<G: Greet>(g: &G) -> Vec<String> {
+    g.names().map(|name| format!("{} {}", g.greeting(), name)).collect()
+}
+
+fn main() {
+    let english = English(vec!["Ferris".to_string(), "Corro".to_string()]);
+    assert_eq!(greet_all(&english), ["hello Ferris", "hello Corro"]);
+    assert_eq!(Unit.greeting().to_string(), "42");
+    assert!(greet_all(&Unit).is_empty());
+}
@@ -0,0 +1,42 @@
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Display;
+
+trait Describe {
+    fn name(&self) -> String;
+
+    fn describe<'a>(&'a self, prefix: &'a str) -> impl Display + 'a {
+        format!("{}{}", prefix, self.name())
+    }
+}
+
+struct Cat;
+struct Dog;
+
+impl Describe for Cat {
+    fn name(&self) -> String {
+        "cat".to_string()
+    }
+}
+
+impl Describe for Dog {
+    fn name(&self) -> String {
+        "dog".to_string()
+    }
+
+    fn describe<'a>(&'a self, _: &'a str) -> impl Display + 'a {
+        42
+    }
+}
+
+fn describe<D: Describe>(d: &D) -> String {
+    d.describe("a ").to_string()
+}
+
+fn main() {
+    assert_eq!(describe(&Cat), "a cat");
+    assert_eq!(describe(&Dog), "42");
+}
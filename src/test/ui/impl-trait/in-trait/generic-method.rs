@@ -0,0 +1,31 @@
+// run-pass
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Debug;
+
+trait Foo {
+    fn foo<T: Debug>(&self, t: T) -> impl Debug;
+    fn bar<'a>(&'a self, t: impl Debug + 'a) -> impl Debug + 'a;
+}
+
+struct Wrap(u32);
+
+impl Foo for Wrap {
+    fn foo<T: Debug>(&self, t: T) -> impl Debug {
+        (self.0, t)
+    }
+
+    fn bar<'a>(&'a self, t: impl Debug + 'a) -> impl Debug + 'a {
+        (&self.0, t)
+    }
+}
+
+fn show<F: Foo>(f: &F) -> String {
+    format!("{:?} {:?}", f.foo("a"), f.bar(Some(1)))
+}
+
+fn main() {
+    assert_eq!(show(&Wrap(0)), r#"(0, "a") (0, Some(1))"#);
+}
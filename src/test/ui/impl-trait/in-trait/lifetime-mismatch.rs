@@ -0,0 +1,17 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Debug;
+
+trait Foo {
+    fn foo(&self) -> impl Debug;
+}
+
+impl Foo for Vec<u8> {
+    fn foo(&self) -> impl Debug + '_ {
+    //~^ ERROR return type of method `foo` captures different lifetimes than in the trait declaration
+        self.first()
+    }
+}
+
+fn main() {}
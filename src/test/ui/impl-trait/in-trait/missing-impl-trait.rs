@@ -0,0 +1,15 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+trait Foo {
+    fn foo(&self) -> impl Clone;
+}
+
+impl Foo for u32 {
+    fn foo(&self) -> u32 {
+    //~^ ERROR method `foo` must return `impl Trait` to match its trait declaration
+        *self
+    }
+}
+
+fn main() {}
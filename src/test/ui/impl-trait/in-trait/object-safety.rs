@@ -0,0 +1,24 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::Debug;
+
+trait Foo {
+    fn foo(&self) -> impl Debug;
+}
+
+trait Bar {
+    fn bar(&self) -> impl Debug where Self: Sized;
+    fn baz(&self) -> u32;
+}
+
+fn make_foo<T: Foo>(t: &T) -> &dyn Foo {
+    //~^ ERROR the trait `Foo` cannot be made into an object
+    t
+}
+
+fn make_bar<T: Bar>(t: &T) -> &dyn Bar {
+    t
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// check-pass
+// Lifetimes of the trait and of the impl are not captured by the anonymous associated type,
+// so they do not have to match between the trait and its implementations.
+
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+trait Parse<'s> {
+    fn parse(&self, input: &'s str) -> impl Iterator<Item = &'s str>;
+}
+
+struct Words;
+struct Prefixed<'p>(&'p str);
+
+impl<'s> Parse<'s> for Words {
+    fn parse(&self, input: &'s str) -> impl Iterator<Item = &'s str> {
+        input.split(' ')
+    }
+}
+
+impl<'p, 's> Parse<'s> for Prefixed<'p> {
+    fn parse(&self, input: &'s str) -> impl Iterator<Item = &'s str> {
+        input.split(' ').filter(|word| !word.starts_with("#"))
+    }
+}
+
+fn count<'s, P: Parse<'s>>(p: &P, input: &'s str) -> usize {
+    p.parse(input).count()
+}
+
+fn main() {
+    assert_eq!(count(&Words, "a b c"), 3);
+    assert_eq!(count(&Prefixed("#"), "a #b c"), 2);
+}
@@ -0,0 +1,17 @@
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+
+use std::fmt::{Debug, Display};
+
+trait Foo {
+    fn foo(&self) -> impl Display;
+}
+
+impl Foo for () {
+    fn foo(&self) -> impl Debug {
+    //~^ ERROR `impl Debug` doesn't implement `std::fmt::Display`
+        ()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(inline_const, min_const_generics)]
+
+use std::mem::size_of;
+
+fn pair_size<T>() -> usize {
+    const { size_of::<(T, T)>() }
+}
+
+fn empty_vecs<T, const N: usize>() -> [Vec<T>; N] {
+    [const { Vec::new() }; N]
+}
+
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    fn padding() -> usize {
+        const { size_of::<Self>() - size_of::<T>() }
+    }
+}
+
+fn main() {
+    assert_eq!(pair_size::<u16>(), 4);
+    assert_eq!(pair_size::<[u8; 3]>(), 6);
+
+    let vecs = empty_vecs::<String, 4>();
+    assert!(vecs.iter().all(Vec::is_empty));
+
+    assert_eq!(Wrapper::<u32>::padding(), 0);
+}
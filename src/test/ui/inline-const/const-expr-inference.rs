@@ -0,0 +1,15 @@
+// run-pass
+
+#![allow(incomplete_features)]
+#![feature(inline_const)]
+
+fn main() {
+    // The element type is inferred from the uses of the array.
+    let mut names = [const { Vec::new() }; 3];
+    names[0].push("ferris");
+    assert_eq!(names[0], ["ferris"]);
+    assert!(names[1].is_empty());
+
+    let empty = const { None };
+    assert_eq!(empty.unwrap_or(7u8), 7);
+}
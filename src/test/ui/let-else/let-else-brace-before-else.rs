@@ -0,0 +1,12 @@
+// run-rustfix
+
+#![feature(let_else)]
+
+fn main() {
+    let Some(1) = { Some(1) } else { //~ ERROR right curly brace `}` before `else` in a `let...else` statement not allowed
+        return;
+    };
+    let Some(1) = loop { break Some(1) } else { //~ ERROR right curly brace `}` before `else` in a `let...else` statement not allowed
+        return;
+    };
+}
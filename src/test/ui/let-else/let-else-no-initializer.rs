@@ -0,0 +1,5 @@
+#![feature(let_else)]
+
+fn main() {
+    let x else { return }; //~ ERROR `let...else` statement without an initializer
+}
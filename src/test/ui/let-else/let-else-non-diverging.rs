@@ -0,0 +1,13 @@
+#![feature(let_else)]
+
+fn tail_expr() {
+    let Some(x) = Some(1) else { Some(2) }; //~ ERROR does not diverge
+    let _ = x;
+}
+
+fn empty_block() {
+    let Some(x) = Some(1) else {}; //~ ERROR does not diverge
+    let _ = x;
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// run-pass
+
+#![feature(let_else)]
+
+// Checks that `ref` bindings of a `let...else` statement extend the lifetime of the temporary
+// they borrow from to the end of the block, like those of a plain `let` statement.
+
+fn temp() -> Option<String> {
+    Some(String::from("temporary"))
+}
+
+fn first(v: Vec<Option<String>>) -> Option<usize> {
+    let Some(Some(ref s)) = v.into_iter().next() else { return None };
+    Some(s.len())
+}
+
+fn main() {
+    let Some(ref x) = temp() else { panic!() };
+    assert_eq!(x, "temporary");
+
+    let Some(ref mut y) = temp() else { panic!() };
+    y.push_str(" value");
+    assert_eq!(y, "temporary value");
+
+    let (Some(ref a), b) = (temp(), 1) else { panic!() };
+    assert_eq!((a.as_str(), b), ("temporary", 1));
+
+    assert_eq!(first(vec![Some(String::from("first"))]), Some(5));
+    assert_eq!(first(vec![None]), None);
+    assert_eq!(first(vec![]), None);
+}
@@ -0,0 +1,48 @@
+// run-pass
+
+#![feature(let_else)]
+
+// Checks that the temporaries of the initializer of a `let...else` statement are dropped at the
+// end of the statement, while the bindings live until the end of the block.
+
+use std::cell::RefCell;
+
+struct Droppy<'a> {
+    log: &'a RefCell<Vec<&'static str>>,
+    name: &'static str,
+}
+
+impl Droppy<'_> {
+    fn get(&self) -> Option<u32> {
+        Some(1)
+    }
+}
+
+impl Drop for Droppy<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn main() {
+    let log = RefCell::new(Vec::new());
+    {
+        let Some(x) = Droppy { log: &log, name: "temporary" }.get() else { panic!() };
+        log.borrow_mut().push("after let");
+        assert_eq!(x, 1);
+    }
+    assert_eq!(*log.borrow(), ["temporary", "after let"]);
+
+    log.borrow_mut().clear();
+    {
+        let Some(_binding) = Some(Droppy { log: &log, name: "binding" }) else { panic!() };
+        log.borrow_mut().push("end of block");
+    }
+    assert_eq!(*log.borrow(), ["end of block", "binding"]);
+
+    // The `Ref` returned by `borrow` is released at the end of the `let...else`.
+    let cell = RefCell::new(vec![1, 2]);
+    let Some(first) = cell.borrow().first().copied() else { panic!() };
+    cell.borrow_mut().push(first);
+    assert_eq!(*cell.borrow(), [1, 2, 1]);
+}
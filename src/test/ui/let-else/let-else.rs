@@ -0,0 +1,34 @@
+// run-pass
+
+#![feature(let_else)]
+
+enum Shape {
+    Circle(u32),
+    Square(u32),
+}
+
+fn first_even(v: &[u32]) -> Option<u32> {
+    for &x in v {
+        let 0 = x % 2 else { continue };
+        return Some(x);
+    }
+    None
+}
+
+fn circle_radius(shape: Shape) -> u32 {
+    let Shape::Circle(r) = shape else { return 0 };
+    r
+}
+
+fn main() {
+    let Some(x) = Some(1) else { panic!() };
+    assert_eq!(x, 1);
+
+    let (a, Ok(b)): (u8, Result<u8, ()>) = (2, Ok(3)) else { unreachable!() };
+    assert_eq!(a + b, 5);
+
+    assert_eq!(first_even(&[1, 3, 4, 5]), Some(4));
+    assert_eq!(first_even(&[1, 3]), None);
+    assert_eq!(circle_radius(Shape::Circle(7)), 7);
+    assert_eq!(circle_radius(Shape::Square(7)), 0);
+}
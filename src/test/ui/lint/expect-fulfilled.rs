@@ -0,0 +1,24 @@
+// check-pass
+
+#![feature(lint_reasons)]
+#![expect(non_camel_case_types, reason = "fulfilled by an early lint pass")]
+
+struct lower_case;
+
+#[expect(dead_code)]
+fn never_called() {}
+
+#[expect(unused_mut, reason = "fulfilled by borrowck")]
+fn borrowck_lint() -> i32 {
+    let mut x = 1;
+    x
+}
+
+fn main() {
+    #[expect(unused_variables)]
+    let y = 2;
+    #[expect(unused)]
+    let z = 3;
+    let _ = lower_case;
+    borrowck_lint();
+}
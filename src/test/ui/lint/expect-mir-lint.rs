@@ -0,0 +1,23 @@
+// revisions: build check
+//[build] build-pass
+//[build] compile-flags: -C opt-level=0
+//[check] check-pass
+//[check] compile-flags: --emit=metadata
+
+#![feature(lint_reasons)]
+
+// `arithmetic_overflow` is emitted by const propagation while building optimized MIR. When
+// codegen happens, it is built for every function to check the expectations, including the
+// ones that are never called. Check builds don't build it, and so don't report expectations
+// of the lints it emits.
+
+fn main() {
+    #[expect(arithmetic_overflow)]
+    let _ = 255u8 + 1;
+}
+
+#[allow(dead_code)]
+fn unused() {
+    #[expect(arithmetic_overflow)]
+    let _ = 255u8 + 1;
+}
@@ -0,0 +1,26 @@
+// check-pass
+
+#![feature(lint_reasons)]
+
+#[expect(dead_code)]
+//~^ WARN this lint expectation is unfulfilled
+//~| NOTE `#[warn(unfulfilled_lint_expectations)]` on by default
+fn used() {}
+
+#[expect(unused_variables, reason = "`x` is only read in debug builds")]
+//~^ WARN this lint expectation is unfulfilled
+//~| NOTE `x` is only read in debug builds
+fn read() {
+    let x = 1;
+    println!("{}", x);
+}
+
+#[allow(unfulfilled_lint_expectations)]
+#[expect(unused_mut)]
+fn silenced() {}
+
+fn main() {
+    used();
+    read();
+    silenced();
+}
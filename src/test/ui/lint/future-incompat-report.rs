@@ -0,0 +1,8 @@
+// check-pass
+// compile-flags: -Z emit-future-incompat-report --cap-lints allow
+
+trait Tr {
+    fn f(mut arg: u8);
+}
+
+fn main() {}
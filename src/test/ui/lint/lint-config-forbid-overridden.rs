@@ -0,0 +1,10 @@
+// Check that a lint forbidden by a `--lint-config` file can still be allowed on the command
+// line, as the command-line flags override the levels of the file.
+//
+// check-pass
+// compile-flags: -Z unstable-options --lint-config {{src-base}}/lint/lint-config-forbid.toml
+// compile-flags: -A unused-variables
+
+fn main() {
+    let x = 1;
+}
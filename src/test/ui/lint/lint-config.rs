@@ -0,0 +1,17 @@
+// Check that the levels of a `--lint-config` file apply, that the level of a single lint
+// overrides the level of its group, and that both the command-line flags and the lint
+// attributes override the levels of the file.
+//
+// compile-flags: -Z unstable-options --lint-config {{src-base}}/lint/lint-config.toml
+// compile-flags: -A dead-code
+
+fn main() {
+    let x = 1; //~ WARNING unused variable: `x`
+    let mut y = 2; //~ ERROR variable does not need to be mutable
+    let _ = y;
+    #[allow(unused_mut)]
+    let mut z = 3;
+    let _ = z;
+}
+
+fn unused() {}
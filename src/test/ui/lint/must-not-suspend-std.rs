@@ -0,0 +1,24 @@
+// check-pass
+// edition:2018
+
+#![feature(must_not_suspend)]
+#![warn(must_not_suspend)]
+
+use std::rc::Rc;
+use std::sync::Mutex;
+
+async fn other() {}
+
+async fn rc() {
+    let shared = Rc::new(0); //~ WARN `Rc` held across a suspend point
+    other().await;
+    drop(shared);
+}
+
+async fn mutex_guard(mutex: &Mutex<u32>) {
+    let guard = mutex.lock().unwrap(); //~ WARN `MutexGuard` held across a suspend point
+    other().await;
+    drop(guard);
+}
+
+fn main() {}
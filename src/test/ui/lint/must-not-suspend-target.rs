@@ -0,0 +1,6 @@
+#![feature(must_not_suspend)]
+
+#[must_not_suspend] //~ ERROR `must_not_suspend` attribute should be applied to a struct
+fn not_a_type() {}
+
+fn main() {}
@@ -0,0 +1,56 @@
+// check-pass
+// edition:2018
+
+#![feature(must_not_suspend)]
+#![feature(generators)]
+#![warn(must_not_suspend)]
+
+#[must_not_suspend = "the connection must be released before waiting"]
+struct Connection;
+
+#[must_not_suspend]
+trait Lease {}
+
+impl Lease for Connection {}
+
+fn lease() -> impl Lease {
+    Connection
+}
+
+async fn other() {}
+
+async fn held() {
+    let connection = Connection; //~ WARN `Connection` held across a suspend point
+    other().await;
+    drop(connection);
+}
+
+async fn scoped() {
+    {
+        let _connection = Connection;
+    }
+    other().await;
+}
+
+async fn nested() {
+    let pair = (0, Box::new(Connection));
+    //~^ WARN boxed `Connection` in tuple element 1 held across a suspend point
+    other().await;
+    drop(pair);
+}
+
+async fn opaque() {
+    let lease = lease(); //~ WARN implementer of `Lease` held across a suspend point
+    other().await;
+    drop(lease);
+}
+
+fn generator() {
+    let _ = || {
+        let connection = Connection; //~ WARN `Connection` held across a suspend point
+        yield;
+        drop(connection);
+    };
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// edition:2018
+
+#![deny(unused_must_use)]
+
+use std::future::Future;
+use std::pin::Pin;
+
+async fn answer() -> u32 {
+    42
+}
+
+fn boxed() -> Box<dyn Future<Output = u32>> {
+    Box::new(answer())
+}
+
+fn pinned() -> Pin<Box<dyn Future<Output = u32>>> {
+    Box::pin(answer())
+}
+
+fn identity<T>(t: T) -> T {
+    t
+}
+
+fn make<F: Future<Output = u32>>(f: impl Fn() -> F) {
+    f(); //~ ERROR unused future that must be used
+}
+
+fn not_async() {
+    answer(); //~ ERROR unused future that must be used
+}
+
+async fn in_async() {
+    answer(); //~ ERROR unused future that must be used
+    identity(answer()); //~ ERROR unused future that must be used
+    boxed(); //~ ERROR unused boxed future that must be used
+    pinned(); //~ ERROR unused pinned boxed future that must be used
+    (answer(), 0); //~ ERROR unused future in tuple element 0 that must be used
+    [answer(), answer()]; //~ ERROR unused array of futures that must be used
+}
+
+fn main() {}
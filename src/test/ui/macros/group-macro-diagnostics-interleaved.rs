@@ -0,0 +1,19 @@
+// compile-flags: -Z group-macro-diagnostics
+
+// Check that an unrelated error reported between the invocations of a macro
+// doesn't split the group of errors reported for those invocations.
+
+macro_rules! call_with_str {
+    () => {
+        takes_u32("not a number")
+        //~^ ERROR mismatched types
+    };
+}
+
+fn takes_u32(_: u32) {}
+
+fn main() {
+    call_with_str!();
+    let _: u8 = "unrelated"; //~ ERROR mismatched types
+    call_with_str!();
+}
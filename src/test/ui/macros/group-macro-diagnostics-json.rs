@@ -0,0 +1,16 @@
+// Check that the invocations of a macro listed for a group of diagnostics
+// reported within its expansions are part of the JSON output.
+
+// compile-flags: -Z group-macro-diagnostics --error-format=json
+
+macro_rules! fail {
+    () => {
+        compile_error!("this macro always fails");
+    };
+}
+
+fn main() {
+    fail!();
+    fail!();
+    fail!();
+}
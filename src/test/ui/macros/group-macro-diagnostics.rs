@@ -0,0 +1,16 @@
+// compile-flags: -Z group-macro-diagnostics
+
+macro_rules! call_with_str {
+    () => {
+        takes_u32("not a number")
+        //~^ ERROR mismatched types
+    };
+}
+
+fn takes_u32(_: u32) {}
+
+fn main() {
+    call_with_str!();
+    call_with_str!();
+    call_with_str!();
+}
@@ -0,0 +1,35 @@
+// Check that mutually recursive functions can be compiled with the MIR inliner
+// enabled, both with and without incremental compilation.
+// run-pass
+// revisions: default incremental
+// compile-flags: -O -Zinline-mir
+//[incremental] incremental
+
+#[inline]
+fn even(n: u32) -> bool {
+    if n == 0 { true } else { odd(n - 1) }
+}
+
+#[inline]
+fn odd(n: u32) -> bool {
+    if n == 0 { false } else { even(n - 1) }
+}
+
+#[inline]
+fn generic_even<T: Copy + Into<u64>>(n: T) -> bool {
+    let n: u64 = n.into();
+    n == 0 || generic_odd(n - 1)
+}
+
+#[inline]
+fn generic_odd<T: Copy + Into<u64>>(n: T) -> bool {
+    let n: u64 = n.into();
+    n != 0 && generic_even(n - 1)
+}
+
+fn main() {
+    assert!(even(10));
+    assert!(odd(7));
+    assert!(generic_even(4u8));
+    assert!(generic_odd(5u32));
+}
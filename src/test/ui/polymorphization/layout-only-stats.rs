@@ -0,0 +1,18 @@
+// build-pass
+// compile-flags:-Zpolymorphize=on -Zpolymorphize-stats -Zinline-mir=no
+#![feature(core_intrinsics)]
+
+// This test checks that instances of a function whose type parameter is only used for its layout
+// are merged for types with the same size and alignment, and that the merges are reported.
+
+use std::intrinsics::size_of;
+
+fn size<T>() -> usize {
+    size_of::<T>()
+}
+
+fn main() {
+    size::<u32>();
+    size::<char>();
+    size::<[u8; 4]>();
+}
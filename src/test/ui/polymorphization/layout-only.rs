@@ -0,0 +1,65 @@
+// build-fail
+// compile-flags:-Zpolymorphize=on
+#![feature(core_intrinsics, rustc_attrs)]
+
+// This test checks that the polymorphization analysis identifies generic parameters which are
+// only used for their size and alignment.
+
+use std::intrinsics::{min_align_of, pref_align_of, size_of};
+
+// Function only uses its type parameter for its size.
+#[rustc_polymorphize_error]
+pub fn size<T>() -> usize {
+    //~^ ERROR item has generic parameters only used for their layout
+    size_of::<T>()
+}
+
+// Function only uses its type parameter for its size and alignment.
+#[rustc_polymorphize_error]
+pub fn size_and_align<T>() -> (usize, usize) {
+    //~^ ERROR item has generic parameters only used for their layout
+    (size_of::<T>(), min_align_of::<T>())
+}
+
+// Function uses its type parameter in an argument.
+#[rustc_polymorphize_error]
+pub fn used_argument<T>(_: T) -> usize {
+    size_of::<T>()
+}
+
+// Function has a predicate on its type parameter.
+#[rustc_polymorphize_error]
+pub fn used_predicate<T: Default>() -> usize {
+    size_of::<T>()
+}
+
+// Function has an unused type parameter and one that is only used for its layout.
+#[rustc_polymorphize_error]
+pub fn mixed<T, U>() -> usize {
+    //~^ ERROR item has unused generic parameters
+    //~| ERROR item has generic parameters only used for their layout
+    size_of::<U>()
+}
+
+// Function uses its type parameter for its preferred alignment, which isn't preserved.
+#[rustc_polymorphize_error]
+pub fn used_pref_align<T>() -> usize {
+    pref_align_of::<T>()
+}
+
+// Function uses its type parameter in a type whose layout depends on more than the size and
+// alignment of the parameter.
+#[rustc_polymorphize_error]
+pub fn used_niche<T>() -> usize {
+    size_of::<Option<T>>()
+}
+
+fn main() {
+    size::<u32>();
+    size_and_align::<u32>();
+    used_argument::<u32>(1);
+    used_predicate::<u32>();
+    mixed::<u32, u32>();
+    used_pref_align::<u32>();
+    used_niche::<char>();
+}
@@ -0,0 +1,57 @@
+// Check that the bindings of an `if let` guard are dropped exactly once,
+// whether a later clause of the guard fails or the arm is taken.
+
+// run-pass
+
+#![feature(if_let_guard, let_chains)]
+#![allow(incomplete_features)]
+
+use std::cell::Cell;
+
+struct Noisy<'a>(&'a Cell<u32>);
+
+impl Drop for Noisy<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn make(drops: &Cell<u32>) -> Option<Noisy<'_>> {
+    Some(Noisy(drops))
+}
+
+fn main() {
+    let drops = Cell::new(0);
+
+    // A later boolean clause fails.
+    match 1 {
+        n if let Some(_x) = make(&drops) && n == 2 => unreachable!(),
+        _ => assert_eq!(drops.get(), 1),
+    }
+
+    // A later `let` clause fails.
+    drops.set(0);
+    match 1 {
+        _ if let Some(_x) = make(&drops) && let None = Some(()) => unreachable!(),
+        _ => assert_eq!(drops.get(), 1),
+    }
+
+    // The guard fails for each alternative of an or-pattern.
+    drops.set(0);
+    match (1, 2) {
+        (n, _) | (_, n) if let Some(_x) = make(&drops) && n == 3 => unreachable!(),
+        _ => assert_eq!(drops.get(), 2),
+    }
+
+    // The arm is taken: the binding lives until the end of the arm.
+    drops.set(0);
+    match 1 {
+        n if let Some(x) = make(&drops) && n == 1 => {
+            assert_eq!(drops.get(), 0);
+            drop(x);
+            assert_eq!(drops.get(), 1);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(drops.get(), 1);
+}
@@ -0,0 +1,12 @@
+#![feature(if_let_guard)]
+#![allow(incomplete_features)]
+
+fn main() {
+    let x = Some(Ok::<u8, ()>(0));
+    match x {
+        //~^ ERROR non-exhaustive patterns: `Some(_)` not covered
+        None => {}
+        Some(r) if let Ok(_) = r => {}
+        Some(r) if let Err(_) = r => {}
+    }
+}
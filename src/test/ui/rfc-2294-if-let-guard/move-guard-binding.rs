@@ -0,0 +1,11 @@
+#![feature(if_let_guard)]
+#![allow(incomplete_features)]
+
+fn main() {
+    let x = Some(String::from("hello"));
+    match x {
+        Some(s) if let Some(t) = Some(s) => drop(t),
+        //~^ ERROR cannot move out of `s` in pattern guard
+        _ => {}
+    }
+}
@@ -0,0 +1,48 @@
+// run-pass
+
+#![feature(if_let_guard, let_chains)]
+#![allow(incomplete_features)]
+
+enum Token {
+    Word(String),
+    Pair(String, String),
+    End,
+}
+
+fn classify(token: &Token) -> String {
+    match token {
+        Token::Word(w) if let Ok(n) = w.parse::<i32>() => format!("number {}", n),
+        Token::Word(w) | Token::Pair(w, _)
+            if let Some(c) = w.chars().next() && c.is_uppercase() =>
+        {
+            format!("name starting with {}", c)
+        }
+        Token::Word(w) => format!("word {}", w),
+        Token::Pair(k, v) if let Ok(n) = v.parse::<u8>() && n > 0 => format!("{} x{}", k, n),
+        Token::Pair(k, _) => format!("key {}", k),
+        Token::End => String::from("end"),
+    }
+}
+
+fn take_first(v: Vec<String>) -> Option<String> {
+    // The bindings of the guard are moved out of its scrutinee and can be used
+    // by value in the arm body.
+    match v.len() {
+        n if let Some(first) = v.first().cloned() => Some(format!("{}/{}", first, n)),
+        _ => None,
+    }
+}
+
+fn main() {
+    assert_eq!(classify(&Token::Word("42".into())), "number 42");
+    assert_eq!(classify(&Token::Word("Alice".into())), "name starting with A");
+    assert_eq!(classify(&Token::Pair("Bob".into(), "3".into())), "name starting with B");
+    assert_eq!(classify(&Token::Word("apple".into())), "word apple");
+    assert_eq!(classify(&Token::Pair("egg".into(), "12".into())), "egg x12");
+    assert_eq!(classify(&Token::Pair("egg".into(), "0".into())), "key egg");
+    assert_eq!(classify(&Token::Pair("egg".into(), "no".into())), "key egg");
+    assert_eq!(classify(&Token::End), "end");
+
+    assert_eq!(take_first(vec!["a".into(), "b".into()]), Some(String::from("a/2")));
+    assert_eq!(take_first(vec![]), None);
+}
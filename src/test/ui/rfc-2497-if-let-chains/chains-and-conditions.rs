@@ -0,0 +1,48 @@
+// run-pass
+
+#![feature(let_chains)]
+#![allow(incomplete_features)]
+
+fn first_even(v: &[Option<i32>]) -> Option<i32> {
+    for x in v {
+        if let Some(n) = *x && n % 2 == 0 {
+            return Some(n);
+        }
+    }
+    None
+}
+
+fn main() {
+    let a = Some(3);
+    let b: Result<i32, ()> = Ok(4);
+
+    if let Some(x) = a && let Ok(y) = b && x < y {
+        assert_eq!(x + y, 7);
+    } else {
+        panic!();
+    }
+
+    if let Some(x) = a && x > 5 {
+        panic!();
+    }
+
+    if true && let Some(x) = a && let Ok(y) = b && y == x + 1 {
+        assert_eq!(y, 4);
+    } else {
+        panic!();
+    }
+
+    let r = if let Some(x) = a && let Err(()) = b { x } else { 0 };
+    assert_eq!(r, 0);
+
+    assert_eq!(first_even(&[None, Some(1), Some(4), Some(6)]), Some(4));
+    assert_eq!(first_even(&[None, Some(1)]), None);
+
+    let mut v = vec![1, 2, 3, 4];
+    let mut sum = 0;
+    while let Some(x) = v.pop() && x > 1 {
+        sum += x;
+    }
+    assert_eq!(sum, 9);
+    assert!(v.is_empty());
+}
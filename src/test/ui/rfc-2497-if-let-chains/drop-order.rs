@@ -0,0 +1,41 @@
+// run-pass
+
+// The temporaries of a boolean operand of a `let` chain are dropped before the
+// next operand is evaluated, while the temporaries of a `let` scrutinee live as
+// long as in an `if let`.
+
+#![feature(let_chains)]
+#![allow(incomplete_features)]
+
+use std::cell::RefCell;
+
+struct D<'a>(&'static str, &'a RefCell<Vec<&'static str>>);
+
+impl D<'_> {
+    fn get(&self, b: bool) -> Option<&'static str> {
+        if b { Some(self.0) } else { None }
+    }
+}
+
+impl Drop for D<'_> {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+fn chain(b: bool) -> Vec<&'static str> {
+    let log = RefCell::new(Vec::new());
+    if let Some(x) = D("let", &log).get(b) && D("bool", &log).get(true).is_some() {
+        log.borrow_mut().push(x);
+        log.borrow_mut().push("then");
+    } else {
+        log.borrow_mut().push("else");
+    }
+    log.borrow_mut().push("end");
+    log.into_inner()
+}
+
+fn main() {
+    assert_eq!(chain(true), ["bool", "let", "then", "let", "end"]);
+    assert_eq!(chain(false), ["else", "let", "end"]);
+}
@@ -0,0 +1,22 @@
+#![feature(let_chains)]
+#![allow(incomplete_features)]
+#![deny(irrefutable_let_patterns)]
+
+fn main() {
+    let opt = Some(1);
+
+    if let Some(x) = opt && let y = x + 1 {
+        //~^ ERROR irrefutable `let` pattern in a `let` chain
+        assert_eq!(y, 2);
+    }
+
+    while let (_, _) = (1, 2) && let Some(_) = opt {
+        //~^ ERROR irrefutable `let` pattern in a `let` chain
+        break;
+    }
+
+    if let Some(_) = opt && let Some(_) = opt {}
+
+    // An irrefutable `let` between refutable clauses is not linted.
+    if let Some(x) = opt && let y = x + 1 && let Some(_) = Some(y) {}
+}
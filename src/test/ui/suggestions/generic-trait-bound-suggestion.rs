@@ -0,0 +1,14 @@
+// run-rustfix
+// Check that the bound suggested for a generic trait leaves out its generic arguments. This
+// makes the suggestion `MaybeIncorrect` even when it is the only candidate, but it applies
+// cleanly here as the parameter of the trait has a default.
+
+pub trait Scale<Factor = u32> {
+    fn scale(&self, factor: Factor) -> u32;
+}
+
+pub fn double<T>(value: &T) -> u32 {
+    value.scale(2) //~ ERROR no method named `scale` found
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// run-rustfix
+// Check that adding the only candidate trait import or bound is machine-applicable, with the
+// import sorted among the ones from the same crate and the bound added to the where-clause.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+
+pub trait Shape {
+    fn area(&self) -> u32;
+}
+
+pub fn area_of<T>(shape: &T) -> u32 where T: Debug {
+    shape.area() //~ ERROR no method named `area` found
+}
+
+fn main() {
+    let mut buffer: Vec<u8> = Vec::new();
+    buffer.write_all(b"shape").unwrap(); //~ ERROR no method named `write_all` found
+    let _ = u32::from_str("1");
+}
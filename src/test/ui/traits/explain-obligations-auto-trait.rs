@@ -0,0 +1,21 @@
+// compile-flags: -Z explain-obligations
+
+#![feature(optin_builtin_traits)]
+#![feature(negative_impls)]
+
+auto trait Portable {}
+
+struct Handle;
+
+impl !Portable for Handle {}
+
+struct Connection {
+    handle: Handle,
+}
+
+fn is_portable<T: Portable>(_: T) {}
+
+fn main() {
+    is_portable(Connection { handle: Handle });
+    //~^ ERROR the trait bound `Handle: Portable` is not satisfied in `Connection`
+}
@@ -0,0 +1,34 @@
+// compile-flags: -Z explain-obligations
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+struct Unit;
+
+impl Describe for u32 {
+    fn describe(&self) -> String {
+        format!("{} meters", self)
+    }
+}
+
+impl<T: Describe> Describe for Box<T> {
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn describe(&self) -> String {
+        self.as_ref().map_or_else(|| "nothing".to_string(), T::describe)
+    }
+}
+
+fn print<T: Describe>(value: T) {
+    println!("{}", value.describe());
+}
+
+fn main() {
+    print(Box::new(Some(3)));
+    print(Box::new(Some(Unit))); //~ ERROR the trait bound `Unit: Describe` is not satisfied
+}
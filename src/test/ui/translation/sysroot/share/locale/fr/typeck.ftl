@@ -0,0 +1,5 @@
+# A partial translation, used by `translate-lang.rs`.
+
+typeck-field-already-declared =
+    le champ `{$field_name}` est déjà déclaré
+    .label = champ déjà déclaré
@@ -0,0 +1,12 @@
+// Check that a translation which can't be loaded is reported with a warning, and
+// that diagnostics are then emitted in English.
+
+// compile-flags: -Z translate-lang=../fr
+
+struct Foo {
+    field1: i32,
+    field1: i32,
+    //~^ ERROR field `field1` is already declared [E0124]
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Check that `-Z translate-lang` translates the messages of translatable diagnostics, and
+// falls back to English for the messages missing from the translation.
+
+// compile-flags: --sysroot {{src-base}}/translation/sysroot -Z translate-lang=fr
+
+#![feature(no_core)]
+#![no_core]
+#![crate_type = "lib"]
+
+struct Foo {
+    field1: (),
+    field1: (),
+    //~^ ERROR le champ `field1` est déjà déclaré [E0124]
+}
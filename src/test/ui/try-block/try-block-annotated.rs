@@ -0,0 +1,25 @@
+// run-pass
+// compile-flags: --edition 2018
+
+#![feature(try_blocks)]
+
+#[derive(Debug, PartialEq)]
+struct MyError;
+
+impl From<std::num::ParseIntError> for MyError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        MyError
+    }
+}
+
+fn main() {
+    // The error type can't be guessed from the `?`, as it goes through `From`.
+    let x = try -> Result<_, MyError> { "3".parse::<u8>()? * 2 };
+    assert_eq!(x, Ok(6));
+
+    let y = try -> Result<u8, MyError> { "three".parse::<u8>()? };
+    assert_eq!(y, Err(MyError));
+
+    let z = try -> Option<_> {};
+    assert_eq!(z, Some(()));
+}
@@ -0,0 +1,11 @@
+// compile-flags: --edition 2018
+
+#![feature(try_blocks)]
+
+fn main() {
+    // The output type is only guessed from the `?` during fallback, which
+    // is too late to resolve a method call on the block, so the error points
+    // at the `?` and suggests an annotation.
+    let x = try { "1".parse::<u32>()? };
+    x.unwrap(); //~ ERROR type annotations needed for `try` block
+}
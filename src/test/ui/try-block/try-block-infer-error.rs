@@ -0,0 +1,42 @@
+// compile-flags: --edition 2018
+
+#![feature(control_flow_enum, try_blocks, try_trait)]
+
+use std::ops::ControlFlow;
+
+struct Custom;
+
+impl std::ops::Try for Custom {
+    type Ok = ();
+    type Error = ();
+
+    fn into_result(self) -> Result<(), ()> { Ok(()) }
+    fn from_error(_: ()) -> Self { Custom }
+    fn from_ok(_: ()) -> Self { Custom }
+}
+
+// The output of a generic `Try` type isn't necessarily its first type parameter.
+struct Flipped<E, T>(Result<T, E>);
+
+impl<E, T> std::ops::Try for Flipped<E, T> {
+    type Ok = T;
+    type Error = E;
+
+    fn into_result(self) -> Result<T, E> { self.0 }
+    fn from_error(e: E) -> Self { Flipped(Err(e)) }
+    fn from_ok(t: T) -> Self { Flipped(Ok(t)) }
+}
+
+fn main() {
+    let _ = try { //~ ERROR type annotations needed for `try` block
+        Custom?;
+    };
+
+    let _ = try { //~ ERROR type annotations needed for `try` block
+        Flipped::<(), u8>(Ok(1))?
+    };
+
+    let _ = try { //~ ERROR type annotations needed for `try` block
+        ControlFlow::<u8, ()>::Continue(1)?
+    };
+}
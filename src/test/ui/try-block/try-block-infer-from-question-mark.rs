@@ -0,0 +1,23 @@
+// run-pass
+// compile-flags: --edition 2018
+
+#![feature(try_blocks)]
+
+use std::num::ParseIntError;
+
+fn parse(s: &str) -> Result<u32, ParseIntError> {
+    s.parse()
+}
+
+fn main() {
+    // The output type is guessed from the first `?` once nothing else
+    // constrains it, during fallback at the end of type checking.
+    let sum = try { parse("1")? + parse("2")? };
+    assert_eq!(sum, Ok(3));
+
+    let len = try { Some("hello")?.len() };
+    assert_eq!(len, Some(5));
+
+    let failed = try { parse("x")? };
+    assert!(matches!(failed, Err(_)));
+}
@@ -0,0 +1,29 @@
+// Crate that exports opaque `impl Trait` types declared in a module and in an
+// associated type, and defined in child modules and methods, along with a constant that
+// uses one of them without defining it.
+
+#![crate_type = "rlib"]
+#![feature(type_alias_impl_trait)]
+
+pub mod outer {
+    pub type Counter = impl Iterator<Item = u32> + Clone;
+
+    pub mod inner {
+        pub fn counter(n: u32) -> super::Counter {
+            0..n
+        }
+    }
+}
+
+pub struct Evens(pub u32);
+
+impl IntoIterator for Evens {
+    type Item = u32;
+    type IntoIter = impl Iterator<Item = u32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        outer::inner::counter(self.0).map(|x| x * 2)
+    }
+}
+
+pub const NO_COUNTER: Option<outer::Counter> = None;
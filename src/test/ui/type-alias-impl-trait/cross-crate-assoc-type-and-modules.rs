@@ -0,0 +1,21 @@
+// Check that opaque types defined in child modules and in associated types, and the
+// constants using them, are usable from another crate.
+
+// aux-build:cross_crate_assoc_tait.rs
+// run-pass
+
+extern crate cross_crate_assoc_tait;
+
+use cross_crate_assoc_tait::{outer, Evens, NO_COUNTER};
+
+fn sum(it: impl Iterator<Item = u32>) -> u32 {
+    it.sum()
+}
+
+fn main() {
+    let counter = outer::inner::counter(4);
+    assert_eq!(sum(counter.clone()), 6);
+    assert_eq!(counter.collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(Evens(3).into_iter().collect::<Vec<_>>(), [0, 2, 4]);
+    assert!(NO_COUNTER.is_none());
+}
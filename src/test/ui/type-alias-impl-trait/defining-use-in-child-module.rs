@@ -0,0 +1,40 @@
+// Check that a type alias `impl Trait` may be defined anywhere within the
+// module that declares it, including its child modules and the methods of an
+// impl whose associated type it is.
+
+// check-pass
+
+#![feature(type_alias_impl_trait)]
+
+use std::fmt::Debug;
+
+mod a {
+    pub type Foo = impl Debug;
+
+    mod b {
+        pub mod c {
+            pub fn foo() -> super::super::Foo {
+                42u8
+            }
+        }
+    }
+
+    pub fn foo() -> Foo {
+        b::c::foo()
+    }
+}
+
+struct Wrapper<T>(T);
+
+impl<T: Debug> Iterator for Wrapper<T> {
+    type Item = impl Debug;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(format!("{:?}", self.0))
+    }
+}
+
+fn main() {
+    let _: a::Foo = a::foo();
+    let _ = Wrapper(1).next();
+}
@@ -0,0 +1,44 @@
+// Check that consts, associated consts and statics may use a type alias `impl Trait`
+// defined by a function without defining it themselves, both in and out of its
+// defining scope.
+
+// build-pass
+
+#![feature(type_alias_impl_trait)]
+
+use std::fmt::Debug;
+
+mod a {
+    pub type Foo = impl Debug + Sync;
+
+    pub fn foo() -> Foo {
+        42u8
+    }
+
+    pub static NO_FOO: Option<Foo> = None;
+}
+
+const ALSO_NO_FOO: Option<a::Foo> = None;
+
+pub trait Trait {
+    type Assoc: Debug;
+
+    const NONE: Option<Self::Assoc>;
+
+    fn assoc(&self) -> Self::Assoc;
+}
+
+impl Trait for () {
+    type Assoc = impl Debug;
+
+    const NONE: Option<Self::Assoc> = None;
+
+    fn assoc(&self) -> Self::Assoc {
+        "defined by a method"
+    }
+}
+
+fn main() {
+    println!("{:?} {:?} {:?}", a::foo(), a::NO_FOO, ALSO_NO_FOO);
+    println!("{:?} {:?}", ().assoc(), <() as Trait>::NONE);
+}
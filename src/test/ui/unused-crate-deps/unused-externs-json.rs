@@ -0,0 +1,11 @@
+// Check that `--json unused-externs` reports the unused crates given with `--extern`
+
+// edition:2018
+// check-pass
+// aux-crate:bar=bar.rs
+// aux-crate:foo=foo.rs
+// compile-flags: -Z unstable-options --error-format json --json unused-externs
+
+use foo as _;
+
+fn main() {}
@@ -133,7 +133,7 @@ pub fn eq_expr(l: &Expr, r: &Expr) -> bool {
         },
         (Loop(lt, ll), Loop(rt, rl)) => eq_label(ll, rl) && eq_block(lt, rt),
         (Block(lb, ll), Block(rb, rl)) => eq_label(ll, rl) && eq_block(lb, rb),
-        (TryBlock(l), TryBlock(r)) => eq_block(l, r),
+        (TryBlock(l, lt), TryBlock(r, rt)) => eq_block(l, r) && both(lt, rt, |l, r| eq_ty(l, r)),
         (Yield(l), Yield(r)) | (Ret(l), Ret(r)) => eq_expr_opt(l, r),
         (Break(ll, le), Break(rl, re)) => eq_label(ll, rl) && eq_expr_opt(le, re),
         (Continue(ll), Continue(rl)) => eq_label(ll, rl),
@@ -528,6 +528,15 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 self.current = expr_pat;
                 self.visit_expr(expr);
             },
+            ExprKind::Let(ref pat, ref expr) => {
+                let pat_pat = self.next("pat");
+                let expr_pat = self.next("expr");
+                println!("Let(ref {}, ref {}) = {};", pat_pat, expr_pat, current);
+                self.current = pat_pat;
+                self.visit_pat(pat);
+                self.current = expr_pat;
+                self.visit_expr(expr);
+            },
         }
     }
 
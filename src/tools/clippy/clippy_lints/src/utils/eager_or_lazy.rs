@@ -61,6 +61,7 @@ fn identify_some_pure_patterns(expr: &Expr<'_>) -> bool {
         | ExprKind::Cast(..)
         | ExprKind::Type(..)
         | ExprKind::DropTemps(..)
+        | ExprKind::Let(..)
         | ExprKind::Loop(..)
         | ExprKind::Match(..)
         | ExprKind::Closure(..)
@@ -50,6 +50,7 @@ impl<'a, 'tcx> SpanlessEq<'a, 'tcx> {
                 self.eq_pat(&l.pat, &r.pat)
                     && both(&l.ty, &r.ty, |l, r| self.eq_ty(l, r))
                     && both(&l.init, &r.init, |l, r| self.eq_expr(l, r))
+                    && both(&l.els, &r.els, |l, r| self.eq_block(l, r))
             },
             (&StmtKind::Expr(ref l), &StmtKind::Expr(ref r)) | (&StmtKind::Semi(ref l), &StmtKind::Semi(ref r)) => {
                 self.eq_expr(l, r)
@@ -154,6 +155,9 @@ impl<'a, 'tcx> SpanlessEq<'a, 'tcx> {
             (&ExprKind::Unary(l_op, ref le), &ExprKind::Unary(r_op, ref re)) => l_op == r_op && self.eq_expr(le, re),
             (&ExprKind::Array(l), &ExprKind::Array(r)) => self.eq_exprs(l, r),
             (&ExprKind::DropTemps(ref le), &ExprKind::DropTemps(ref re)) => self.eq_expr(le, re),
+            (&ExprKind::Let(ref lp, ref le), &ExprKind::Let(ref rp, ref re)) => {
+                self.eq_pat(lp, rp) && self.eq_expr(le, re)
+            },
             _ => false,
         }
     }
@@ -457,6 +461,10 @@ impl<'a, 'tcx> SpanlessHash<'a, 'tcx> {
             ExprKind::Box(ref e) | ExprKind::DropTemps(ref e) | ExprKind::Yield(ref e, _) => {
                 self.hash_expr(e);
             },
+            ExprKind::Let(_, ref e) => {
+                // TODO: pat?
+                self.hash_expr(e);
+            },
             ExprKind::Call(ref fun, args) => {
                 self.hash_expr(fun);
                 self.hash_exprs(args);
@@ -639,6 +647,9 @@ impl<'a, 'tcx> SpanlessHash<'a, 'tcx> {
                 if let Some(ref init) = local.init {
                     self.hash_expr(init);
                 }
+                if let Some(els) = local.els {
+                    self.hash_block(els);
+                }
             },
             StmtKind::Item(..) => {},
             StmtKind::Expr(expr) | StmtKind::Semi(expr) => {
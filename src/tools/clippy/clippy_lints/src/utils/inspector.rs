@@ -357,6 +357,13 @@ fn print_expr(cx: &LateContext<'_>, expr: &hir::Expr<'_>, indent: usize) {
             println!("{}DropTemps", ind);
             print_expr(cx, e, indent + 1);
         },
+        hir::ExprKind::Let(ref pat, ref e) => {
+            println!("{}Let", ind);
+            println!("{}pattern:", ind);
+            print_pat(cx, pat, indent + 1);
+            println!("{}scrutinee:", ind);
+            print_expr(cx, e, indent + 1);
+        },
     }
 }
 
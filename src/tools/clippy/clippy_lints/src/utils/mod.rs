@@ -1387,7 +1387,7 @@ pub fn run_lints(cx: &LateContext<'_>, lints: &[&'static Lint], id: HirId) -> bo
     lints.iter().any(|lint| {
         matches!(
             cx.tcx.lint_level_at_node(lint, id),
-            (Level::Forbid | Level::Deny | Level::Warn, _)
+            (Level::Forbid | Level::Deny | Level::Warn | Level::Expect(_), _)
         )
     })
 }
@@ -99,6 +99,7 @@ impl<'a> Sugg<'a> {
             | hir::ExprKind::Box(..)
             | hir::ExprKind::Closure(..)
             | hir::ExprKind::Unary(..)
+            | hir::ExprKind::Let(..)
             | hir::ExprKind::Match(..) => Sugg::MaybeParen(snippet),
             hir::ExprKind::Continue(..)
             | hir::ExprKind::Yield(..)
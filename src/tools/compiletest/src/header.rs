@@ -313,6 +313,9 @@ pub struct TestProps {
     // testing harness and used when generating compilation
     // arguments. (In particular, it propagates to the aux-builds.)
     pub incremental_dir: Option<PathBuf>,
+    // Whether a non-incremental test should be compiled in incremental mode,
+    // set with the `// incremental` header.
+    pub incremental: bool,
     // How far should the test proceed while still passing.
     pass_mode: Option<PassMode>,
     // Ignore `--pass` overrides from the command line for this test.
@@ -361,6 +364,7 @@ impl TestProps {
             pretty_compare_only: false,
             forbid_output: vec![],
             incremental_dir: None,
+            incremental: false,
             pass_mode: None,
             fail_mode: None,
             ignore_pass: false,
@@ -435,6 +439,10 @@ impl TestProps {
                     self.should_ice = config.parse_should_ice(ln);
                 }
 
+                if !self.incremental {
+                    self.incremental = config.parse_incremental(ln);
+                }
+
                 if !self.build_aux_docs {
                     self.build_aux_docs = config.parse_build_aux_docs(ln);
                 }
@@ -683,6 +691,11 @@ impl Config {
     fn parse_should_ice(&self, line: &str) -> bool {
         self.parse_name_directive(line, "should-ice")
     }
+
+    fn parse_incremental(&self, line: &str) -> bool {
+        self.parse_name_directive(line, "incremental")
+    }
+
     fn parse_error_pattern(&self, line: &str) -> Option<String> {
         self.parse_name_value_directive(line, "error-pattern")
     }
@@ -23,6 +23,27 @@ struct ArtifactNotification {
     artifact: PathBuf,
 }
 
+#[derive(Deserialize)]
+struct FutureIncompatReport {
+    future_incompat_report: Vec<FutureBreakageItem>,
+}
+
+#[derive(Deserialize)]
+struct Explanation {
+    #[allow(dead_code)]
+    explanation: String,
+}
+
+#[derive(Deserialize)]
+struct UnusedExterns {
+    unused_extern_names: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct FutureBreakageItem {
+    diagnostic: Diagnostic,
+}
+
 #[derive(Deserialize, Clone)]
 struct DiagnosticSpan {
     file_name: String,
@@ -78,6 +99,24 @@ pub fn extract_rendered(output: &str) -> String {
                 } else if serde_json::from_str::<ArtifactNotification>(line).is_ok() {
                     // Ignore the notification.
                     None
+                } else if serde_json::from_str::<Explanation>(line).is_ok() {
+                    // Ignore the explanation, which is rendered in a note of its diagnostic.
+                    None
+                } else if let Ok(report) = serde_json::from_str::<FutureIncompatReport>(line) {
+                    Some(
+                        report
+                            .future_incompat_report
+                            .into_iter()
+                            .map(|item| {
+                                format!(
+                                    "Future incompatibility report: {}",
+                                    item.diagnostic.rendered.unwrap_or_default()
+                                )
+                            })
+                            .collect(),
+                    )
+                } else if let Ok(report) = serde_json::from_str::<UnusedExterns>(line) {
+                    Some(format!("Unused externs: {}\n", report.unused_extern_names.join(", ")))
                 } else {
                     print!(
                         "failed to decode compiler output as json: line: {}\noutput: {}",
@@ -101,6 +140,15 @@ fn parse_line(file_name: &str, line: &str, output: &str, proc_res: &ProcRes) ->
     // The compiler sometimes intermingles non-JSON stuff into the
     // output.  This hack just skips over such lines. Yuck.
     if line.starts_with('{') {
+        // The future incompatibility report repeats diagnostics which were already emitted,
+        // or which are allowed, so it's not checked against the annotations. Neither are the
+        // report of unused externs and the explanations, which aren't diagnostics.
+        if serde_json::from_str::<FutureIncompatReport>(line).is_ok()
+            || serde_json::from_str::<UnusedExterns>(line).is_ok()
+            || serde_json::from_str::<Explanation>(line).is_ok()
+        {
+            return vec![];
+        }
         match serde_json::from_str::<Diagnostic>(line) {
             Ok(diagnostic) => {
                 let mut expected_errors = vec![];
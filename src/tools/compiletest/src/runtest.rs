@@ -248,6 +248,13 @@ pub fn run(config: Config, testpaths: &TestPaths, revision: Option<&str>) {
             };
             rev_cx.run_revision();
         }
+    } else if props.incremental {
+        // Tests with the `// incremental` header get a fresh incremental
+        // directory of their own.
+        cx.init_incremental_test();
+        let mut incremental_props = props.clone();
+        incremental_props.incremental_dir = Some(cx.incremental_dir());
+        TestCx { props: &incremental_props, ..cx }.run_revision();
     } else {
         cx.run_revision();
     }
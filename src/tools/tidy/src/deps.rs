@@ -170,6 +170,7 @@ const PERMITTED_DEPENDENCIES: &[&str] = &[
     "termcolor",
     "termize",
     "thread_local",
+    "toml",
     "tracing",
     "tracing-attributes",
     "tracing-core",